@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// A small timing harness for `cargo bench`, since #[bench] needs nightly: every case runs once to warm up and then
+// a few more times, and the fastest run is the one reported as it's the least disturbed by everything else going on.
+
+pub const RUNS: usize = 5;
+
+pub fn measure<T>(mut f: impl FnMut() -> T) -> Duration {
+    black_box(f());
+    (0..RUNS)
+        .map(|_| {
+            let started = Instant::now();
+            black_box(f());
+            started.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+// For cases that take seconds, where warming up and repeating would take minutes: one run, with its result
+pub fn measure_once<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let started = Instant::now();
+    let result = black_box(f());
+    (result, started.elapsed())
+}
+
+// One line per case with how many times slower than the fastest it is
+pub fn report(title: &str, rows: &[(&str, Duration)]) {
+    println!("{title}");
+    let fastest = rows.iter().map(|(_, d)| *d).min().unwrap_or_default();
+    for (name, duration) in rows {
+        let ratio = duration.as_secs_f64() / fastest.as_secs_f64().max(1e-9);
+        println!("  {name:<32} {duration:>12.3?} {ratio:>9.1}x");
+    }
+}
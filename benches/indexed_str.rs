@@ -0,0 +1,38 @@
+// IndexedStr::char_at against `str.chars().nth(i)` from chap_09_slices, which walks the string from the start on
+// every call.
+mod common;
+
+use common::{measure, report};
+use the_rust_book_learning::text::indexed_str::IndexedStr;
+
+fn main() {
+    let text = "Здравствуйте नमस्ते hello ".repeat(2_000);
+    let lookups = 2_000;
+    let char_count = text.chars().count();
+    let positions: Vec<usize> = (0..lookups).map(|i| (i * 7919) % char_count).collect();
+
+    let naive = |text: &str| {
+        positions.iter().fold(0u32, |sum, &n| {
+            sum.wrapping_add(text.chars().nth(n).unwrap() as u32)
+        })
+    };
+    let indexed = |text: &IndexedStr| {
+        positions.iter().fold(0u32, |sum, &n| {
+            sum.wrapping_add(text.char_at(n).unwrap() as u32)
+        })
+    };
+    let table = IndexedStr::new(&text);
+    assert_eq!(naive(&text), indexed(&table));
+
+    report(
+        &format!("{lookups} lookups over {char_count} chars"),
+        &[
+            ("chars().nth()", measure(|| naive(&text))),
+            ("IndexedStr::char_at", measure(|| indexed(&table))),
+            (
+                "IndexedStr::new (building)",
+                measure(|| IndexedStr::new(&text)),
+            ),
+        ],
+    );
+}
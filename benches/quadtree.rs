@@ -0,0 +1,84 @@
+// The quadtree against checking every rectangle, both measured in full: range queries over 100k rectangles and
+// all overlapping pairs among the same 100k. Brute force needs n²/2 checks for those, 5·10⁹ of them, so that one
+// is timed once instead of being warmed up and repeated.
+mod common;
+
+use common::{measure, measure_once, report};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use the_rust_book_learning::geometry::quadtree::QuadTree;
+use the_rust_book_learning::geometry::rect::Rect;
+
+const WORLD: u32 = 100_000;
+
+fn random_rect(rng: &mut impl Rng, max_size: u32) -> Rect {
+    Rect::new(
+        rng.gen_range(0..WORLD),
+        rng.gen_range(0..WORLD),
+        rng.gen_range(0..max_size),
+        rng.gen_range(0..max_size),
+    )
+}
+
+fn build(rects: &[Rect]) -> QuadTree {
+    let mut tree = QuadTree::new(Rect::sized(WORLD, WORLD));
+    for &rect in rects {
+        tree.insert(rect);
+    }
+    tree
+}
+
+fn brute_range(rects: &[Rect], area: &Rect) -> usize {
+    rects.iter().filter(|r| r.overlaps(area)).count()
+}
+
+fn brute_pairs(rects: &[Rect]) -> usize {
+    let mut count = 0;
+    for (i, a) in rects.iter().enumerate() {
+        count += rects[i + 1..].iter().filter(|b| a.overlaps(b)).count();
+    }
+    count
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(40);
+    let rects: Vec<Rect> = (0..100_000).map(|_| random_rect(&mut rng, 500)).collect();
+    let queries: Vec<Rect> = (0..1_000).map(|_| random_rect(&mut rng, 2_000)).collect();
+
+    let tree = build(&rects);
+    let brute = || {
+        queries
+            .iter()
+            .map(|q| brute_range(&rects, q))
+            .sum::<usize>()
+    };
+    let fast = || {
+        queries
+            .iter()
+            .map(|q| tree.query_range(q).len())
+            .sum::<usize>()
+    };
+    assert_eq!(brute(), fast());
+    report(
+        &format!(
+            "1000 range queries over {} rectangles (depth {})",
+            rects.len(),
+            tree.depth()
+        ),
+        &[
+            ("brute force", measure(brute)),
+            ("quadtree", measure(fast)),
+            ("building the quadtree", measure(|| build(&rects))),
+        ],
+    );
+
+    let (expected, brute_time) = measure_once(|| brute_pairs(&rects));
+    assert_eq!(expected, tree.overlapping_pairs().len());
+    report(
+        &format!("all overlapping pairs among {} rectangles", rects.len()),
+        &[
+            ("brute force", brute_time),
+            ("quadtree", measure(|| tree.overlapping_pairs())),
+        ],
+    );
+}
@@ -0,0 +1,44 @@
+// Rope::insert against String::insert_str on a big document. Every String insert moves everything after it, a
+// Rope insert only touches one leaf and the path down to it.
+mod common;
+
+use common::{measure, report};
+use the_rust_book_learning::text::rope::Rope;
+
+fn main() {
+    let document = "The quick brown fox jumps over the lazy dog. ".repeat(100_000); // ~4.5 MB
+    let edits = 2_000;
+    // ASCII only, so char index == byte index
+    let positions: Vec<usize> = (0..edits)
+        .map(|i| (i * 7919) % (document.len() / 2))
+        .collect();
+
+    let string_inserts = || {
+        let mut string = document.clone();
+        for &at in &positions {
+            string.insert_str(at, "I am ");
+        }
+        string
+    };
+    let rope_inserts = || {
+        let mut rope = Rope::from(document.as_str());
+        for &at in &positions {
+            rope.insert(at, "I am ");
+        }
+        rope
+    };
+    assert_eq!(String::from(&rope_inserts()), string_inserts());
+
+    report(
+        &format!("{edits} inserts into a {} byte document", document.len()),
+        &[
+            ("String::insert_str (with clone)", measure(string_inserts)),
+            ("String clone alone", measure(|| document.clone())),
+            ("Rope::insert (with Rope::from)", measure(rope_inserts)),
+            (
+                "Rope::from alone",
+                measure(|| Rope::from(document.as_str())),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,85 @@
+// Longest-prefix match in the routing table's tries against scanning every route, on the same tables and the same
+// addresses: 1M lookups in a table built from 100k random routes for the trie alone, and 10k lookups in one from
+// 10k routes for the comparison. Repeated prefixes replace each other, so the tables end up somewhat smaller.
+mod common;
+
+use common::{measure, report};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use the_rust_book_learning::net::addr::IpAddr;
+use the_rust_book_learning::net::cidr::Cidr;
+use the_rust_book_learning::net::route::RoutingTable;
+
+// Every fifth one IPv6, mostly inside 2001:db8::/32 so the prefixes have something to match
+fn random_addr(rng: &mut impl Rng, i: usize) -> IpAddr {
+    if !i.is_multiple_of(5) {
+        IpAddr::from_bits(true, rng.r#gen::<u32>() as u128)
+    } else {
+        IpAddr::from_bits(false, 0x2001_0db8 << 96 | rng.r#gen::<u128>() >> 32)
+    }
+}
+
+fn random_table(rng: &mut impl Rng, n: usize) -> (RoutingTable<usize>, Vec<Cidr>) {
+    let mut table = RoutingTable::new();
+    let mut prefixes = Vec::new();
+    for i in 0..n {
+        let addr = random_addr(rng, i);
+        let len = if addr.is_ipv4() {
+            rng.gen_range(0..=24)
+        } else {
+            rng.gen_range(28..=64)
+        };
+        let prefix = Cidr::containing(addr, len).unwrap();
+        if table.insert(prefix, i).is_none() {
+            prefixes.push(prefix);
+        }
+    }
+    (table, prefixes)
+}
+
+fn scan(prefixes: &[Cidr], addr: IpAddr) -> Option<Cidr> {
+    prefixes
+        .iter()
+        .filter(|prefix| prefix.contains_addr(addr))
+        .max_by_key(|prefix| prefix.len())
+        .copied()
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(46);
+
+    let (table, _) = random_table(&mut rng, 100_000);
+    let addrs: Vec<IpAddr> = (0..1_000_000).map(|i| random_addr(&mut rng, i)).collect();
+    let lookups = || addrs.iter().filter(|&&a| table.lookup(a).is_some()).count();
+    report(
+        &format!("{} lookups in {} routes", addrs.len(), table.len()),
+        &[("trie", measure(lookups))],
+    );
+
+    let (table, prefixes) = random_table(&mut rng, 10_000);
+    let addrs = &addrs[..10_000];
+    for &addr in addrs {
+        assert_eq!(
+            table.lookup(addr).map(|(prefix, _)| prefix),
+            scan(&prefixes, addr)
+        );
+    }
+    report(
+        &format!("{} lookups in {} routes", addrs.len(), table.len()),
+        &[
+            (
+                "trie",
+                measure(|| addrs.iter().filter(|&&a| table.lookup(a).is_some()).count()),
+            ),
+            (
+                "linear scan",
+                measure(|| {
+                    addrs
+                        .iter()
+                        .filter(|&&a| scan(&prefixes, a).is_some())
+                        .count()
+                }),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,37 @@
+// The three substring searchers against str::find, with the needle right at the end of a long haystack.
+mod common;
+
+use common::{measure, report};
+use the_rust_book_learning::text::search::{Horspool, Kmp, TwoWay, find};
+
+fn main() {
+    let mut haystack = "Здравствуйте, I am hello world. नमस्ते! ".repeat(50_000);
+    haystack.push_str("needle in the haystack");
+    let needle = "needle in the haystack";
+    let expected = haystack.find(needle);
+
+    let kmp = Kmp::new(needle);
+    let horspool = Horspool::new(needle);
+    let two_way = TwoWay::new(needle);
+    for found in [
+        find(&kmp, &haystack),
+        find(&horspool, &haystack),
+        find(&two_way, &haystack),
+    ] {
+        assert_eq!(found.map(|span| span.start), expected);
+    }
+
+    report(
+        &format!(
+            "finding a {} byte needle in {} bytes",
+            needle.len(),
+            haystack.len()
+        ),
+        &[
+            ("str::find", measure(|| haystack.find(needle))),
+            ("KMP", measure(|| find(&kmp, &haystack))),
+            ("Horspool", measure(|| find(&horspool, &haystack))),
+            ("Two-way", measure(|| find(&two_way, &haystack))),
+        ],
+    );
+}
@@ -0,0 +1,88 @@
+#!/usr/bin/env python3
+# Writes src/text/char_names.rs from the Unicode character database:
+#
+#     python3 scripts/char_names.py 16.0.0 > src/text/char_names.rs
+#
+# The two files it needs are downloaded from unicode.org, or read from a directory laid out like the UCD
+# (UnicodeData.txt, NameAliases.txt) given as a second argument.
+#
+# Only the blocks in BLOCKS are written out, utf8::char_name falls back to "U+XXXX" for everything else. Controls
+# have no name in UnicodeData.txt, so they get their first `control` alias from NameAliases.txt instead.
+import os
+import sys
+import urllib.request
+
+BLOCKS = [
+    (0x0000, 0x007F),  # Basic Latin
+    (0x0080, 0x00FF),  # Latin-1 Supplement
+    (0x0400, 0x04FF),  # Cyrillic
+    (0x0900, 0x097F),  # Devanagari
+    (0x2000, 0x206F),  # General Punctuation
+    (0x20AC, 0x20AC),  # EURO SIGN, the one currency symbol the examples print
+    (0x1F1E6, 0x1F1FF),  # Regional indicators, which flags are made of
+]
+
+FILES = {
+    "data": "UnicodeData.txt",
+    "aliases": "NameAliases.txt",
+}
+
+
+def read(version, local_dir, key):
+    if local_dir:
+        with open(os.path.join(local_dir, FILES[key]), encoding="utf-8") as f:
+            return f.read()
+    url = f"https://www.unicode.org/Public/{version}/ucd/{FILES[key]}"
+    with urllib.request.urlopen(url) as response:
+        return response.read().decode("utf-8")
+
+
+# Yields the ;-separated fields of every data line
+def records(text):
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].strip()
+        if line:
+            yield [f.strip() for f in line.split(";")]
+
+
+def char(c):
+    return f"'\\u{{{c:x}}}'"
+
+
+def main():
+    version = sys.argv[1]
+    local_dir = sys.argv[2] if len(sys.argv) > 2 else None
+
+    names = {}
+    for fields in records(read(version, local_dir, "data")):
+        # "<control>" and the "<CJK Ideograph, First>" style range markers aren't names
+        if not fields[1].startswith("<"):
+            names[int(fields[0], 16)] = fields[1]
+    for fields in records(read(version, local_dir, "aliases")):
+        c = int(fields[0], 16)
+        if fields[2] == "control" and c not in names:
+            names[c] = fields[1]
+
+    out = [
+        f"// Generated by scripts/char_names.py from the Unicode {version} character database, do not edit by hand.",
+        "// Only the blocks this crate prints (ASCII, Latin-1, Cyrillic, Devanagari, General Punctuation, the euro sign,",
+        "// flags) are included, everything else falls back to \"U+XXXX\" in utf8::char_name.",
+        "",
+        "pub const CHAR_NAMES: &[(char, &str)] = &[",
+    ]
+    for first, last in BLOCKS:
+        for c in range(first, last + 1):
+            if c not in names:
+                continue
+            item = f'{char(c)}, "{names[c]}"'
+            # rustfmt puts a tuple on one line only if its items fit in 60 columns
+            if len(item) > 60:
+                out += ["    (", f"        {char(c)},", f'        "{names[c]}",', "    ),"]
+            else:
+                out.append(f"    ({item}),")
+    out.append("];")
+    print("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,144 @@
+#!/usr/bin/env python3
+# Writes src/text/grapheme_tables.rs from the Unicode character database:
+#
+#     python3 scripts/grapheme_tables.py 16.0.0 > src/text/grapheme_tables.rs
+#
+# The three files it needs are downloaded from unicode.org, or read from a directory laid out like the UCD
+# (auxiliary/GraphemeBreakProperty.txt, emoji/emoji-data.txt, DerivedCoreProperties.txt) given as a second argument.
+#
+# Every code point gets one GraphemeCat: its Grapheme_Cluster_Break value if it has one, else ExtendedPictographic,
+# else InCbConsonant for Indic_Conjunct_Break=Consonant, else Other. Runs of the same category become one range and
+# Other is left out, which is what grapheme_cat falls back to.
+import os
+import re
+import sys
+import urllib.request
+
+GCB_NAMES = {
+    "CR": "CR",
+    "LF": "LF",
+    "Control": "Control",
+    "Extend": "Extend",
+    "ZWJ": "Zwj",
+    "Regional_Indicator": "RegionalIndicator",
+    "Prepend": "Prepend",
+    "SpacingMark": "SpacingMark",
+    "L": "L",
+    "V": "V",
+    "T": "T",
+    "LV": "LV",
+    "LVT": "LVT",
+}
+
+FILES = {
+    "gcb": "auxiliary/GraphemeBreakProperty.txt",
+    "emoji": "emoji/emoji-data.txt",
+    "derived": "DerivedCoreProperties.txt",
+}
+
+
+def read(version, local_dir, key):
+    if local_dir:
+        with open(os.path.join(local_dir, FILES[key]), encoding="utf-8") as f:
+            return f.read()
+    url = f"https://www.unicode.org/Public/{version}/ucd/{FILES[key]}"
+    with urllib.request.urlopen(url) as response:
+        return response.read().decode("utf-8")
+
+
+# Yields (first, last, [fields...]) for every data line, e.g. "0915..0939 ; InCB; Consonant # ..."
+def entries(text):
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        fields = [f.strip() for f in line.split(";")]
+        m = re.fullmatch(r"([0-9A-Fa-f]+)(?:\.\.([0-9A-Fa-f]+))?", fields[0])
+        first = int(m.group(1), 16)
+        last = int(m.group(2), 16) if m.group(2) else first
+        yield first, last, fields[1:]
+
+
+def ranges(points):
+    out = []
+    for c in sorted(points):
+        if out and out[-1][1] + 1 == c:
+            out[-1][1] = c
+        else:
+            out.append([c, c])
+    return out
+
+
+def char(c):
+    return f"'\\u{{{c:x}}}'"
+
+
+def main():
+    version = sys.argv[1]
+    local_dir = sys.argv[2] if len(sys.argv) > 2 else None
+
+    gcb = {}
+    for first, last, fields in entries(read(version, local_dir, "gcb")):
+        for c in range(first, last + 1):
+            gcb[c] = GCB_NAMES[fields[0]]
+    pictographic = set()
+    for first, last, fields in entries(read(version, local_dir, "emoji")):
+        if fields[0] == "Extended_Pictographic":
+            pictographic.update(range(first, last + 1))
+    incb = {"Consonant": set(), "Linker": set(), "Extend": set()}
+    for first, last, fields in entries(read(version, local_dir, "derived")):
+        if fields[0] == "InCB":
+            incb[fields[1]].update(range(first, last + 1))
+
+    cats = {}
+    for c in range(0x110000):
+        if c in gcb:
+            cats[c] = gcb[c]
+        elif c in pictographic:
+            cats[c] = "ExtendedPictographic"
+        elif c in incb["Consonant"]:
+            cats[c] = "InCbConsonant"
+
+    table = []
+    for c in sorted(cats):
+        if table and table[-1][1] + 1 == c and table[-1][2] == cats[c]:
+            table[-1][1] = c
+        else:
+            table.append([c, c, cats[c]])
+
+    major, minor, patch = version.split(".")
+    out = [
+        f"// Generated by scripts/grapheme_tables.py from the Unicode {version} character database, do not edit by hand:",
+        "// - Grapheme_Cluster_Break from auxiliary/GraphemeBreakProperty.txt",
+        "// - Extended_Pictographic from emoji/emoji-data.txt",
+        "// - Indic_Conjunct_Break (InCB) from DerivedCoreProperties.txt",
+        "// Ranges are sorted and non-overlapping so they can be binary searched. Anything not listed is `Other`.",
+        "use super::grapheme::GraphemeCat::{self, *};",
+        "",
+        f"pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {patch});",
+        "",
+        "pub const GRAPHEME_CAT_TABLE: &[(char, char, GraphemeCat)] = &[",
+    ]
+    out += [f"    ({char(lo)}, {char(hi)}, {cat})," for lo, hi, cat in table]
+    out += [
+        "];",
+        "",
+        "// InCB=Linker: the viramas that glue two consonants into a conjunct (GB9c)",
+        "pub const INCB_LINKER: &[char] = &[",
+    ]
+    # as many per line as fit in 100 columns, like rustfmt lays out short items
+    line = "   "
+    for c in sorted(incb["Linker"]):
+        item = f" {char(c)},"
+        if len(line) + len(item) > 100:
+            out.append(line)
+            line = "   "
+        line += item
+    out += [line, "];", "", "pub const INCB_EXTEND_TABLE: &[(char, char)] = &["]
+    out += [f"    ({char(lo)}, {char(hi)})," for lo, hi in ranges(incb["Extend"])]
+    out.append("];")
+    print("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
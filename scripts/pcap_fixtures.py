@@ -0,0 +1,146 @@
+#!/usr/bin/env python3
+# Writes the capture files in assets/pcap that src/net/pcap.rs is tested against:
+#
+#     python3 scripts/pcap_fixtures.py [out_dir]
+#
+# The frames are put together by hand with struct and the IPv4 header checksums are computed here, separately from
+# the Rust code, so a bug in one doesn't hide a bug in the other. Record i is stamped 1700000000 + i seconds and i
+# milliseconds.
+import os
+import socket
+import struct
+import sys
+
+
+def checksum(b):
+    if len(b) % 2:
+        b += b"\0"
+    s = sum(struct.unpack("!%dH" % (len(b) // 2), b))
+    while s >> 16:
+        s = (s & 0xFFFF) + (s >> 16)
+    return ~s & 0xFFFF
+
+
+def eth(dst, src, ethertype, payload, vlan=None):
+    h = bytes.fromhex(dst.replace(":", "")) + bytes.fromhex(src.replace(":", ""))
+    if vlan is not None:
+        h += struct.pack("!HH", 0x8100, vlan)
+    return h + struct.pack("!H", ethertype) + payload
+
+
+def ipv4(src, dst, proto, payload, ttl=64, ident=1, bad_checksum=False):
+    h = struct.pack(
+        "!BBHHHBBH4s4s",
+        0x45,
+        0,
+        20 + len(payload),
+        ident,
+        0,
+        ttl,
+        proto,
+        0,
+        socket.inet_aton(src),
+        socket.inet_aton(dst),
+    )
+    c = checksum(h)
+    if bad_checksum:
+        c ^= 0x1234
+    return h[:10] + struct.pack("!H", c) + h[12:] + payload
+
+
+def ipv6(src, dst, next_header, payload, hop_limit=64):
+    return (
+        struct.pack(
+            "!IHBB16s16s",
+            0x60000000,
+            len(payload),
+            next_header,
+            hop_limit,
+            socket.inet_pton(socket.AF_INET6, src),
+            socket.inet_pton(socket.AF_INET6, dst),
+        )
+        + payload
+    )
+
+
+def tcp(src_port, dst_port, seq, ack, flags, data=b""):
+    return struct.pack("!HHIIBBHHH", src_port, dst_port, seq, ack, 5 << 4, flags, 65535, 0, 0) + data
+
+
+def udp(src_port, dst_port, data):
+    return struct.pack("!HHHH", src_port, dst_port, 8 + len(data), 0) + data
+
+
+A = "02:00:00:00:00:01"
+B = "02:00:00:00:00:02"
+SYN, ACK, PSH, FIN = 0x02, 0x10, 0x08, 0x01
+DNS_QUERY = b"\x12\x34\x01\x00\x00\x01\x00\x00\x00\x00\x00\x00\x07example\x03com\x00\x00\x01\x00\x01"
+
+FRAMES = [
+    eth(B, A, 0x0800, ipv4("192.168.1.10", "93.184.216.34", 6, tcp(51000, 80, 1000, 0, SYN))),
+    eth(A, B, 0x0800, ipv4("93.184.216.34", "192.168.1.10", 6, tcp(80, 51000, 5000, 1001, SYN | ACK))),
+    eth(
+        B,
+        A,
+        0x0800,
+        ipv4(
+            "192.168.1.10",
+            "93.184.216.34",
+            6,
+            tcp(51000, 80, 1001, 5001, ACK | PSH, b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n"),
+        ),
+    ),
+    eth(
+        A,
+        B,
+        0x0800,
+        ipv4(
+            "93.184.216.34",
+            "192.168.1.10",
+            6,
+            tcp(80, 51000, 5001, 1039, ACK | PSH, b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello"),
+        ),
+    ),
+    eth(B, A, 0x0800, ipv4("192.168.1.10", "192.168.1.1", 17, udp(40000, 53, DNS_QUERY))),
+    eth(A, B, 0x0800, ipv4("192.168.1.1", "192.168.1.10", 17, udp(53, 40000, b"\x12\x34\x81\x80" + bytes(40)))),
+    eth(B, A, 0x86DD, ipv6("2001:db8::10", "2001:db8::1", 6, tcp(52000, 443, 1, 0, SYN))),
+    eth(B, A, 0x86DD, ipv6("2001:db8::10", "2001:db8::1", 6, tcp(52000, 443, 2, 1, ACK, b"\x16\x03\x01" + b"x" * 50))),
+    # a hop-by-hop options header in front of UDP
+    eth(B, A, 0x86DD, ipv6("fe80::1", "ff02::1", 0, bytes([17, 0, 1, 4, 0, 0, 0, 0]) + udp(5353, 5353, b"mdns!"))),
+    # UDP over IPv4 with a VLAN tag
+    eth(B, A, 0x0800, ipv4("10.0.0.5", "10.0.0.6", 17, udp(1234, 5678, b"tagged")), vlan=42),
+    # a broken IPv4 header checksum
+    eth(B, A, 0x0800, ipv4("192.168.1.10", "93.184.216.34", 6, tcp(51000, 80, 1039, 5045, ACK | FIN), bad_checksum=True)),
+    # ARP, not IP
+    eth("ff:ff:ff:ff:ff:ff", A, 0x0806, bytes.fromhex("0001080006040001") + bytes(20)),
+]
+
+
+# `byte_order` is "<" or ">" for struct; the magic number is written in that order, which is how readers tell
+def capture(frames, byte_order="<", nanos=False, snaplen=65535):
+    magic = 0xA1B23C4D if nanos else 0xA1B2C3D4
+    out = struct.pack(byte_order + "IHHiIII", magic, 2, 4, 0, 0, snaplen, 1)
+    for i, frame in enumerate(frames):
+        kept = frame[:snaplen]
+        frac = i * 1_000_000 if nanos else i * 1000
+        out += struct.pack(byte_order + "IIII", 1_700_000_000 + i, frac, len(kept), len(frame)) + kept
+    return out
+
+
+def main():
+    out_dir = sys.argv[1] if len(sys.argv) > 1 else os.path.join(os.path.dirname(__file__), "..", "assets", "pcap")
+    os.makedirs(out_dir, exist_ok=True)
+    files = {
+        "sample_le.pcap": capture(FRAMES),
+        "sample_be_nanos.pcap": capture(FRAMES, ">", nanos=True),
+        "snaplen_64.pcap": capture(FRAMES[:4], snaplen=64),
+        # cut off 10 bytes before the end of the third frame
+        "truncated.pcap": capture(FRAMES[:3])[:-10],
+    }
+    for name, data in files.items():
+        with open(os.path.join(out_dir, name), "wb") as f:
+            f.write(data)
+
+
+if __name__ == "__main__":
+    main()
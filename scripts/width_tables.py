@@ -0,0 +1,100 @@
+#!/usr/bin/env python3
+# Writes src/text/width_tables.rs from the Unicode character database:
+#
+#     python3 scripts/width_tables.py 16.0.0 > src/text/width_tables.rs
+#
+# The two files it needs are downloaded from unicode.org, or read from a directory laid out like the UCD
+# (UnicodeData.txt, EastAsianWidth.txt) given as a second argument. Use the same version as
+# scripts/grapheme_tables.py, wrap::display_width measures the clusters those tables find.
+#
+# ZERO_WIDTH is general category Mn, Me or Cf (but not U+00AD SOFT HYPHEN, which terminals draw as a hyphen) plus the
+# Hangul medial vowels and final consonants, which join the leading consonant before them into one syllable block.
+# WIDE is East_Asian_Width W or F. Both are lists of sorted, non-overlapping ranges for a binary search.
+import os
+import re
+import sys
+import urllib.request
+
+FILES = {
+    "data": "UnicodeData.txt",
+    "eaw": "EastAsianWidth.txt",
+}
+
+
+def read(version, local_dir, key):
+    if local_dir:
+        with open(os.path.join(local_dir, FILES[key]), encoding="utf-8") as f:
+            return f.read()
+    url = f"https://www.unicode.org/Public/{version}/ucd/{FILES[key]}"
+    with urllib.request.urlopen(url) as response:
+        return response.read().decode("utf-8")
+
+
+# Yields (first, last, [fields...]) for every data line, e.g. "3400..4DBF;W  # Lo [6592] ...". With missing=True
+# it reads the "# @missing: 20000..2FFFD; W" lines instead, the defaults for code points no data line lists.
+def entries(text, missing=False):
+    for line in text.splitlines():
+        if missing:
+            if not line.startswith("# @missing:"):
+                continue
+            line = line[len("# @missing:"):]
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        fields = [f.strip() for f in line.split(";")]
+        m = re.fullmatch(r"([0-9A-Fa-f]+)(?:\.\.([0-9A-Fa-f]+))?", fields[0])
+        first = int(m.group(1), 16)
+        last = int(m.group(2), 16) if m.group(2) else first
+        yield first, last, fields[1:]
+
+
+def ranges(points):
+    out = []
+    for c in sorted(points):
+        if out and out[-1][1] + 1 == c:
+            out[-1][1] = c
+        else:
+            out.append([c, c])
+    return out
+
+
+def char(c):
+    return f"'\\u{{{c:x}}}'"
+
+
+def main():
+    version = sys.argv[1]
+    local_dir = sys.argv[2] if len(sys.argv) > 2 else None
+
+    zero_width = set()
+    for c, _, (name, category, *_) in entries(read(version, local_dir, "data")):
+        # no mark or format character is part of a "<..., First>"/"<..., Last>" range, so single lines are enough
+        if category in ("Mn", "Me", "Cf") and c != 0xAD:
+            zero_width.add(c)
+        elif name.startswith(("HANGUL JUNGSEONG ", "HANGUL JONGSEONG ")):
+            zero_width.add(c)
+    eaw = read(version, local_dir, "eaw")
+    widths = {}
+    # unassigned code points in the CJK ideograph blocks and planes default to W, so they're counted as wide too
+    for missing in (True, False):
+        for first, last, fields in entries(eaw, missing):
+            for c in range(first, last + 1):
+                widths[c] = fields[0]
+    wide = {c for c, width in widths.items() if width in ("W", "F")}
+
+    out = [
+        f"// Generated by scripts/width_tables.py from the Unicode {version} character database, do not edit by hand.",
+        "// ZERO_WIDTH: general categories Mn, Me and Cf (combining marks, ZWJ, variation selectors...) plus the Hangul",
+        "// medial vowels and final consonants. WIDE: East_Asian_Width W or F (CJK, fullwidth forms, most emoji).",
+        "",
+        "pub const ZERO_WIDTH: &[(char, char)] = &[",
+    ]
+    out += [f"    ({char(lo)}, {char(hi)})," for lo, hi in ranges(zero_width)]
+    out += ["];", "", "pub const WIDE: &[(char, char)] = &["]
+    out += [f"    ({char(lo)}, {char(hi)})," for lo, hi in ranges(wide)]
+    out.append("];")
+    print("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -1,3 +1,4 @@
+use crate::text::diagnostics::{Diagnostic, span_of};
 use std::io;
 
 pub fn ex_1() -> Result<f64, String> {
@@ -16,24 +17,41 @@ pub fn ex_1() -> Result<f64, String> {
         .read_line(&mut temp_type)
         .expect("Failed to get the temperature type, please try again.");
 
+    // INFO: On bad input we point at the exact token that failed, see text::diagnostics
+    let temp_input = temp.trim_end();
     let temp: f64 = match temp.trim().parse() {
         Ok(num) => num,
         Err(_) => {
-            return Err("Please enter valid temperature value.".to_string());
+            let diagnostic = Diagnostic::error("Please enter valid temperature value.")
+                .with_label(
+                    span_of(temp_input, temp_input.trim()),
+                    "expected a number like 36.6",
+                )
+                .with_help("use a '.' for the decimal point and no unit, e.g. 98.6");
+            return Err(diagnostic.render("temperature", temp_input));
         }
     };
 
+    let type_input = temp_type.trim_end();
+    let invalid_type = || {
+        Diagnostic::error("Please enter valid temperature type.")
+            .with_label(
+                span_of(type_input, type_input.trim()),
+                "expected 'F' or 'C'",
+            )
+            .render("type", type_input)
+    };
     let temp_type: char = match temp_type.trim().parse() {
         Ok(val) => val,
         Err(_) => {
-            return Err("Please enter valid temperature type.".to_string());
+            return Err(invalid_type());
         }
     };
 
     match temp_type {
         'F' => Ok(((temp - 32.0) * 5.0) / 9.0),
         'C' => Ok(((temp * 9.0) / 5.0) + 32.0),
-        _ => Err("Please enter valid temperature type.".to_string()),
+        _ => Err(invalid_type()),
     }
 }
 
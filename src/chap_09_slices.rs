@@ -72,6 +72,8 @@ pub fn slices() {
     let a: [u8; 5] = [1, 2, 3, 4, 5];
     let slice: &[u8] = &a[1..3]; // slice of the array 'a', This slice has the type &[u8]. It works the same way as string slices do, by storing a reference to the first element and a length.
     assert_eq!(slice, &[2, 3]);
+
+    // INFO: first_word/second_word only understand ASCII spaces, see text::words for the Unicode-aware version (words, nth_word and byte spans)
 }
 
 pub fn find_first_words_index(s: &String) -> usize {
@@ -86,7 +88,7 @@ pub fn find_first_words_index(s: &String) -> usize {
 pub fn first_word(s: &String) -> &str {
     for (idx, &el) in s.as_bytes().iter().enumerate() {
         if el == b' ' {
-            return &s[0..idx + 1];
+            return &s[0..idx];
         }
     }
     return &s[..];
@@ -77,7 +77,9 @@ pub fn start() {
 
     // Tuple Structs
     struct Color(i32, i32, i32); // rgb
+    // INFO: color::rgb::Color keeps this shape but with u8 channels, so (300, -20, 0) can't happen.
     struct Point(i32, i32, i32); // (x,y,z) co-ordinates
+    // INFO: geometry::vec3 has Point3<T> and Vec3<T>, which also look the same but are kept apart the same way.
     let black = Color(0, 0, 0);
     let origin = Point(0, 0, 0);
     // Even though Point and Color look the same, but if you pass them as a parameter, a Point param won't accept Color and vice versa.
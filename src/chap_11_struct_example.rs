@@ -1,3 +1,5 @@
+use std::fmt;
+
 // Using proper struct to do the same thing
 #[derive(Debug)]
 struct Rectangle {
@@ -31,6 +33,9 @@ pub fn example() {
     println!("rect is {rect:?}");
     println!("rect is {rect:#?}"); // for pretty printing the info
 
+    // INFO: Now Rectangle does implement Display (at the bottom of this file), it gets drawn as a box with geometry::canvas
+    println!("rect is\n{rect}");
+
     // we can use the dbg! macro to print the line numbers along with the info! although, it takes the ownership of the value and then returns it to the caller and it prints to the `stderr` unlike println! which prints to the console: `stdout` - more on it later!
     let scale = 2;
     let rect = Rectangle {
@@ -57,3 +62,13 @@ fn area(dimensions: (u32, u32)) -> u32 {
 fn calculate_area(width: u32, height: u32) -> u32 {
     width * height
 }
+
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            crate::geometry::canvas::draw_sized(self.width, self.height)
+        )
+    }
+}
@@ -1,3 +1,4 @@
+// INFO: geometry::shapes has a shared Rectangle (and Square, Circle, Triangle, Polygon) behind one Shape trait
 #[derive(Debug)]
 struct Rectangle {
     width: u32,
@@ -15,7 +16,9 @@ impl Rectangle {
     }
 
     fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width >= other.width && self.height > other.height
+        // NOTE: Both sides have to be compared the same way, this used to be `>=` for the width but `>` for the height.
+        // geometry::rect has both flavours (can_hold and can_hold_strict) and a fit check that allows rotation.
+        self.width > other.width && self.height > other.height
     }
 
     fn square(size: u32) -> Self {
@@ -34,6 +34,7 @@ pub fn start() {
     #[derive(Debug)]
     enum IpAddrEnum {
         V4(String), // WARN: its a constructor and NOT a value now!
+        // INFO: these Strings accept anything, net::addr has IpAddr with strict parsing into real numbers.
         V6(String),
     }
 
@@ -59,6 +60,7 @@ pub fn start() {
         Write(String),              // Includes a single String
         ChangeColor(i32, i32, i32), // Includes three i32 values
     }
+    // INFO: message::codec has this same enum with a binary wire format and a text form (the `{:?}` output parsed back)
 
     // Above enum variants behave like different kinds of structs wrapped inside a single tagged union type!
     struct Quit; // unit struct - no data =  Constructor with no args!
@@ -85,6 +87,7 @@ pub fn start() {
 
     // INFO: we can also use "impl" keyword with enums just like structs!
 
+    // INFO: message::turtle gives call() a real job: each Message becomes a turtle-graphics command (see assets/house.turtle)
     impl Message {
         fn call(&self) {
             println!("self: {self:?}");
@@ -158,4 +161,5 @@ pub fn start() {
     // INFO: In order to handle the cases of Option<T> enum, we need some construct which runs different code when its None and some other code when Some(T) is valid and could help us with extracting the value from inside the Some variant. This is where the `match` control flow construct comes in!!
 }
 
+// INFO: a real router looks up the whole address, not just its kind: see net::route::RoutingTable::lookup.
 fn route(ip_kind: IpAddrKind) {}
@@ -74,4 +74,5 @@ pub fn start() {
     }
 
     // Getting grapheme clusters isn't supported by Rust out of the box, crates can be found online to do so.
+    // INFO: or see text::grapheme for our own implementation of the Unicode rules
 }
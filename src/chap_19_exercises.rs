@@ -19,6 +19,7 @@ Borrow:
 2. By definition since we are borrowing it, we use "references" to do so. Rust automatically dereferences for methods but NOT for associated functions (which don't contain the &Self type (or self shorthand) in their function signature) ,operators and expressions. We use '&' to denote references and '*' to dereference the values.
 3. We can have multiple number of immutable references(reading only) at once and use them at the same time, i.e., in overlapping lifetimes whereas for mutable references only one is allowed in case of overlapping lifetimes. Multiple mutable references cannot have overlapping lifetimes. Either one(mutable reference) with multiple immutable ones given their lifetimes don't overlap or none at all. I remember it simply like this: You cannot read(immutable reference) while you're writing(mutable reference) because you'll never be sure what's the correct data at a given time if both happen simultaneously.
 */
+use crate::text::diagnostics::{Diagnostic, span_of};
 use rand::{Rng, thread_rng};
 use std::{cmp::max, collections::HashMap, i32, io};
 pub fn ex1() {
@@ -110,6 +111,7 @@ pub fn ex3() {
                     .expect("Failed to get the command.");
                 let mut name: String = String::new();
                 let mut dept: String = String::new();
+                let mut dept_word: Option<&str> = None;
                 let mut counter: u8 = 2;
                 for word in command.split_whitespace().rev() {
                     if counter == 0 {
@@ -118,6 +120,7 @@ pub fn ex3() {
                     if word.to_lowercase() != "add" && word.to_lowercase() != "to" {
                         if counter == 2 {
                             dept = String::from(word);
+                            dept_word = Some(word);
                             counter -= 1;
                         } else if counter == 1 {
                             name = String::from(word);
@@ -126,7 +129,18 @@ pub fn ex3() {
                     }
                 }
                 if dept.is_empty() || name.is_empty() {
-                    println!("No valid department or name found in the command!");
+                    // INFO: Show which part of the command we couldn't make sense of, see text::diagnostics
+                    let line = command.trim_end();
+                    let mut diagnostic =
+                        Diagnostic::error("No valid department or name found in the command!");
+                    diagnostic = match dept_word {
+                        Some(word) => diagnostic
+                            .with_label(line.len()..line.len(), "expected `<name> to <department>`")
+                            .with_secondary_label(span_of(line, word), "only found this word"),
+                        None => diagnostic.with_label(0..line.len(), "no name or department here"),
+                    };
+                    let diagnostic = diagnostic.with_help("try `Add Sally to Engineering`");
+                    println!("{}", diagnostic.render("command", line));
                     continue;
                 }
                 map.entry(dept.to_lowercase())
@@ -0,0 +1,3 @@
+// Colours that grew out of the `Color(i32, i32, i32)` tuple struct in chap_10_structs.
+mod css_names;
+pub mod rgb;
@@ -0,0 +1,552 @@
+#![allow(dead_code)]
+use super::css_names::CSS_NAMES;
+use std::fmt;
+use std::str::FromStr;
+
+// chap_10_structs has `struct Color(i32, i32, i32)`, which happily holds (300, -20, 0). This one keeps the same
+// tuple shape but with u8 channels, so every value is a real colour; anything built from bigger numbers is
+// clamped into 0..=255 first.
+//
+// HSL and HSV use hue in degrees (0..360) and the other two parts as fractions (0.0..=1.0), like CSS does.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    Empty,
+    // "#ff88" has neither 3 nor 6 digits
+    BadLength(usize),
+    BadDigit(char),
+    UnknownName(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::Empty => write!(f, "empty colour"),
+            ParseColorError::BadLength(len) => {
+                write!(f, "hex colours have 3 or 6 digits, found {len}")
+            }
+            ParseColorError::BadDigit(c) => write!(f, "'{c}' is not a hex digit"),
+            ParseColorError::UnknownName(name) => write!(f, "unknown colour name '{name}'"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+fn clamp_channel(value: i32) -> u8 {
+    value.clamp(0, 255) as u8
+}
+
+fn unit_to_channel(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+// WCAG's sRGB -> linear light conversion
+fn linearize(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Hue in degrees from the largest channel, shared by HSL and HSV
+fn hue(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    if delta == 0.0 {
+        return 0.0;
+    }
+    let h = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    h * 60.0
+}
+
+// Builds a colour from hue, chroma and the amount added to every channel, which is how both HSL and HSV end up
+fn from_hue_chroma(h: f64, chroma: f64, m: f64) -> Color {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Color(
+        unit_to_channel(r + m),
+        unit_to_channel(g + m),
+        unit_to_channel(b + m),
+    )
+}
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const WHITE: Color = Color(255, 255, 255);
+
+    // Out of range values are clamped, so (300, -20, 0) becomes (255, 0, 0)
+    pub fn from_i32(r: i32, g: i32, b: i32) -> Self {
+        Color(clamp_channel(r), clamp_channel(g), clamp_channel(b))
+    }
+
+    fn units(self) -> (f64, f64, f64) {
+        (
+            self.0 as f64 / 255.0,
+            self.1 as f64 / 255.0,
+            self.2 as f64 / 255.0,
+        )
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    // "#ff8800", "ff8800", and the short form "#f80"
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.trim().trim_start_matches('#');
+        if digits.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        if let Some(bad) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ParseColorError::BadDigit(bad));
+        }
+        let value = |s: &str| u8::from_str_radix(s, 16).unwrap();
+        match digits.len() {
+            3 => {
+                // each digit is doubled: f -> ff
+                let d: Vec<u8> = digits.chars().map(|c| value(&c.to_string()) * 17).collect();
+                Ok(Color(d[0], d[1], d[2]))
+            }
+            6 => Ok(Color(
+                value(&digits[0..2]),
+                value(&digits[2..4]),
+                value(&digits[4..6]),
+            )),
+            len => Err(ParseColorError::BadLength(len)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        CSS_NAMES
+            .binary_search_by(|(n, _)| n.cmp(&name.as_str()))
+            .ok()
+            .map(|i| {
+                let (r, g, b) = CSS_NAMES[i].1;
+                Color(r, g, b)
+            })
+    }
+
+    // The CSS name of exactly this colour, if it has one
+    pub fn name(self) -> Option<&'static str> {
+        CSS_NAMES
+            .iter()
+            .find(|(_, rgb)| *rgb == (self.0, self.1, self.2))
+            .map(|(name, _)| *name)
+    }
+
+    // The CSS name of the closest colour (plain distance in RGB, good enough to describe a colour)
+    pub fn nearest_name(self) -> &'static str {
+        let distance = |(r, g, b): (u8, u8, u8)| {
+            let dr = r as i32 - self.0 as i32;
+            let dg = g as i32 - self.1 as i32;
+            let db = b as i32 - self.2 as i32;
+            dr * dr + dg * dg + db * db
+        };
+        CSS_NAMES
+            .iter()
+            .min_by_key(|(_, rgb)| distance(*rgb))
+            .map(|(name, _)| *name)
+            .unwrap()
+    }
+
+    pub fn to_hsl(self) -> Hsl {
+        let (r, g, b) = self.units();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl {
+            h: hue(r, g, b, max, delta),
+            s,
+            l,
+        }
+    }
+
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue_chroma(h, chroma, l - chroma / 2.0)
+    }
+
+    pub fn to_hsv(self) -> Hsv {
+        let (r, g, b) = self.units();
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        Hsv {
+            h: hue(r, g, b, max, delta),
+            s: if max == 0.0 { 0.0 } else { delta / max },
+            v: max,
+        }
+    }
+
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
+        let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+        let chroma = v * s;
+        from_hue_chroma(h, chroma, v - chroma)
+    }
+
+    // t = 0.0 gives self, t = 1.0 gives other
+    pub fn lerp(self, other: Color, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Color(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    // `steps` colours from self to other, both ends included
+    pub fn gradient(self, other: Color, steps: usize) -> Vec<Color> {
+        match steps {
+            0 => Vec::new(),
+            1 => vec![self],
+            _ => (0..steps)
+                .map(|i| self.lerp(other, i as f64 / (steps - 1) as f64))
+                .collect(),
+        }
+    }
+
+    // Paints self on top of `background` like a layer in an image editor, with `opacity` from 0.0 to 1.0
+    pub fn blend(self, background: Color, mode: BlendMode, opacity: f64) -> Self {
+        let channel = |top: u8, bottom: u8| {
+            let (a, b) = (bottom as f64 / 255.0, top as f64 / 255.0);
+            let blended = match mode {
+                BlendMode::Normal => b,
+                BlendMode::Multiply => a * b,
+                BlendMode::Screen => 1.0 - (1.0 - a) * (1.0 - b),
+                BlendMode::Overlay => {
+                    if a < 0.5 {
+                        2.0 * a * b
+                    } else {
+                        1.0 - 2.0 * (1.0 - a) * (1.0 - b)
+                    }
+                }
+            };
+            unit_to_channel(blended)
+        };
+        let layer = Color(
+            channel(self.0, background.0),
+            channel(self.1, background.1),
+            channel(self.2, background.2),
+        );
+        background.lerp(layer, opacity)
+    }
+
+    // WCAG 2 relative luminance: 0.0 for black, 1.0 for white
+    pub fn luminance(self) -> f64 {
+        0.2126 * linearize(self.0) + 0.7152 * linearize(self.1) + 0.0722 * linearize(self.2)
+    }
+
+    // From 1:1 (the same colour) to 21:1 (black on white)
+    pub fn contrast_ratio(self, other: Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    // WCAG AA asks for 4.5:1 for normal text and 3:1 for large text, AAA for 7:1 and 4.5:1
+    pub fn wcag_level(self, other: Color, large_text: bool) -> &'static str {
+        let ratio = self.contrast_ratio(other);
+        let (aa, aaa) = if large_text { (3.0, 4.5) } else { (4.5, 7.0) };
+        if ratio >= aaa {
+            "AAA"
+        } else if ratio >= aa {
+            "AA"
+        } else {
+            "fail"
+        }
+    }
+
+    // Black or white, whichever reads better on top of this colour
+    pub fn readable_text(self) -> Color {
+        if self.contrast_ratio(Color::BLACK) >= self.contrast_ratio(Color::WHITE) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+
+    // The closest of the 256 xterm colours: either the 6x6x6 cube (16..=231) or the grey ramp (232..=255)
+    pub fn to_ansi256(self) -> u8 {
+        const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        let nearest_level = |c: u8| {
+            (0..6)
+                .min_by_key(|&i| (LEVELS[i] as i32 - c as i32).abs())
+                .unwrap()
+        };
+        let (ri, gi, bi) = (
+            nearest_level(self.0),
+            nearest_level(self.1),
+            nearest_level(self.2),
+        );
+        let cube = Color(LEVELS[ri], LEVELS[gi], LEVELS[bi]);
+
+        let average = (self.0 as u32 + self.1 as u32 + self.2 as u32) / 3;
+        let grey_index = ((average as i32 - 8) / 10).clamp(0, 23);
+        let level = (8 + grey_index * 10) as u8;
+        let grey = Color(level, level, level);
+
+        let distance = |c: Color| {
+            let dr = c.0 as i32 - self.0 as i32;
+            let dg = c.1 as i32 - self.1 as i32;
+            let db = c.2 as i32 - self.2 as i32;
+            dr * dr + dg * dg + db * db
+        };
+        if distance(grey) < distance(cube) {
+            232 + grey_index as u8
+        } else {
+            16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+        }
+    }
+
+    pub fn ansi256_fg(self) -> String {
+        format!("\x1b[38;5;{}m", self.to_ansi256())
+    }
+
+    pub fn ansi256_bg(self) -> String {
+        format!("\x1b[48;5;{}m", self.to_ansi256())
+    }
+
+    pub fn truecolor_fg(self) -> String {
+        format!("\x1b[38;2;{};{};{}m", self.0, self.1, self.2)
+    }
+
+    pub fn truecolor_bg(self) -> String {
+        format!("\x1b[48;2;{};{};{}m", self.0, self.1, self.2)
+    }
+
+    // A block of this colour with its hex code written in readable text on top
+    pub fn swatch(self, truecolor: bool) -> String {
+        let (bg, fg) = if truecolor {
+            (self.truecolor_bg(), self.readable_text().truecolor_fg())
+        } else {
+            (self.ansi256_bg(), self.readable_text().ansi256_fg())
+        };
+        format!("{bg}{fg} {} \x1b[0m", self.to_hex())
+    }
+}
+
+impl From<(i32, i32, i32)> for Color {
+    fn from((r, g, b): (i32, i32, i32)) -> Self {
+        Color::from_i32(r, g, b)
+    }
+}
+
+// Hex codes or CSS names: "#ff8800", "f80", "rebeccapurple"
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        if let Some(color) = Color::from_name(s) {
+            return Ok(color);
+        }
+        if s.starts_with('#') || s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Color::from_hex(s);
+        }
+        Err(ParseColorError::UnknownName(s.to_string()))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+pub fn start() {
+    // The black from chap_10_structs, and what clamping does to values that don't fit
+    let black = Color::from((0, 0, 0));
+    let clamped = Color::from((300, -20, 128));
+    println!("{black} {clamped} ({:?})", clamped);
+
+    let orange: Color = "#ff8800".parse().unwrap();
+    let hsl = orange.to_hsl();
+    let hsv = orange.to_hsv();
+    println!(
+        "{orange}: hsl({:.0}, {:.0}%, {:.0}%), hsv({:.0}, {:.0}%, {:.0}%), looks like {}",
+        hsl.h,
+        hsl.s * 100.0,
+        hsl.l * 100.0,
+        hsv.h,
+        hsv.s * 100.0,
+        hsv.v * 100.0,
+        orange.nearest_name()
+    );
+
+    // Swatches, 256 colours and truecolour
+    let palette = [
+        "rebeccapurple",
+        "tomato",
+        "gold",
+        "seagreen",
+        "steelblue",
+        "#f80",
+    ];
+    for truecolor in [false, true] {
+        let row: Vec<String> = palette
+            .iter()
+            .map(|name| name.parse::<Color>().unwrap().swatch(truecolor))
+            .collect();
+        println!("{}", row.join(" "));
+    }
+    let rainbow: String = (0..36)
+        .map(|i| {
+            format!(
+                "{} \x1b[0m",
+                Color::from_hsl(i as f64 * 10.0, 0.8, 0.5).truecolor_bg()
+            )
+        })
+        .collect();
+    println!("{rainbow}");
+    let fade: String = Color::from_name("navy")
+        .unwrap()
+        .gradient(Color::from_name("gold").unwrap(), 36)
+        .iter()
+        .map(|c| format!("{} \x1b[0m", c.ansi256_bg()))
+        .collect();
+    println!("{fade}");
+
+    for (text, background) in [("black", "white"), ("white", "gold"), ("navy", "lightgray")] {
+        let (t, b): (Color, Color) = (text.parse().unwrap(), background.parse().unwrap());
+        println!(
+            "{text} on {background}: {:.2}:1, {} for normal text",
+            t.contrast_ratio(b),
+            t.wcag_level(b, false)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Color::from((300, -20, 128)), Color(255, 0, 128));
+        let orange: Color = "#ff8800".parse().unwrap();
+        assert_eq!(orange.to_hex(), "#ff8800");
+        assert_eq!(Color::from_hex("#F80"), Ok(orange));
+        assert_eq!(Color::from_hex("#ff88"), Err(ParseColorError::BadLength(4)));
+        assert_eq!(
+            Color::from_hex("#ff88zz"),
+            Err(ParseColorError::BadDigit('z'))
+        );
+        assert_eq!(
+            "blurple".parse::<Color>(),
+            Err(ParseColorError::UnknownName("blurple".to_string()))
+        );
+        assert_eq!("RebeccaPurple".parse::<Color>(), Ok(Color(102, 51, 153)));
+        assert_eq!(Color(102, 51, 153).name(), Some("rebeccapurple"));
+        assert_eq!(CSS_NAMES.len(), 148);
+        assert!(CSS_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn hsl_and_hsv() {
+        let hsl = Color(255, 136, 0).to_hsl();
+        assert!(close(hsl.h, 32.0) && close(hsl.s, 1.0) && close(hsl.l, 0.5));
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color(255, 0, 0));
+        assert_eq!(Color::from_hsl(120.0, 1.0, 0.25), Color(0, 128, 0));
+        assert_eq!(Color::from_hsv(240.0, 1.0, 1.0), Color(0, 0, 255));
+        assert_eq!(
+            Color::from_hsl(-120.0, 1.0, 0.5),
+            Color::from_hsl(240.0, 1.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn contrast_and_blending() {
+        let orange = Color(255, 136, 0);
+        assert!(close(Color::BLACK.contrast_ratio(Color::WHITE), 21.0));
+        assert!(close(orange.contrast_ratio(orange), 1.0));
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.5), Color(128, 128, 128));
+        assert_eq!(orange.lerp(Color::BLACK, 0.0), orange);
+        assert_eq!(orange.lerp(Color::BLACK, 1.0), Color::BLACK);
+        assert_eq!(Color::WHITE.blend(orange, BlendMode::Multiply, 1.0), orange);
+        assert_eq!(Color::BLACK.blend(orange, BlendMode::Screen, 1.0), orange);
+        assert_eq!(
+            Color::WHITE.blend(Color::BLACK, BlendMode::Normal, 0.0),
+            Color::BLACK
+        );
+    }
+
+    #[test]
+    fn terminal_codes() {
+        assert_eq!(Color(255, 0, 0).to_ansi256(), 196);
+        assert_eq!(Color::WHITE.to_ansi256(), 231);
+        assert_eq!(Color(128, 128, 128).to_ansi256(), 244);
+        assert_eq!(Color(255, 136, 0).truecolor_fg(), "\x1b[38;2;255;136;0m");
+    }
+
+    // Hex round trips exactly, HSL and HSV get back to the same 8-bit channels, and readable_text always
+    // passes WCAG AA for normal text
+    #[test]
+    fn random_round_trips() {
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let c = Color(rng.r#gen(), rng.r#gen(), rng.r#gen());
+            assert_eq!(Color::from_hex(&c.to_hex()), Ok(c));
+            let hsl = c.to_hsl();
+            assert_eq!(Color::from_hsl(hsl.h, hsl.s, hsl.l), c);
+            let hsv = c.to_hsv();
+            assert_eq!(Color::from_hsv(hsv.h, hsv.s, hsv.v), c);
+            let ratio = c.contrast_ratio(c.readable_text());
+            assert!(ratio >= 4.5, "{c} only gets {ratio:.2}:1");
+        }
+    }
+}
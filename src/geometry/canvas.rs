@@ -0,0 +1,782 @@
+#![allow(dead_code)]
+use super::rect::Rect;
+use crate::text::wrap::char_width;
+use std::env;
+use std::fmt;
+
+// A grid of character cells to draw shapes on, so we can see a Rectangle instead of reading its {:#?} output.
+//
+// Drawing happens in world units which the canvas maps to cells. A terminal cell is about twice as tall as it is
+// wide, so by default one world unit is one column but only half a row: that way a 10x10 square is drawn 10
+// columns wide and 5 rows tall and actually looks square. `Canvas::fit` picks a smaller scale when the shapes
+// are wider or taller than the terminal.
+//
+// Horizontal and vertical lines are stored as "which sides of this cell does a line leave through" rather than as
+// characters, so where two rectangles cross we get ┼, ├ or ┬ instead of one line overwriting the other.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl AnsiColor {
+    // SGR foreground codes 30-37
+    fn code(self) -> u8 {
+        30 + self as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineStyle {
+    Light,
+    Heavy,
+    Double,
+    Rounded,
+    Ascii,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fill {
+    Solid,
+    LightShade,
+    MediumShade,
+    DarkShade,
+    Dots,
+    Hatch,
+    Checker,
+    Char(char),
+}
+
+impl Fill {
+    fn char_at(self, x: i32, y: i32) -> char {
+        match self {
+            Fill::Solid => '█',
+            Fill::LightShade => '░',
+            Fill::MediumShade => '▒',
+            Fill::DarkShade => '▓',
+            Fill::Dots => '·',
+            Fill::Hatch => '/',
+            Fill::Checker => {
+                if (x + y) % 2 == 0 {
+                    '█'
+                } else {
+                    ' '
+                }
+            }
+            Fill::Char(c) => c,
+        }
+    }
+}
+
+// The sides a line leaves a cell through
+const UP: u8 = 1;
+const RIGHT: u8 = 2;
+const DOWN: u8 = 4;
+const LEFT: u8 = 8;
+
+// Indexed by the UP | RIGHT | DOWN | LEFT bits
+const LIGHT: [char; 16] = [
+    ' ', '╵', '╶', '└', '╷', '│', '┌', '├', '╴', '┘', '─', '┴', '┐', '┤', '┬', '┼',
+];
+const HEAVY: [char; 16] = [
+    ' ', '╹', '╺', '┗', '╻', '┃', '┏', '┣', '╸', '┛', '━', '┻', '┓', '┫', '┳', '╋',
+];
+// There are no half-lines in the double set, so line ends use the full ones
+const DOUBLE: [char; 16] = [
+    ' ', '║', '═', '╚', '║', '║', '╔', '╠', '═', '╝', '═', '╩', '╗', '╣', '╦', '╬',
+];
+const ROUNDED: [char; 16] = [
+    ' ', '╵', '╶', '╰', '╷', '│', '╭', '├', '╴', '╯', '─', '┴', '╮', '┤', '┬', '┼',
+];
+const ASCII: [char; 16] = [
+    ' ', '|', '-', '+', '|', '|', '+', '+', '-', '+', '-', '+', '+', '+', '+', '+',
+];
+
+impl LineStyle {
+    fn char_for(self, sides: u8) -> char {
+        let table = match self {
+            LineStyle::Light => &LIGHT,
+            LineStyle::Heavy => &HEAVY,
+            LineStyle::Double => &DOUBLE,
+            LineStyle::Rounded => &ROUNDED,
+            LineStyle::Ascii => &ASCII,
+        };
+        table[(sides & 0b1111) as usize]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cell {
+    // a plain character, drawn instead of the lines if set
+    ch: Option<char>,
+    // the right half of a wide character in the cell before, which already covers this column
+    tail: bool,
+    sides: u8,
+    style: LineStyle,
+    color: Option<AnsiColor>,
+}
+
+const EMPTY: Cell = Cell {
+    ch: None,
+    tail: false,
+    sides: 0,
+    style: LineStyle::Light,
+    color: None,
+};
+
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    // private so they always match cells.len()
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    // cells per world unit
+    scale_x: f64,
+    scale_y: f64,
+    // what the next drawing calls use
+    pub style: LineStyle,
+    pub color: Option<AnsiColor>,
+    pub point_char: char,
+}
+
+// No canvas gets more cells than this (a 1024x1024 one, say) however big it's asked to be; drawing off the edge is
+// clipped anyway
+const MAX_CELLS: usize = 1 << 20;
+
+fn env_size(name: &str, default: usize) -> usize {
+    env::var(name)
+        .ok()
+        .and_then(|c| c.parse().ok())
+        .filter(|&c| c > 0)
+        .unwrap_or(default)
+}
+
+// The terminal width from $COLUMNS (set by most shells), or the classic 80
+pub fn terminal_width() -> usize {
+    env_size("COLUMNS", 80)
+}
+
+// The terminal height from $LINES, or the classic 24
+pub fn terminal_height() -> usize {
+    env_size("LINES", 24)
+}
+
+impl Canvas {
+    // `width` x `height` cells, one world unit per column and two per row
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_scale(width, height, 1.0, 0.5)
+    }
+
+    // The size is cut down to MAX_CELLS, width first
+    pub fn with_scale(width: usize, height: usize, scale_x: f64, scale_y: f64) -> Self {
+        let width = width.min(MAX_CELLS);
+        let height = height.min(MAX_CELLS / width.max(1));
+        Self {
+            width,
+            height,
+            cells: vec![EMPTY; width * height],
+            scale_x,
+            scale_y,
+            style: LineStyle::Light,
+            color: None,
+            point_char: '*',
+        }
+    }
+
+    // A canvas big enough for a world of `world_width` x `world_height` units, scaled down (never up) so it's at
+    // most `max_columns` wide and `max_rows` tall. Both axes get the same scale so shapes keep their proportions.
+    pub fn fit(world_width: u32, world_height: u32, max_columns: usize, max_rows: usize) -> Self {
+        let scale = (max_columns as f64 / world_width as f64)
+            .min(2.0 * max_rows as f64 / world_height as f64)
+            .min(1.0);
+        let width = (world_width as f64 * scale).round().max(1.0) as usize;
+        let height = (world_height as f64 * scale / 2.0).round().max(1.0) as usize;
+        Self::with_scale(width, height, scale, scale / 2.0)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Cell coordinates of a world point. It takes i64 so a far corner (x + width) can't overflow on the way in;
+    // anything past the i32 range comes out clamped to it, which is off the canvas either way.
+    fn map(&self, x: i64, y: i64) -> (i32, i32) {
+        (
+            (x as f64 * self.scale_x).round() as i32,
+            (y as f64 * self.scale_y).round() as i32,
+        )
+    }
+
+    fn cell_mut(&mut self, x: i32, y: i32) -> Option<&mut Cell> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        let idx = y as usize * self.width + x as usize;
+        // Drawing over either half of a wide character leaves the other half empty. A tail is never in the first
+        // column, so the cell before it is on the same row.
+        if self.cells[idx].tail {
+            self.cells[idx].tail = false;
+            self.cells[idx - 1].ch = None;
+        } else if x as usize + 1 < self.width && self.cells[idx + 1].tail {
+            self.cells[idx + 1].tail = false;
+        }
+        Some(&mut self.cells[idx])
+    }
+
+    // Plots a character at cell coordinates, anything outside the canvas is clipped
+    pub fn put(&mut self, x: i32, y: i32, ch: char) {
+        let color = self.color;
+        if let Some(cell) = self.cell_mut(x, y) {
+            *cell = Cell {
+                ch: Some(ch),
+                tail: false,
+                sides: 0,
+                style: cell.style,
+                color,
+            };
+        }
+    }
+
+    fn add_sides(&mut self, x: i32, y: i32, sides: u8) {
+        let (style, color) = (self.style, self.color);
+        if let Some(cell) = self.cell_mut(x, y) {
+            if cell.ch.is_some() {
+                cell.ch = None;
+                cell.sides = 0;
+            }
+            cell.sides |= sides;
+            cell.style = style;
+            cell.color = color;
+        }
+    }
+
+    // Lines in cell coordinates
+    fn hline_cells(&mut self, x0: i32, x1: i32, y: i32) {
+        let (x0, x1) = (x0.min(x1), x0.max(x1));
+        // only the part on the canvas, so a line a billion cells long doesn't take a billion steps
+        for x in x0.max(-1)..=x1.min(self.width as i32) {
+            let mut sides = 0;
+            if x > x0 {
+                sides |= LEFT;
+            }
+            if x < x1 {
+                sides |= RIGHT;
+            }
+            self.add_sides(x, y, if sides == 0 { LEFT | RIGHT } else { sides });
+        }
+    }
+
+    fn vline_cells(&mut self, x: i32, y0: i32, y1: i32) {
+        let (y0, y1) = (y0.min(y1), y0.max(y1));
+        for y in y0.max(-1)..=y1.min(self.height as i32) {
+            let mut sides = 0;
+            if y > y0 {
+                sides |= UP;
+            }
+            if y < y1 {
+                sides |= DOWN;
+            }
+            self.add_sides(x, y, if sides == 0 { UP | DOWN } else { sides });
+        }
+    }
+
+    // The cells covered by a world rectangle: from its top-left corner up to (not including) its bottom-right one,
+    // but always at least one cell
+    fn cell_box(&self, x: i64, y: i64, width: u32, height: u32) -> (i32, i32, i32, i32) {
+        let (left, top) = self.map(x, y);
+        let (right, bottom) = self.map(
+            x.saturating_add(width.into()),
+            y.saturating_add(height.into()),
+        );
+        (
+            left,
+            top,
+            right.saturating_sub(1).max(left),
+            bottom.saturating_sub(1).max(top),
+        )
+    }
+
+    pub fn rect(&mut self, x: i64, y: i64, width: u32, height: u32) {
+        let (left, top, right, bottom) = self.cell_box(x, y, width, height);
+        if left == right && top == bottom {
+            self.put(left, top, self.point_char);
+            return;
+        }
+        self.hline_cells(left, right, top);
+        self.hline_cells(left, right, bottom);
+        self.vline_cells(left, top, bottom);
+        self.vline_cells(right, top, bottom);
+    }
+
+    pub fn square(&mut self, x: i64, y: i64, size: u32) {
+        self.rect(x, y, size, size);
+    }
+
+    pub fn fill_rect(&mut self, x: i64, y: i64, width: u32, height: u32, fill: Fill) {
+        let (left, top, right, bottom) = self.cell_box(x, y, width, height);
+        for cy in top.max(0)..=bottom.min(self.height as i32) {
+            for cx in left.max(0)..=right.min(self.width as i32) {
+                self.put(cx, cy, fill.char_at(cx, cy));
+            }
+        }
+    }
+
+    // The part of a line in (unrounded) cell coordinates that crosses the canvas, give or take a cell, found with
+    // the Liang-Barsky algorithm; None if it misses. Lines reaching far off the edge would otherwise take billions
+    // of Bresenham steps, or bend where their ends got clamped to the i32 range.
+    fn clip_cells(&self, from: (f64, f64), to: (f64, f64)) -> Option<((f64, f64), (f64, f64))> {
+        let (left, top, right, bottom) = (-1.0, -1.0, self.width as f64, self.height as f64);
+        let inside =
+            |(x, y): (f64, f64)| (left..=right).contains(&x) && (top..=bottom).contains(&y);
+        if inside(from) && inside(to) {
+            return Some((from, to));
+        }
+        let (x0, y0) = from;
+        let (dx, dy) = (to.0 - x0, to.1 - y0);
+        let (mut t0, mut t1) = (0.0f64, 1.0f64);
+        for (p, q) in [
+            (-dx, x0 - left),
+            (dx, right - x0),
+            (-dy, y0 - top),
+            (dy, bottom - y0),
+        ] {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+            } else if p < 0.0 {
+                t0 = t0.max(q / p);
+            } else {
+                t1 = t1.min(q / p);
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+        let at = |t: f64| (x0 + t * dx, y0 + t * dy);
+        Some((at(t0), at(t1)))
+    }
+
+    // Axis-aligned lines use the box-drawing characters (and join up with rectangles), anything else is drawn
+    // with Bresenham's algorithm using ─ │ ╱ ╲ depending on which way each step goes. The ends are world points in
+    // i64 so a drawing can be shifted anywhere without overflowing.
+    pub fn line(&mut self, x0: i64, y0: i64, x1: i64, y1: i64) {
+        let scaled = |x: i64, y: i64| (x as f64 * self.scale_x, y as f64 * self.scale_y);
+        let Some((from, to)) = self.clip_cells(scaled(x0, y0), scaled(x1, y1)) else {
+            return;
+        };
+        let cell = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        let ((x0, y0), (x1, y1)) = (cell(from), cell(to));
+        if y0 == y1 {
+            return self.hline_cells(x0, x1, y0);
+        }
+        if x0 == x1 {
+            return self.vline_cells(x0, y0, y1);
+        }
+
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let (sx, sy) = (if x0 < x1 { 1 } else { -1 }, if y0 < y1 { 1 } else { -1 });
+        let (mut x, mut y) = (x0, y0);
+        let mut err = dx + dy;
+        loop {
+            let (px, py) = (x, y);
+            let done = x == x1 && y == y1;
+            let (mut moved_x, mut moved_y) = (false, false);
+            if !done {
+                let e2 = 2 * err;
+                if e2 >= dy {
+                    err += dy;
+                    x += sx;
+                    moved_x = true;
+                }
+                if e2 <= dx {
+                    err += dx;
+                    y += sy;
+                    moved_y = true;
+                }
+            }
+            let ch = match (moved_x, moved_y) {
+                (true, false) => '─',
+                (false, true) => '│',
+                // the last cell leans the same way as the whole line
+                _ if (sx > 0) == (sy > 0) => '╲',
+                _ => '╱',
+            };
+            self.put(px, py, ch);
+            if done {
+                break;
+            }
+        }
+    }
+
+    // The outline of an ellipse around cell (x, y): the nearest cell to it in every column it crosses and in every
+    // row, mirrored into all four quadrants. Between them the columns and rows leave no gaps, however flat or steep
+    // the outline gets. Only the columns and rows on the canvas are looked at, so a circle far bigger than the canvas
+    // costs no more than a small one.
+    fn ellipse(&mut self, x: i32, y: i32, rx: i64, ry: i64) {
+        let (x, y) = (i64::from(x), i64::from(y));
+        // how far the outline is from the centre along one axis, `d` away from it along the other
+        let reach = |r: i64, d: i64, other: i64| {
+            let t = d as f64 / other as f64;
+            (r as f64 * (1.0 - t * t).max(0.0).sqrt()).round() as i64
+        };
+        let (width, height) = (self.width as i64, self.height as i64);
+        // anything off the canvas is moved to just outside it, where put() drops it
+        let cell = |v: i64, size: i64| v.clamp(-1, size) as i32;
+        for cx in x.saturating_sub(rx).max(0)..=x.saturating_add(rx).min(width - 1) {
+            let dy = if rx == 0 { ry } else { reach(ry, cx - x, rx) };
+            for cy in [y - dy, y + dy] {
+                self.put(cx as i32, cell(cy, height), self.point_char);
+            }
+        }
+        for cy in y.saturating_sub(ry).max(0)..=y.saturating_add(ry).min(height - 1) {
+            let dx = if ry == 0 { rx } else { reach(rx, cy - y, ry) };
+            for cx in [x - dx, x + dx] {
+                self.put(cell(cx, width), cy as i32, self.point_char);
+            }
+        }
+    }
+
+    // `r` is in world units, so the circle looks round on screen
+    pub fn circle(&mut self, cx: i64, cy: i64, r: u32) {
+        let (x, y) = self.map(cx, cy);
+        let rx = (r as f64 * self.scale_x).round() as i64;
+        let ry = (r as f64 * self.scale_y).round() as i64;
+        self.ellipse(x, y, rx, ry);
+    }
+
+    pub fn fill_circle(&mut self, cx: i64, cy: i64, r: u32, fill: Fill) {
+        let (x, y) = self.map(cx, cy);
+        let rx = (r as f64 * self.scale_x).max(0.5);
+        let ry = (r as f64 * self.scale_y).max(0.5);
+        let (rows, columns) = (ry.ceil() as i32, rx.ceil() as i32);
+        let (top, bottom) = (y.saturating_sub(rows).max(0), y.saturating_add(rows));
+        let (left, right) = (x.saturating_sub(columns).max(0), x.saturating_add(columns));
+        for py in top..=bottom.min(self.height as i32) {
+            for px in left..=right.min(self.width as i32) {
+                let nx = (px as f64 - x as f64) / rx;
+                let ny = (py as f64 - y as f64) / ry;
+                if nx * nx + ny * ny <= 1.0 {
+                    self.put(px, py, fill.char_at(px, py));
+                }
+            }
+        }
+    }
+
+    // Plots a character two columns wide: the cell after it is marked as covered, so rendering leaves it out.
+    // One that would hang off either edge is clipped whole.
+    fn put_wide(&mut self, x: i32, y: i32, ch: char) {
+        if x < 0 || x as usize + 1 >= self.width {
+            return;
+        }
+        self.put(x, y, ch);
+        let color = self.color;
+        if let Some(cell) = self.cell_mut(x + 1, y) {
+            *cell = Cell {
+                ch: None,
+                tail: true,
+                sides: 0,
+                style: cell.style,
+                color,
+            };
+        }
+    }
+
+    // Text in cell coordinates starting at a world point, e.g. a label inside a rectangle
+    pub fn text(&mut self, x: i64, y: i64, text: &str) {
+        let (mut x, y) = self.map(x, y);
+        for (c, width) in text_chars(text) {
+            if width == 2 {
+                self.put_wide(x, y, c);
+            } else {
+                self.put(x, y, c);
+            }
+            x = x.saturating_add(width as i32);
+        }
+    }
+
+    fn cell_char(cell: &Cell) -> char {
+        match cell.ch {
+            Some(c) => c,
+            None => cell.style.char_for(cell.sides),
+        }
+    }
+
+    // Plain text, one line per row with trailing spaces removed
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.chunks(self.width.max(1)) {
+            let line: String = row
+                .iter()
+                .filter(|cell| !cell.tail)
+                .map(Self::cell_char)
+                .collect();
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        out
+    }
+
+    // With colours: an escape sequence whenever the colour changes and a reset at the end of each row
+    pub fn render_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.chunks(self.width.max(1)) {
+            let mut current = None;
+            let mut line = String::new();
+            for cell in row.iter().filter(|cell| !cell.tail) {
+                if cell.color != current {
+                    match cell.color {
+                        Some(color) => line.push_str(&format!("\x1b[{}m", color.code())),
+                        None => line.push_str("\x1b[0m"),
+                    }
+                    current = cell.color;
+                }
+                line.push(Self::cell_char(cell));
+            }
+            if current.is_some() {
+                line.push_str("\x1b[0m");
+            }
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+// The characters Canvas::text draws and how many columns each takes. Control characters are written escaped
+// (a newline as \n) so they can't break up the grid, and a cell only holds one character, so combining marks and
+// other zero-width characters are left out.
+fn text_chars(text: &str) -> impl Iterator<Item = (char, usize)> + '_ {
+    text.chars()
+        .flat_map(|c| -> Vec<(char, usize)> {
+            if c.is_control() {
+                c.escape_default().map(|e| (e, 1)).collect()
+            } else {
+                vec![(c, char_width(c))]
+            }
+        })
+        .filter(|&(_, width)| width > 0)
+}
+
+// How many columns Canvas::text takes for `text`
+pub fn text_width(text: &str) -> usize {
+    text_chars(text).map(|(_, width)| width).sum()
+}
+
+impl fmt::Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render().trim_end_matches('\n'))
+    }
+}
+
+// A Rect drawn as a box of its size, with the size written inside when there's room. Its position is left out:
+// draw it on a Canvas with rect() to see where it is.
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", draw_sized(self.width, self.height))
+    }
+}
+
+// Draws a width x height box scaled to the terminal, used by the Display impls of our Rectangles
+pub fn draw_sized(width: u32, height: u32) -> Canvas {
+    let mut canvas = Canvas::fit(
+        width,
+        height,
+        terminal_width().saturating_sub(1).max(1),
+        terminal_height().saturating_sub(1).max(1),
+    );
+    canvas.rect(0, 0, width, height);
+    let label = format!("{width}x{height}");
+    if canvas.height >= 3 && canvas.width >= label.len() + 2 {
+        let row = (canvas.height / 2) as i32;
+        let column = ((canvas.width - label.len()) / 2) as i32;
+        for (i, c) in label.chars().enumerate() {
+            canvas.put(column + i as i32, row, c);
+        }
+    }
+    canvas
+}
+
+pub fn start() {
+    let mut canvas = Canvas::new(60, 14);
+    canvas.color = Some(AnsiColor::Cyan);
+    canvas.rect(0, 0, 32, 23);
+    canvas.color = Some(AnsiColor::Yellow);
+    canvas.style = LineStyle::Rounded;
+    canvas.rect(20, 10, 33, 14);
+    canvas.color = Some(AnsiColor::Green);
+    canvas.fill_rect(3, 4, 10, 6, Fill::MediumShade);
+    canvas.color = Some(AnsiColor::Magenta);
+    canvas.circle(42, 8, 6);
+    canvas.color = Some(AnsiColor::Red);
+    canvas.line(2, 24, 16, 14);
+    canvas.color = None;
+    canvas.text(24, 18, "overlap");
+    println!("{}", canvas.render_ansi());
+
+    let rect = Rect::new(0, 0, 20, 8);
+    println!("{rect}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossing_rectangles_join_up() {
+        let mut canvas = Canvas::new(12, 4);
+        canvas.rect(0, 0, 8, 6);
+        canvas.rect(4, 2, 8, 6);
+        assert_eq!(
+            canvas.render(),
+            "┌──────┐\n│   ┌──┼───┐\n└───┼──┘   │\n    └──────┘\n"
+        );
+    }
+
+    #[test]
+    fn line_styles_and_fills() {
+        let mut canvas = Canvas::new(6, 3);
+        canvas.style = LineStyle::Double;
+        canvas.square(0, 0, 6);
+        assert_eq!(canvas.render(), "╔════╗\n║    ║\n╚════╝\n");
+
+        let mut canvas = Canvas::new(6, 3);
+        canvas.style = LineStyle::Ascii;
+        canvas.rect(0, 0, 6, 6);
+        canvas.fill_rect(1, 2, 4, 2, Fill::Checker);
+        assert_eq!(canvas.render(), "+----+\n|█ █ |\n+----+\n");
+    }
+
+    // A circle of radius 4 is 9 columns wide and 5 rows tall
+    #[test]
+    fn circle() {
+        let mut canvas = Canvas::new(9, 5);
+        canvas.circle(4, 4, 4);
+        assert_eq!(
+            canvas.render(),
+            "  *****\n *     *\n*       *\n *     *\n  *****\n"
+        );
+    }
+
+    // Only the columns and rows on the canvas are walked, so these are as quick as a small circle
+    #[test]
+    fn huge_circles_are_clipped() {
+        let mut canvas = Canvas::new(10, 5);
+        canvas.circle(0, 0, u32::MAX);
+        canvas.circle(i64::MIN, i64::MAX, u32::MAX);
+        assert_eq!(canvas.render(), "\n\n\n\n\n");
+
+        // the right edge of a circle a billion units across, which is a straight line this close up
+        canvas.circle(5 - 1_000_000_000, 4, 1_000_000_000);
+        assert_eq!(canvas.render(), "     *\n".repeat(5));
+
+        let mut dot = Canvas::new(3, 2);
+        dot.circle(1, 2, 0);
+        assert_eq!(dot.render(), "\n *\n");
+    }
+
+    #[test]
+    fn diagonal_line() {
+        let mut canvas = Canvas::new(5, 3);
+        canvas.line(0, 0, 4, 4);
+        assert_eq!(canvas.render(), "╲\n ─╲\n   ─╲\n");
+    }
+
+    // Only the part on the canvas gets drawn, however far off the ends are
+    #[test]
+    fn long_lines_are_clipped() {
+        let mut near = Canvas::new(5, 3);
+        near.line(0, 0, 4, 8);
+        let mut far = Canvas::new(5, 3);
+        far.line(-4_000_000_000, -8_000_000_000, 4_000_000_000, 8_000_000_000);
+        assert_eq!(far.render(), "╲\n ╲\n  ╲\n");
+        assert_eq!(far.render(), near.render());
+
+        let mut missed = Canvas::new(5, 3);
+        missed.line(-10, 0, 0, -100);
+        missed.line(i64::MIN, 10, i64::MAX, 1_000);
+        assert_eq!(missed.render(), "\n\n\n");
+    }
+
+    #[test]
+    fn colours() {
+        let mut canvas = Canvas::new(3, 1);
+        canvas.color = Some(AnsiColor::Red);
+        canvas.put(0, 0, 'x');
+        assert_eq!(canvas.render_ansi(), "\x1b[31mx\x1b[0m\n");
+    }
+
+    #[test]
+    fn text() {
+        let mut canvas = Canvas::new(9, 2);
+        canvas.text(0, 0, "a\tb\u{7}");
+        canvas.text(0, 2, "e\u{301}日本語");
+        assert_eq!(canvas.render(), "a\\tb\\u{7}\ne日本語\n");
+        assert_eq!(text_width("a\tb\u{7}"), 9);
+        assert_eq!(text_width("e\u{301}日本語"), 7);
+
+        // a wide character that doesn't fit is left out whole, and drawing over either half blanks the other
+        let mut canvas = Canvas::new(5, 3);
+        canvas.text(0, 0, "日本語");
+        canvas.text(1, 2, "日本語");
+        canvas.text(0, 4, "日本語");
+        canvas.put(2, 1, 'y');
+        canvas.put(0, 2, 'x');
+        assert_eq!(canvas.render(), "日本\n  y本\nx 本\n");
+
+        canvas.color = Some(AnsiColor::Red);
+        canvas.text(-1, 0, "日本");
+        assert_eq!(
+            canvas.render_ansi().lines().next(),
+            Some(" \x1b[31m本\x1b[0m")
+        );
+    }
+
+    // Shapes wider or taller than the terminal get scaled down, both axes alike
+    #[test]
+    fn fit_scales_both_axes() {
+        let wide = Canvas::fit(400, 100, 80, 24);
+        assert_eq!((wide.width(), wide.height()), (80, 10));
+        let tall = Canvas::fit(100, 400, 80, 24);
+        assert_eq!((tall.width(), tall.height()), (12, 24));
+        let small = Canvas::fit(20, 8, 80, 24);
+        assert_eq!((small.width(), small.height()), (20, 4));
+        let needle = Canvas::fit(1, 2_000_000_000, 80, 24);
+        assert_eq!((needle.width(), needle.height()), (1, 24));
+    }
+
+    #[test]
+    fn huge_canvases_are_cut_down() {
+        let canvas = Canvas::new(usize::MAX, usize::MAX);
+        assert!(canvas.width * canvas.height <= MAX_CELLS);
+        let canvas = Canvas::new(10, usize::MAX);
+        assert_eq!(canvas.width, 10);
+        assert!(canvas.width * canvas.height <= MAX_CELLS);
+    }
+
+    // Far corners past i32::MAX are clipped instead of wrapping round to the other side
+    #[test]
+    fn shapes_off_the_edge_are_clipped() {
+        let mut canvas = Canvas::new(4, 2);
+        canvas.rect(i64::MAX - 1, 0, u32::MAX, 4);
+        canvas.fill_rect(i64::MAX, i64::MAX, u32::MAX, u32::MAX, Fill::Solid);
+        assert_eq!(canvas.render(), "\n\n");
+
+        canvas.rect(-2, -2, u32::MAX, u32::MAX);
+        assert_eq!(canvas.render(), "\n\n");
+        canvas.rect(1, 0, u32::MAX, 4);
+        assert_eq!(canvas.render(), " ┌──\n └──\n");
+    }
+}
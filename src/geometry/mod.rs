@@ -0,0 +1,9 @@
+// Geometry that grew out of the Rectangle examples (chap_11_struct_example and chap_12_methods).
+pub mod canvas;
+pub mod packing;
+pub mod parse;
+pub mod quadtree;
+pub mod rect;
+pub mod shapes;
+pub mod svg;
+pub mod vec3;
@@ -0,0 +1,538 @@
+#![allow(dead_code)]
+use super::rect::Rect;
+use std::fs;
+
+// Packing rectangles into fixed-size bins (sheets of plywood, sprite atlases, pallets), built on top of
+// Rect::can_hold and Rect::fit. Items are placed one by one, biggest first, into the first bin with room for them;
+// a new bin is opened when none of the open ones has room, up to `max_bins`.
+//
+// Three ways to decide where an item goes inside a bin:
+// - Shelf: rows of items like books on a shelf, a new shelf starts under the tallest item of the last one
+// - Guillotine: every placement cuts the leftover space into two rectangles with one straight cut
+// - MaxRects: keeps every maximal free rectangle (they may overlap) and picks the one the item fits most snugly,
+//   slower but usually packs the tightest
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heuristic {
+    Shelf,
+    Guillotine,
+    MaxRects,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    // index into the items that were packed
+    pub item: usize,
+    pub bin: usize,
+    // where it ended up, with the size after rotation
+    pub rect: Rect,
+    pub rotated: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Packing {
+    pub bin_size: Rect,
+    pub bins: usize,
+    pub placements: Vec<Placement>,
+    // items that are bigger than an empty bin, or that didn't fit once all bins were used (and empty items, which
+    // have nothing to place)
+    pub unfit: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Shelf {
+    y: u32,
+    height: u32,
+    used_width: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Bin {
+    size: Rect,
+    heuristic: Heuristic,
+    shelves: Vec<Shelf>,
+    // free space, used by Guillotine (never overlapping) and MaxRects (overlapping)
+    free: Vec<Rect>,
+}
+
+impl Bin {
+    fn new(size: Rect, heuristic: Heuristic) -> Self {
+        Self {
+            size,
+            heuristic,
+            shelves: Vec::new(),
+            free: vec![Rect::sized(size.width, size.height)],
+        }
+    }
+
+    // The orientations worth trying for an item, upright first. Only the sizes matter: the position comes from the
+    // bin, and anything placed inside the bin fits in u32, so Rect::new can't panic for it.
+    fn orientations(item: Rect, allow_rotation: bool) -> Vec<(Rect, bool)> {
+        let mut options = vec![(item, false)];
+        if allow_rotation && item.width != item.height {
+            options.push((Rect::sized(item.height, item.width), true));
+        }
+        options
+    }
+
+    fn insert(&mut self, item: Rect, allow_rotation: bool) -> Option<(Rect, bool)> {
+        match self.heuristic {
+            Heuristic::Shelf => self.insert_shelf(item, allow_rotation),
+            Heuristic::Guillotine => self.insert_guillotine(item, allow_rotation),
+            Heuristic::MaxRects => self.insert_max_rects(item, allow_rotation),
+        }
+    }
+
+    // Puts the item on the shelf where it wastes the least height; if no shelf has room, a new one is started below
+    // the last. When rotating, the orientation that wastes less of the shelf's height wins.
+    fn insert_shelf(&mut self, item: Rect, allow_rotation: bool) -> Option<(Rect, bool)> {
+        let mut best: Option<(u32, usize, Rect, bool)> = None;
+        for (i, shelf) in self.shelves.iter().enumerate() {
+            for (size, rotated) in Self::orientations(item, allow_rotation) {
+                let fits =
+                    size.height <= shelf.height && shelf.used_width + size.width <= self.size.width;
+                let waste = shelf.height - size.height.min(shelf.height);
+                if fits && best.is_none_or(|(w, ..)| waste < w) {
+                    best = Some((waste, i, size, rotated));
+                }
+            }
+        }
+        if let Some((_, i, size, rotated)) = best {
+            let shelf = &mut self.shelves[i];
+            let rect = Rect::new(shelf.used_width, shelf.y, size.width, size.height);
+            shelf.used_width += size.width;
+            return Some((rect, rotated));
+        }
+
+        // A new shelf: lying flat (the lower height) leaves more room for the shelves after it
+        let top = self.shelves.last().map_or(0, |s| s.y + s.height);
+        let room = Rect::sized(self.size.width, self.size.height - top);
+        let (size, rotated) = Self::orientations(item, allow_rotation)
+            .into_iter()
+            .filter(|(size, _)| room.can_hold(size))
+            .min_by_key(|(size, _)| size.height)?;
+        self.shelves.push(Shelf {
+            y: top,
+            height: size.height,
+            used_width: size.width,
+        });
+        Some((Rect::new(0, top, size.width, size.height), rotated))
+    }
+
+    // Best area fit: the free rectangle with the least area left over. The leftover L shape is cut along the
+    // shorter side, which keeps the bigger piece as big as possible.
+    fn insert_guillotine(&mut self, item: Rect, allow_rotation: bool) -> Option<(Rect, bool)> {
+        let mut best: Option<(u64, usize, Rect, bool)> = None;
+        for (i, free) in self.free.iter().enumerate() {
+            for (size, rotated) in Self::orientations(item, allow_rotation) {
+                let leftover = free.area() - size.area().min(free.area());
+                if free.can_hold(&size) && best.is_none_or(|(l, ..)| leftover < l) {
+                    best = Some((leftover, i, size, rotated));
+                }
+            }
+        }
+        let (_, i, size, rotated) = best?;
+        let free = self.free.swap_remove(i);
+        let placed = Rect::new(free.x, free.y, size.width, size.height);
+
+        let right_width = free.width - size.width;
+        let bottom_height = free.height - size.height;
+        let (right, bottom) = if right_width < bottom_height {
+            // horizontal cut: the bottom piece gets the full width
+            (
+                Rect::new(placed.right(), free.y, right_width, size.height),
+                Rect::new(free.x, placed.bottom(), free.width, bottom_height),
+            )
+        } else {
+            // vertical cut: the right piece gets the full height
+            (
+                Rect::new(placed.right(), free.y, right_width, free.height),
+                Rect::new(free.x, placed.bottom(), size.width, bottom_height),
+            )
+        };
+        self.free
+            .extend([right, bottom].into_iter().filter(|r| !r.is_empty()));
+        Some((placed, rotated))
+    }
+
+    // Best short side fit: the free rectangle where the smaller of the two leftover gaps is the smallest
+    fn insert_max_rects(&mut self, item: Rect, allow_rotation: bool) -> Option<(Rect, bool)> {
+        let mut best: Option<((u32, u32), Rect, bool)> = None;
+        for free in &self.free {
+            for (size, rotated) in Self::orientations(item, allow_rotation) {
+                if !free.can_hold(&size) {
+                    continue;
+                }
+                let gap_x = free.width - size.width;
+                let gap_y = free.height - size.height;
+                let score = (gap_x.min(gap_y), gap_x.max(gap_y));
+                if best.is_none_or(|(s, ..)| score < s) {
+                    best = Some((
+                        score,
+                        Rect::new(free.x, free.y, size.width, size.height),
+                        rotated,
+                    ));
+                }
+            }
+        }
+        let (_, placed, rotated) = best?;
+
+        // Every free rectangle the item overlaps is replaced by the (up to four) pieces of it left around the item
+        let mut free = Vec::with_capacity(self.free.len() + 4);
+        for f in &self.free {
+            if !f.overlaps(&placed) {
+                free.push(*f);
+                continue;
+            }
+            let pieces = [
+                Rect::new(f.x, f.y, placed.x.saturating_sub(f.x), f.height),
+                Rect::new(
+                    placed.right(),
+                    f.y,
+                    f.right().saturating_sub(placed.right()),
+                    f.height,
+                ),
+                Rect::new(f.x, f.y, f.width, placed.y.saturating_sub(f.y)),
+                Rect::new(
+                    f.x,
+                    placed.bottom(),
+                    f.width,
+                    f.bottom().saturating_sub(placed.bottom()),
+                ),
+            ];
+            free.extend(pieces.into_iter().filter(|p| !p.is_empty()));
+        }
+
+        // Drop the free rectangles that lie inside another one (keeping one copy of duplicates)
+        let mut maximal: Vec<Rect> = Vec::with_capacity(free.len());
+        for (i, a) in free.iter().enumerate() {
+            let covered = free
+                .iter()
+                .enumerate()
+                .any(|(j, b)| i != j && b.contains(a) && (a != b || j < i));
+            if !covered {
+                maximal.push(*a);
+            }
+        }
+        self.free = maximal;
+        Some((placed, rotated))
+    }
+}
+
+// Packs `items` (only their sizes matter) into bins of `bin_size`
+pub fn pack(
+    items: &[Rect],
+    bin_size: Rect,
+    max_bins: usize,
+    heuristic: Heuristic,
+    allow_rotation: bool,
+) -> Packing {
+    let bin_size = Rect::sized(bin_size.width, bin_size.height);
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    // Shelves are filled best by sorting on height, the others by area
+    match heuristic {
+        Heuristic::Shelf => order.sort_by_key(|&i| {
+            let item = items[i];
+            let height = if allow_rotation {
+                item.width.min(item.height)
+            } else {
+                item.height
+            };
+            std::cmp::Reverse((height, item.area()))
+        }),
+        _ => order.sort_by_key(|&i| {
+            std::cmp::Reverse((items[i].area(), items[i].width.max(items[i].height)))
+        }),
+    }
+
+    let mut bins: Vec<Bin> = Vec::new();
+    let mut placements = Vec::new();
+    let mut unfit = Vec::new();
+    for i in order {
+        let item = Rect::sized(items[i].width, items[i].height);
+        let fits_empty_bin = if allow_rotation {
+            bin_size.fit(&item).is_some()
+        } else {
+            bin_size.can_hold(&item)
+        };
+        if item.is_empty() || !fits_empty_bin {
+            unfit.push(i);
+            continue;
+        }
+
+        let mut placed = false;
+        for (b, bin) in bins.iter_mut().enumerate() {
+            if let Some((rect, rotated)) = bin.insert(item, allow_rotation) {
+                placements.push(Placement {
+                    item: i,
+                    bin: b,
+                    rect,
+                    rotated,
+                });
+                placed = true;
+                break;
+            }
+        }
+        if !placed && bins.len() < max_bins {
+            let mut bin = Bin::new(bin_size, heuristic);
+            // an empty bin always has room for anything that passed the size check above
+            if let Some((rect, rotated)) = bin.insert(item, allow_rotation) {
+                placements.push(Placement {
+                    item: i,
+                    bin: bins.len(),
+                    rect,
+                    rotated,
+                });
+                placed = true;
+            }
+            bins.push(bin);
+        }
+        if !placed {
+            unfit.push(i);
+        }
+    }
+    unfit.sort();
+
+    Packing {
+        bin_size,
+        bins: bins.len(),
+        placements,
+        unfit,
+    }
+}
+
+// Letters for the ASCII rendering, item 0 is A, item 26 is a, and so on
+const LABELS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+impl Packing {
+    // Percentage of the bin covered by items
+    pub fn utilisation(&self, bin: usize) -> f64 {
+        let used: u64 = self
+            .placements
+            .iter()
+            .filter(|p| p.bin == bin)
+            .map(|p| p.rect.area())
+            .sum();
+        100.0 * used as f64 / self.bin_size.area().max(1) as f64
+    }
+
+    pub fn total_utilisation(&self) -> f64 {
+        let used: u64 = self.placements.iter().map(|p| p.rect.area()).sum();
+        let total = self.bin_size.area() * self.bins as u64;
+        100.0 * used as f64 / total.max(1) as f64
+    }
+
+    // Checks everything a packing promises: every item is either placed once or listed as unfit, it has its own
+    // size (or the rotated one), it's inside its bin and it doesn't overlap anything else in that bin
+    pub fn verify(&self, items: &[Rect]) -> Result<(), String> {
+        let mut seen = vec![0; items.len()];
+        for p in &self.placements {
+            seen[p.item] += 1;
+        }
+        for &i in &self.unfit {
+            seen[i] += 1;
+        }
+        if let Some(i) = seen.iter().position(|&count| count != 1) {
+            return Err(format!("item {i} shows up {} times", seen[i]));
+        }
+
+        for (n, p) in self.placements.iter().enumerate() {
+            let item = items[p.item];
+            let expected = if p.rotated {
+                (item.height, item.width)
+            } else {
+                (item.width, item.height)
+            };
+            if (p.rect.width, p.rect.height) != expected {
+                return Err(format!("item {} has the wrong size: {:?}", p.item, p.rect));
+            }
+            if p.bin >= self.bins || !self.bin_size.contains(&p.rect) {
+                return Err(format!("item {} is outside its bin: {:?}", p.item, p.rect));
+            }
+            for q in &self.placements[n + 1..] {
+                if p.bin == q.bin && p.rect.overlaps(&q.rect) {
+                    return Err(format!("items {} and {} overlap", p.item, q.item));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // One block of characters per bin, scaled down so a bin is at most `max_width` characters wide.
+    // A cell shows the item covering its middle, '.' is empty space.
+    pub fn to_ascii(&self, max_width: u32) -> String {
+        let scale = self.bin_size.width.div_ceil(max_width.max(1)).max(1);
+        let columns = self.bin_size.width.div_ceil(scale);
+        // terminal cells are about twice as tall as they are wide
+        let rows = self.bin_size.height.div_ceil(scale * 2);
+
+        let mut out = String::new();
+        for bin in 0..self.bins {
+            out.push_str(&format!("Bin {bin} ({:.1}% used)\n", self.utilisation(bin)));
+            for row in 0..rows {
+                for column in 0..columns {
+                    let x = column * scale + scale / 2;
+                    let y = row * scale * 2 + scale;
+                    let label = self
+                        .placements
+                        .iter()
+                        .find(|p| p.bin == bin && p.rect.contains_point(x, y))
+                        .map_or('.', |p| LABELS[p.item % LABELS.len()] as char);
+                    out.push(label);
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    // All bins side by side with a gap between them, each item in its own colour with its index in the middle
+    pub fn to_svg(&self) -> String {
+        let gap = 10;
+        let (w, h) = (self.bin_size.width, self.bin_size.height);
+        let total_width = self.bins as u32 * (w + gap) + gap;
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{total_width}\" height=\"{}\" viewBox=\"0 0 {total_width} {}\">\n",
+            h + 2 * gap,
+            h + 2 * gap
+        );
+        for bin in 0..self.bins {
+            let offset = gap + bin as u32 * (w + gap);
+            out.push_str(&format!(
+                "  <rect x=\"{offset}\" y=\"{gap}\" width=\"{w}\" height=\"{h}\" fill=\"#eeeeee\" stroke=\"#333333\"/>\n"
+            ));
+            for p in self.placements.iter().filter(|p| p.bin == bin) {
+                // golden-angle steps keep neighbouring items apart on the colour wheel
+                let hue = (p.item * 137) % 360;
+                let (x, y) = (offset + p.rect.x, gap + p.rect.y);
+                out.push_str(&format!(
+                    "  <rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"{}\" fill=\"hsl({hue}, 60%, 70%)\" stroke=\"#333333\"/>\n",
+                    p.rect.width, p.rect.height
+                ));
+                out.push_str(&format!(
+                    "  <text x=\"{}\" y=\"{}\" font-size=\"10\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>\n",
+                    x + p.rect.width / 2,
+                    y + p.rect.height / 2,
+                    p.item
+                ));
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+const HEURISTICS: [Heuristic; 3] = [Heuristic::Shelf, Heuristic::Guillotine, Heuristic::MaxRects];
+
+pub fn start() {
+    // Cutting a sheet of plywood, the sizes from the chap_12 rectangles and a few more
+    let items = [
+        Rect::sized(32, 23),
+        Rect::sized(10, 15),
+        Rect::sized(33, 24),
+        Rect::sized(22, 22),
+        Rect::sized(40, 12),
+        Rect::sized(8, 30),
+        Rect::sized(18, 18),
+        Rect::sized(25, 10),
+        Rect::sized(12, 12),
+        Rect::sized(70, 5),
+        Rect::sized(15, 35),
+        Rect::sized(101, 1), // too wide for the sheet even when turned
+    ];
+    let sheet = Rect::sized(80, 60);
+
+    for heuristic in HEURISTICS {
+        for allow_rotation in [false, true] {
+            let packing = pack(&items, sheet, 3, heuristic, allow_rotation);
+            packing.verify(&items).unwrap();
+            println!(
+                "{heuristic:?}, rotation {}: {} bin(s), {:.1}% used, didn't fit: {:?}",
+                if allow_rotation { "on" } else { "off" },
+                packing.bins,
+                packing.total_utilisation(),
+                packing.unfit
+            );
+        }
+    }
+
+    let packing = pack(&items, sheet, 3, Heuristic::MaxRects, true);
+    println!("\n{}", packing.to_ascii(40));
+    for p in packing.placements.iter().take(3) {
+        println!(
+            "item {} -> bin {} at ({}, {}){}",
+            p.item,
+            p.bin,
+            p.rect.x,
+            p.rect.y,
+            if p.rotated { ", rotated" } else { "" }
+        );
+    }
+    let path = std::env::temp_dir().join("packing.svg");
+    match fs::write(&path, packing.to_svg()) {
+        Ok(()) => println!("SVG written to {}", path.display()),
+        Err(err) => println!("Couldn't write the SVG: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::rect::Orientation;
+    use rand::{Rng, thread_rng};
+
+    #[test]
+    fn too_big_and_too_many() {
+        let items = [
+            Rect::sized(30, 30),
+            Rect::sized(50, 50),
+            Rect::sized(101, 1),
+        ];
+        let packing = pack(&items, Rect::sized(80, 60), 3, Heuristic::MaxRects, true);
+        packing.verify(&items).unwrap();
+        assert_eq!(packing.unfit, [2]);
+
+        let only_one_bin = pack(&items, Rect::sized(50, 50), 1, Heuristic::Shelf, false);
+        only_one_bin.verify(&items).unwrap();
+        assert_eq!(only_one_bin.unfit.len(), 2);
+    }
+
+    // A bin exactly as big as the item, which needs turning
+    #[test]
+    fn exact_fit_after_turning() {
+        let exact = pack(
+            &[Rect::sized(3, 7)],
+            Rect::sized(7, 3),
+            1,
+            Heuristic::Guillotine,
+            true,
+        );
+        assert_eq!(exact.placements[0].rect, Rect::new(0, 0, 7, 3));
+        assert!(exact.placements[0].rotated);
+        assert_eq!(exact.utilisation(0), 100.0);
+        assert_eq!(
+            Rect::sized(7, 3).fit(&Rect::sized(3, 7)),
+            Some(Orientation::Rotated)
+        );
+    }
+
+    // Random items: no overlaps, everything inside its bin, every item accounted for
+    #[test]
+    fn random_packings_verify() {
+        let mut rng = thread_rng();
+        for _ in 0..300 {
+            let bin = Rect::sized(rng.gen_range(10..100), rng.gen_range(10..100));
+            let items: Vec<Rect> = (0..rng.gen_range(0..60))
+                .map(|_| Rect::sized(rng.gen_range(0..60), rng.gen_range(0..60)))
+                .collect();
+            for heuristic in HEURISTICS {
+                for allow_rotation in [false, true] {
+                    let packing = pack(&items, bin, 4, heuristic, allow_rotation);
+                    if let Err(err) = packing.verify(&items) {
+                        panic!("{heuristic:?} (rotation {allow_rotation}) in {bin:?}: {err}");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,533 @@
+#![allow(dead_code)]
+use super::shapes::{BoundingBox, Circle, Point, Polygon, Rectangle, Shape, Square, Triangle};
+use crate::text::diagnostics::{Diagnostic, span_of};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+// Shapes written the way we'd say them:
+//
+//   32x23                       a rectangle at the origin
+//   rectangle 32x23 at 1,2      the same, somewhere else ("rect" works too)
+//   square 22 at 0,0
+//   circle r=5 at 3,4
+//   triangle 0,0 4,0 0,3
+//   polygon 0,0 4,0 4,1 1,1 1,3 0,3
+//
+// Errors point at the word that was wrong, and `render` turns them into a text::diagnostics report.
+// Display writes the same syntax back, so printing a shape and parsing it again gives the same shape.
+
+// Any of the shapes, for when we need to know which one it is (a Box<dyn Shape> can't be printed or compared)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShapeKind {
+    Rectangle(Rectangle),
+    Square(Square),
+    Circle(Circle),
+    Triangle(Triangle),
+    Polygon(Polygon),
+}
+
+impl ShapeKind {
+    pub fn as_shape(&self) -> &dyn Shape {
+        match self {
+            ShapeKind::Rectangle(s) => s,
+            ShapeKind::Square(s) => s,
+            ShapeKind::Circle(s) => s,
+            ShapeKind::Triangle(s) => s,
+            ShapeKind::Polygon(s) => s,
+        }
+    }
+}
+
+impl Shape for ShapeKind {
+    fn name(&self) -> &'static str {
+        self.as_shape().name()
+    }
+
+    fn area(&self) -> f64 {
+        self.as_shape().area()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.as_shape().perimeter()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.as_shape().bounding_box()
+    }
+
+    fn centroid(&self) -> Point {
+        self.as_shape().centroid()
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        self.as_shape().contains(p)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseShapeError {
+    pub message: String,
+    // byte range of the offending word in the input
+    pub span: Range<usize>,
+    pub help: Option<String>,
+}
+
+impl ParseShapeError {
+    fn new(message: &str, span: Range<usize>) -> Self {
+        Self {
+            message: message.to_string(),
+            span,
+            help: None,
+        }
+    }
+
+    fn with_help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_string());
+        self
+    }
+
+    pub fn diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error("couldn't parse the shape")
+            .with_label(self.span.clone(), &self.message);
+        match &self.help {
+            Some(help) => diagnostic.with_help(help),
+            None => diagnostic,
+        }
+    }
+
+    // The rustc-style report, `input` has to be the string that was parsed
+    pub fn render(&self, input: &str) -> String {
+        self.diagnostic().render("shape", input)
+    }
+}
+
+impl fmt::Display for ParseShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.span.start)?;
+        if let Some(help) = &self.help {
+            write!(f, ", {help}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseShapeError {}
+
+const SHAPE_HELP: &str =
+    "expected rectangle, square, circle, triangle, polygon or a size like 32x23";
+
+// The words of the input with their positions, so errors can point back at them
+struct Words<'a> {
+    input: &'a str,
+    words: Vec<&'a str>,
+    next: usize,
+}
+
+impl<'a> Words<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            words: input.split_whitespace().collect(),
+            next: 0,
+        }
+    }
+
+    fn span(&self, word: &str) -> Range<usize> {
+        span_of(self.input, word)
+    }
+
+    // Where the next word would be, for "expected ... here" errors at the end of the input
+    fn end_span(&self) -> Range<usize> {
+        let end = self.input.trim_end().len();
+        end..end
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.words.get(self.next).copied()
+    }
+
+    fn take(&mut self, what: &str) -> Result<&'a str, ParseShapeError> {
+        match self.peek() {
+            Some(word) => {
+                self.next += 1;
+                Ok(word)
+            }
+            None => Err(ParseShapeError::new(
+                &format!("expected {what} here"),
+                self.end_span(),
+            )),
+        }
+    }
+
+    fn number(&self, text: &str, word: &str, what: &str) -> Result<f64, ParseShapeError> {
+        match text.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(n),
+            _ if text.is_empty() => Err(ParseShapeError::new(
+                &format!("the {what} is missing"),
+                self.span(word),
+            )),
+            _ => Err(ParseShapeError::new(
+                &format!("`{text}` isn't a valid {what}"),
+                self.span(word),
+            )),
+        }
+    }
+
+    fn size(&self, text: &str, word: &str, what: &str) -> Result<f64, ParseShapeError> {
+        let n = self.number(text, word, what)?;
+        if n < 0.0 {
+            return Err(ParseShapeError::new(
+                &format!("the {what} can't be negative"),
+                self.span(word),
+            ));
+        }
+        Ok(n)
+    }
+
+    fn point(&mut self, what: &str) -> Result<Point, ParseShapeError> {
+        let word = self.take(what)?;
+        let Some((x, y)) = word.split_once(',') else {
+            return Err(ParseShapeError::new(
+                &format!("expected {what} like 3,4"),
+                self.span(word),
+            )
+            .with_help("no spaces around the comma"));
+        };
+        Ok(Point::new(
+            self.number(x, word, "x coordinate")?,
+            self.number(y, word, "y coordinate")?,
+        ))
+    }
+
+    // "32x23" (the word has already been taken)
+    fn dimensions(&self, word: &str) -> Result<(f64, f64), ParseShapeError> {
+        match word.split_once(['x', 'X']) {
+            Some((w, h)) => Ok((self.size(w, word, "width")?, self.size(h, word, "height")?)),
+            None => Err(ParseShapeError::new(
+                "expected a size like 32x23",
+                self.span(word),
+            )),
+        }
+    }
+
+    // An optional "at x,y", the origin otherwise
+    fn position(&mut self) -> Result<Point, ParseShapeError> {
+        if self.peek().is_some_and(|w| w.eq_ignore_ascii_case("at")) {
+            self.next += 1;
+            return self.point("a position");
+        }
+        Ok(Point::new(0.0, 0.0))
+    }
+
+    fn points(&mut self) -> Result<Vec<Point>, ParseShapeError> {
+        let mut points = Vec::new();
+        while self.peek().is_some() {
+            points.push(self.point("a corner")?);
+        }
+        Ok(points)
+    }
+
+    fn finish(&self) -> Result<(), ParseShapeError> {
+        match self.peek() {
+            Some(word) => Err(ParseShapeError::new(
+                &format!("unexpected `{word}`"),
+                self.span(word),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for ShapeKind {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Words::new(s);
+        let Some(first) = words.peek() else {
+            return Err(
+                ParseShapeError::new("there's no shape here", 0..s.len()).with_help(SHAPE_HELP)
+            );
+        };
+        words.next += 1;
+
+        let shape = match first.to_lowercase().as_str() {
+            "rect" | "rectangle" => {
+                let word = words.take("a size like 32x23")?;
+                let (width, height) = words.dimensions(word)?;
+                let origin = words.position()?;
+                ShapeKind::Rectangle(Rectangle {
+                    origin,
+                    width,
+                    height,
+                })
+            }
+            "square" => {
+                let word = words.take("a size")?;
+                let size = words.size(word, word, "size")?;
+                let origin = words.position()?;
+                ShapeKind::Square(Square { origin, size })
+            }
+            "circle" => {
+                let word = words.take("a radius like r=5")?;
+                let radius = word.strip_prefix("r=").unwrap_or(word);
+                let radius = words.size(radius, word, "radius")?;
+                let center = words.position()?;
+                ShapeKind::Circle(Circle { center, radius })
+            }
+            "triangle" => {
+                let a = words.point("a corner")?;
+                let b = words.point("a corner")?;
+                let c = words.point("a corner")?;
+                ShapeKind::Triangle(Triangle { a, b, c })
+            }
+            "polygon" => {
+                let points = words.points()?;
+                if points.len() < 3 {
+                    return Err(ParseShapeError::new(
+                        &format!("a polygon needs at least 3 corners, found {}", points.len()),
+                        words.span(first).start..s.trim_end().len(),
+                    ));
+                }
+                ShapeKind::Polygon(Polygon::new(points))
+            }
+            _ if first.starts_with(|c: char| c.is_ascii_digit() || c == '.') => {
+                let (width, height) = words.dimensions(first)?;
+                let origin = words.position()?;
+                ShapeKind::Rectangle(Rectangle {
+                    origin,
+                    width,
+                    height,
+                })
+            }
+            _ => {
+                return Err(ParseShapeError::new(
+                    &format!("unknown shape `{first}`"),
+                    words.span(first),
+                )
+                .with_help(SHAPE_HELP));
+            }
+        };
+        words.finish()?;
+        Ok(shape)
+    }
+}
+
+// `{}` on an f64 prints the shortest text that parses back to exactly the same number
+impl fmt::Display for ShapeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeKind::Rectangle(r) => write!(
+                f,
+                "rectangle {}x{} at {},{}",
+                r.width, r.height, r.origin.x, r.origin.y
+            ),
+            ShapeKind::Square(s) => write!(f, "square {} at {},{}", s.size, s.origin.x, s.origin.y),
+            ShapeKind::Circle(c) => {
+                write!(f, "circle r={} at {},{}", c.radius, c.center.x, c.center.y)
+            }
+            ShapeKind::Triangle(t) => write!(
+                f,
+                "triangle {},{} {},{} {},{}",
+                t.a.x, t.a.y, t.b.x, t.b.y, t.c.x, t.c.y
+            ),
+            ShapeKind::Polygon(p) => {
+                write!(f, "polygon")?;
+                for v in &p.vertices {
+                    write!(f, " {},{}", v.x, v.y)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// Parsing one particular shape: parse any shape and complain if it's a different one
+fn parse_as<T>(
+    s: &str,
+    expected: &str,
+    pick: fn(ShapeKind) -> Result<T, ShapeKind>,
+) -> Result<T, ParseShapeError> {
+    let shape: ShapeKind = s.parse()?;
+    pick(shape).map_err(|other| {
+        let first = s.split_whitespace().next().unwrap_or("");
+        ParseShapeError::new(
+            &format!("expected a {expected}, found a {}", other.name()),
+            span_of(s, first),
+        )
+    })
+}
+
+impl FromStr for Rectangle {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_as(s, "rectangle", |shape| match shape {
+            ShapeKind::Rectangle(r) => Ok(r),
+            // a square is a perfectly good rectangle
+            ShapeKind::Square(sq) => Ok(Rectangle::new(sq.origin.x, sq.origin.y, sq.size, sq.size)),
+            other => Err(other),
+        })
+    }
+}
+
+impl FromStr for Square {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_as(s, "square", |shape| match shape {
+            ShapeKind::Square(sq) => Ok(sq),
+            other => Err(other),
+        })
+    }
+}
+
+impl FromStr for Circle {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_as(s, "circle", |shape| match shape {
+            ShapeKind::Circle(c) => Ok(c),
+            other => Err(other),
+        })
+    }
+}
+
+impl FromStr for Triangle {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_as(s, "triangle", |shape| match shape {
+            ShapeKind::Triangle(t) => Ok(t),
+            other => Err(other),
+        })
+    }
+}
+
+impl FromStr for Polygon {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_as(s, "polygon", |shape| match shape {
+            ShapeKind::Polygon(p) => Ok(p),
+            // every triangle is a polygon too
+            ShapeKind::Triangle(t) => Ok(Polygon::new(vec![t.a, t.b, t.c])),
+            other => Err(other),
+        })
+    }
+}
+
+pub fn start() {
+    let inputs = [
+        "32x23",
+        "square 22",
+        "circle r=5 at 3,4",
+        "rect 10x15 at -2.5,1e2",
+        "triangle 0,0 4,0 0,3",
+        "polygon 0,0 4,0 4,1 1,1 1,3 0,3",
+    ];
+    for input in inputs {
+        let shape: ShapeKind = input.parse().unwrap();
+        println!("{input:<34} -> {shape} (area {:.2})", shape.area());
+    }
+
+    let bad = [
+        "hexagon 5",
+        "circle r=-5",
+        "32x",
+        "square 22 at 3 4",
+        "triangle 0,0 4,0",
+        "polygon 1,1 2,2",
+        "circle r=5 at 3,4 please",
+        "",
+    ];
+    for input in bad {
+        let err = input.parse::<ShapeKind>().unwrap_err();
+        println!("{}", err.render(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Printing a shape and parsing it again gives the same shape
+    #[test]
+    fn round_trips() {
+        for input in [
+            "32x23",
+            "square 22",
+            "circle r=5 at 3,4",
+            "rect 10x15 at -2.5,1e2",
+            "triangle 0,0 4,0 0,3",
+            "polygon 0,0 4,0 4,1 1,1 1,3 0,3",
+        ] {
+            let shape: ShapeKind = input.parse().unwrap();
+            assert_eq!(shape.to_string().parse::<ShapeKind>(), Ok(shape), "{input}");
+        }
+        assert_eq!(
+            "rect 10x15 at -2.5,1e2"
+                .parse::<ShapeKind>()
+                .unwrap()
+                .to_string(),
+            "rectangle 10x15 at -2.5,100"
+        );
+    }
+
+    #[test]
+    fn typed_parsing() {
+        let rect: Rectangle = "32x23".parse().unwrap();
+        assert_eq!(rect, Rectangle::new(0.0, 0.0, 32.0, 23.0));
+        let circle: Circle = "circle r=5 at 3,4".parse().unwrap();
+        assert_eq!(circle, Circle::new(3.0, 4.0, 5.0));
+        assert_eq!("circle 5".parse::<Circle>(), Ok(Circle::new(0.0, 0.0, 5.0)));
+        assert_eq!(
+            "square 22".parse::<Rectangle>(),
+            Ok(Rectangle::new(0.0, 0.0, 22.0, 22.0))
+        );
+        assert_eq!(
+            "triangle 0,0 4,0 0,3".parse::<Polygon>().unwrap().area(),
+            6.0
+        );
+    }
+
+    // Every error points at the part of the input that's wrong
+    #[test]
+    fn error_spans() {
+        let err = "circle r=5".parse::<Square>().unwrap_err();
+        assert_eq!(err.message, "expected a square, found a circle");
+        assert_eq!(err.span, 0..6);
+        let err = "square 22 at 3 4".parse::<ShapeKind>().unwrap_err();
+        assert_eq!(err.span, 13..14);
+        let err = "circle r=abc".parse::<ShapeKind>().unwrap_err();
+        assert_eq!(err.message, "`abc` isn't a valid radius");
+        assert_eq!(err.span, 7..12);
+        let err = "triangle 0,0 4,0".parse::<ShapeKind>().unwrap_err();
+        assert_eq!(err.span, 16..16);
+        assert!("32xNaN".parse::<ShapeKind>().is_err());
+        let err = "circle r=-5".parse::<ShapeKind>().unwrap_err();
+        assert_eq!(
+            (err.message.as_str(), err.span),
+            ("the radius can't be negative", 7..11)
+        );
+        let err = "polygon 1,1 2,2".parse::<ShapeKind>().unwrap_err();
+        assert_eq!(err.message, "a polygon needs at least 3 corners, found 2");
+        let err = "circle r=5 at 3,4 please".parse::<ShapeKind>().unwrap_err();
+        assert_eq!(err.span, 18..24);
+        let err = "".parse::<ShapeKind>().unwrap_err();
+        assert_eq!(err.message, "there's no shape here");
+        assert_eq!(
+            "hexagon 5"
+                .parse::<ShapeKind>()
+                .unwrap_err()
+                .render("hexagon 5"),
+            "error: couldn't parse the shape\n\
+             \x20--> shape:1:1\n\
+             \x20 |\n\
+             1 | hexagon 5\n\
+             \x20 | ^^^^^^^ unknown shape `hexagon`\n\
+             \x20 |\n\
+             \x20 = help: expected rectangle, square, circle, triangle, polygon or a size like 32x23\n"
+        );
+    }
+}
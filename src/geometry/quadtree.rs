@@ -0,0 +1,341 @@
+#![allow(dead_code)]
+use super::rect::Rect;
+
+// A quadtree over Rects, so "what's under the mouse", "what's inside this area" and "which rectangles overlap"
+// don't have to look at every rectangle every time.
+//
+// Every node covers a region and splits it into four quarters once it holds more than NODE_CAPACITY items. An
+// item lives in the deepest node whose region contains it completely, so rectangles that straddle a split line
+// stay in the parent. Items outside the tree's bounds are kept in the root, which means they still work, they're
+// just checked on every query.
+
+const NODE_CAPACITY: usize = 8;
+const MAX_DEPTH: usize = 16;
+
+#[derive(Debug, Clone)]
+struct Node {
+    bounds: Rect,
+    depth: usize,
+    items: Vec<usize>,
+    children: Option<Box<[Node; 4]>>,
+}
+
+impl Node {
+    fn new(bounds: Rect, depth: usize) -> Self {
+        Self {
+            bounds,
+            depth,
+            items: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn quarters(bounds: Rect, depth: usize) -> Option<Box<[Node; 4]>> {
+        let (left, right) = bounds.split_x(bounds.width / 2)?;
+        let (top_left, bottom_left) = left.split_y(bounds.height / 2)?;
+        let (top_right, bottom_right) = right.split_y(bounds.height / 2)?;
+        Some(Box::new([
+            Node::new(top_left, depth + 1),
+            Node::new(top_right, depth + 1),
+            Node::new(bottom_left, depth + 1),
+            Node::new(bottom_right, depth + 1),
+        ]))
+    }
+
+    // The child that fully contains `rect`, if there is one
+    fn child_for(&mut self, rect: &Rect) -> Option<&mut Node> {
+        self.children
+            .as_mut()?
+            .iter_mut()
+            .find(|child| child.bounds.contains(rect))
+    }
+
+    fn insert(&mut self, id: usize, rects: &[Option<Rect>]) {
+        let rect = rects[id].unwrap();
+        if let Some(child) = self.child_for(&rect) {
+            return child.insert(id, rects);
+        }
+        self.items.push(id);
+        if self.children.is_none() && self.items.len() > NODE_CAPACITY && self.depth < MAX_DEPTH {
+            self.split(rects);
+        }
+    }
+
+    // Moves every item that fits inside one of the new quarters down into it
+    fn split(&mut self, rects: &[Option<Rect>]) {
+        self.children = Self::quarters(self.bounds, self.depth);
+        if self.children.is_none() {
+            return; // too small to split
+        }
+        let items = std::mem::take(&mut self.items);
+        for id in items {
+            let rect = rects[id].unwrap();
+            match self.child_for(&rect) {
+                Some(child) => child.insert(id, rects),
+                None => self.items.push(id),
+            }
+        }
+    }
+
+    // Items are always in the same place for the same rect, so we can walk straight down to it
+    fn remove(&mut self, id: usize, rect: &Rect) -> bool {
+        if let Some(pos) = self.items.iter().position(|&i| i == id) {
+            self.items.swap_remove(pos);
+            return true;
+        }
+        match self.child_for(rect) {
+            Some(child) => child.remove(id, rect),
+            None => false,
+        }
+    }
+
+    fn query(&self, area: &Rect, rects: &[Option<Rect>], out: &mut Vec<usize>) {
+        for &id in &self.items {
+            if rects[id].is_some_and(|r| r.overlaps(area)) {
+                out.push(id);
+            }
+        }
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                if child.bounds.overlaps(area) {
+                    child.query(area, rects, out);
+                }
+            }
+        }
+    }
+
+    // Two items can only overlap if they're in the same node or one is in an ancestor of the other's node, since
+    // sibling regions don't share any cells. So every item is checked against the rest of its node, and looked up
+    // in the subtrees below it like any other range query.
+    fn pairs(&self, rects: &[Option<Rect>], out: &mut Vec<(usize, usize)>) {
+        let mut below = Vec::new();
+        for (n, &a) in self.items.iter().enumerate() {
+            let ra = rects[a].unwrap();
+            for &b in &self.items[n + 1..] {
+                if ra.overlaps(&rects[b].unwrap()) {
+                    out.push((a.min(b), a.max(b)));
+                }
+            }
+            if let Some(children) = &self.children {
+                below.clear();
+                for child in children.iter() {
+                    if child.bounds.overlaps(&ra) {
+                        child.query(&ra, rects, &mut below);
+                    }
+                }
+                out.extend(below.iter().map(|&b| (a.min(b), a.max(b))));
+            }
+        }
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.pairs(rects, out);
+            }
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match &self.children {
+            Some(children) => children.iter().map(Node::depth).max().unwrap_or(0) + 1,
+            None => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuadTree {
+    root: Node,
+    // indexed by the id handed out by insert; removed items leave a None behind so ids stay valid
+    rects: Vec<Option<Rect>>,
+    len: usize,
+}
+
+impl QuadTree {
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            root: Node::new(bounds, 0),
+            rects: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, id: usize) -> Option<Rect> {
+        self.rects.get(id).copied().flatten()
+    }
+
+    // Returns the id to use with get and remove
+    pub fn insert(&mut self, rect: Rect) -> usize {
+        let id = self.rects.len();
+        self.rects.push(Some(rect));
+        self.root.insert(id, &self.rects);
+        self.len += 1;
+        id
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<Rect> {
+        let rect = self.get(id)?;
+        if self.root.remove(id, &rect) {
+            self.rects[id] = None;
+            self.len -= 1;
+            Some(rect)
+        } else {
+            None
+        }
+    }
+
+    // Ids of the rectangles covering the cell at (x, y). No Rect reaches past u32::MAX, so nothing covers the
+    // last column or row.
+    pub fn query_point(&self, x: u32, y: u32) -> Vec<usize> {
+        match Rect::try_new(x, y, 1, 1) {
+            Some(cell) => self.query_range(&cell),
+            None => Vec::new(),
+        }
+    }
+
+    // Ids of the rectangles overlapping `area`
+    pub fn query_range(&self, area: &Rect) -> Vec<usize> {
+        let mut out = Vec::new();
+        self.root.query(area, &self.rects, &mut out);
+        out
+    }
+
+    // Every pair of overlapping rectangles as (smaller id, bigger id)
+    pub fn overlapping_pairs(&self) -> Vec<(usize, usize)> {
+        let mut out = Vec::new();
+        self.root.pairs(&self.rects, &mut out);
+        out
+    }
+
+    pub fn depth(&self) -> usize {
+        self.root.depth()
+    }
+}
+
+fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+    v.sort();
+    v
+}
+
+pub fn start() {
+    let mut tree = QuadTree::new(Rect::sized(100, 100));
+    let rect1 = tree.insert(Rect::new(0, 0, 32, 23));
+    tree.insert(Rect::new(5, 5, 10, 15));
+    tree.insert(Rect::new(20, 10, 33, 24));
+    tree.insert(Rect::new(80, 80, 10, 10));
+    tree.insert(Rect::new(150, 150, 10, 10)); // outside the bounds, still found
+
+    println!("Under (6, 6): {:?}", sorted(tree.query_point(6, 6))); // [0, 1]
+    println!("Under (155, 155): {:?}", tree.query_point(155, 155)); // [4]
+    println!("Overlapping pairs: {:?}", sorted(tree.overlapping_pairs())); // [(0, 1), (0, 2)]
+    tree.remove(rect1);
+    println!("After removing rect1: {:?}", tree.overlapping_pairs()); // []
+    println!("{} rectangles, depth {}", tree.len(), tree.depth());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    // The O(n) and O(n²) versions to check against (benches/quadtree.rs races its own copies)
+    fn brute_range(rects: &[Option<Rect>], area: &Rect) -> Vec<usize> {
+        (0..rects.len())
+            .filter(|&i| rects[i].is_some_and(|r| r.overlaps(area)))
+            .collect()
+    }
+
+    fn brute_pairs(rects: &[Rect]) -> Vec<(usize, usize)> {
+        let mut out = Vec::new();
+        for i in 0..rects.len() {
+            for j in i + 1..rects.len() {
+                if rects[i].overlaps(&rects[j]) {
+                    out.push((i, j));
+                }
+            }
+        }
+        out
+    }
+
+    fn random_rect(rng: &mut impl Rng, world: u32, max_size: u32) -> Rect {
+        Rect::new(
+            rng.gen_range(0..world),
+            rng.gen_range(0..world),
+            rng.gen_range(0..max_size),
+            rng.gen_range(0..max_size),
+        )
+    }
+
+    #[test]
+    fn queries() {
+        let mut tree = QuadTree::new(Rect::sized(100, 100));
+        let rect1 = tree.insert(Rect::new(0, 0, 32, 23));
+        let rect2 = tree.insert(Rect::new(5, 5, 10, 15));
+        let rect3 = tree.insert(Rect::new(20, 10, 33, 24));
+        let far = tree.insert(Rect::new(80, 80, 10, 10));
+        let outside = tree.insert(Rect::new(150, 150, 10, 10)); // outside the bounds, still found
+
+        assert_eq!(sorted(tree.query_point(6, 6)), [rect1, rect2]);
+        assert_eq!(tree.query_point(155, 155), [outside]);
+        assert_eq!(tree.query_range(&Rect::new(70, 70, 20, 20)), [far]);
+        assert_eq!(
+            sorted(tree.overlapping_pairs()),
+            [(rect1, rect2), (rect1, rect3)]
+        );
+        assert_eq!(tree.remove(rect1), Some(Rect::new(0, 0, 32, 23)));
+        assert_eq!(tree.remove(rect1), None);
+        assert!(tree.overlapping_pairs().is_empty());
+        assert_eq!(tree.len(), 4);
+    }
+
+    #[test]
+    fn far_corner() {
+        let mut tree = QuadTree::new(Rect::sized(u32::MAX, u32::MAX));
+        let corner = tree.insert(Rect::new(u32::MAX - 10, u32::MAX - 10, 10, 10));
+        assert_eq!(tree.query_point(u32::MAX - 1, u32::MAX - 1), [corner]);
+        assert!(tree.query_point(u32::MAX, u32::MAX - 1).is_empty());
+        assert!(tree.query_point(0, u32::MAX).is_empty());
+    }
+
+    // Random inserts and removes, checked against brute force after every round
+    #[test]
+    fn random_trees() {
+        let mut rng = thread_rng();
+        for _ in 0..50 {
+            let mut tree = QuadTree::new(Rect::sized(256, 256));
+            for _ in 0..rng.gen_range(0..400) {
+                // some rectangles stick out of the bounds on purpose
+                tree.insert(random_rect(&mut rng, 300, 40));
+            }
+            for _ in 0..rng.gen_range(0..100) {
+                let id = rng.gen_range(0..tree.rects.len().max(1));
+                let expected = tree.get(id);
+                assert_eq!(tree.remove(id), expected);
+            }
+            assert_eq!(tree.len(), tree.rects.iter().flatten().count());
+
+            for _ in 0..20 {
+                let area = random_rect(&mut rng, 300, 80);
+                assert_eq!(
+                    sorted(tree.query_range(&area)),
+                    brute_range(&tree.rects, &area)
+                );
+            }
+            let live: Vec<usize> = (0..tree.rects.len())
+                .filter(|&i| tree.rects[i].is_some())
+                .collect();
+            let live_rects: Vec<Rect> = live.iter().map(|&i| tree.rects[i].unwrap()).collect();
+            let expected: Vec<(usize, usize)> = brute_pairs(&live_rects)
+                .into_iter()
+                .map(|(a, b)| (live[a], live[b]))
+                .collect();
+            assert_eq!(sorted(tree.overlapping_pairs()), expected);
+        }
+    }
+}
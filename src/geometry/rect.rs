@@ -0,0 +1,401 @@
+#![allow(dead_code)]
+
+// Rectangles that know where they are, with whole-number coordinates like cells on a screen: (x, y) is the
+// top-left corner and y grows downwards. A Rect covers the cells x..x + width and y..y + height (half-open), so
+// two rectangles that only touch along an edge don't overlap.
+//
+// A Rect has to fit in u32 coordinates, right() and bottom() included. try_new() returns None for one that
+// doesn't and new() panics, like indexing past the end of a slice would. Everything built from existing Rects
+// (intersection, union, splits) stays inside them and so can't overflow; rotated() and moved_to() can leave the u32
+// range, so they return an Option like try_new().
+//
+// chap_12_methods::Rectangle::can_hold compared the width with >= but the height with >, so a 10x15 rectangle
+// could hold a 10x14 one but not a 9x15 one. Here every check comes in two flavours that treat both sides the
+// same way: non-strict (edges may touch, a rectangle holds itself) and strict (there has to be room to spare).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Upright,
+    // turned by 90 degrees, width and height swapped
+    Rotated,
+}
+
+impl Rect {
+    pub fn try_new(x: u32, y: u32, width: u32, height: u32) -> Option<Self> {
+        x.checked_add(width)?;
+        y.checked_add(height)?;
+        Some(Self {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        match Self::try_new(x, y, width, height) {
+            Some(rect) => rect,
+            None => panic!("{width}x{height} rectangle at ({x}, {y}) reaches past u32::MAX"),
+        }
+    }
+
+    // A rectangle at the origin, for when only the size matters
+    pub fn sized(width: u32, height: u32) -> Self {
+        Self::new(0, 0, width, height)
+    }
+
+    pub fn right(&self) -> u32 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> u32 {
+        self.y + self.height
+    }
+
+    pub fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    // Turned on its side around the top-left corner
+    pub fn rotated(&self) -> Option<Self> {
+        Self::try_new(self.x, self.y, self.height, self.width)
+    }
+
+    pub fn moved_to(&self, x: u32, y: u32) -> Option<Self> {
+        Self::try_new(x, y, self.width, self.height)
+    }
+
+    pub fn contains_point(&self, x: u32, y: u32) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    // `other` lies completely inside, sharing edges is fine
+    pub fn contains(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
+
+    // `other` lies inside without touching any of our edges
+    pub fn strictly_contains(&self, other: &Rect) -> bool {
+        other.x > self.x
+            && other.y > self.y
+            && other.right() < self.right()
+            && other.bottom() < self.bottom()
+    }
+
+    // Size-only checks, positions are ignored (this is what chap_12's can_hold meant)
+    pub fn can_hold(&self, other: &Rect) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+
+    pub fn can_hold_strict(&self, other: &Rect) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+
+    // How `other` fits inside, if it fits at all. Upright is preferred when both ways work.
+    pub fn fit(&self, other: &Rect) -> Option<Orientation> {
+        if self.can_hold(other) {
+            Some(Orientation::Upright)
+        } else if self.width >= other.height && self.height >= other.width {
+            Some(Orientation::Rotated)
+        } else {
+            None
+        }
+    }
+
+    // Overlapping means sharing at least one cell; touching edges and empty rectangles never overlap
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.x < other.right()
+            && other.x < self.right()
+            && self.y < other.bottom()
+            && other.y < self.bottom()
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        Some(Rect::new(
+            x,
+            y,
+            self.right().min(other.right()) - x,
+            self.bottom().min(other.bottom()) - y,
+        ))
+    }
+
+    // The smallest rectangle holding both (their bounding box). Empty rectangles don't add anything to it.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *other;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Rect::new(
+            x,
+            y,
+            self.right().max(other.right()) - x,
+            self.bottom().max(other.bottom()) - y,
+        )
+    }
+
+    pub fn bounding_box(rects: &[Rect]) -> Option<Rect> {
+        rects
+            .iter()
+            .filter(|r| !r.is_empty())
+            .copied()
+            .reduce(|a, b| a.union(&b))
+    }
+
+    // Cuts with a vertical line `offset` cells from the left edge, giving the left and the right part.
+    // Both parts have to end up non-empty.
+    pub fn split_x(&self, offset: u32) -> Option<(Rect, Rect)> {
+        if offset == 0 || offset >= self.width {
+            return None;
+        }
+        Some((
+            Rect::new(self.x, self.y, offset, self.height),
+            Rect::new(self.x + offset, self.y, self.width - offset, self.height),
+        ))
+    }
+
+    // Cuts with a horizontal line `offset` cells from the top edge, giving the top and the bottom part
+    pub fn split_y(&self, offset: u32) -> Option<(Rect, Rect)> {
+        if offset == 0 || offset >= self.height {
+            return None;
+        }
+        Some((
+            Rect::new(self.x, self.y, self.width, offset),
+            Rect::new(self.x, self.y + offset, self.width, self.height - offset),
+        ))
+    }
+}
+
+pub fn start() {
+    // The rectangles from chap_12_methods, now with positions
+    let rect1 = Rect::new(0, 0, 32, 23);
+    let rect2 = Rect::new(5, 5, 10, 15);
+    let rect3 = Rect::new(20, 10, 33, 24);
+
+    println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
+    println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
+    println!("Does rect1 contain rect2? {}", rect1.contains(&rect2));
+    println!("Do rect1 and rect3 overlap? {}", rect1.overlaps(&rect3));
+    println!("rect1 ∩ rect3 = {:?}", rect1.intersection(&rect3));
+    println!("rect1 ∪ rect3 = {:?}", rect1.union(&rect3));
+
+    // A tall thin plank only fits a wide box lying down
+    let shelf = Rect::sized(40, 10);
+    let plank = Rect::sized(5, 30);
+    println!("Plank in shelf: {:?}", shelf.fit(&plank));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    // The inconsistency from chap_12: same width should behave like same height
+    #[test]
+    fn can_hold_treats_both_sides_alike() {
+        let holder = Rect::sized(10, 15);
+        assert!(holder.can_hold(&Rect::sized(10, 14)));
+        assert!(holder.can_hold(&Rect::sized(9, 15)));
+        assert!(!holder.can_hold_strict(&Rect::sized(10, 14)));
+        assert!(!holder.can_hold_strict(&Rect::sized(9, 15)));
+        assert!(holder.can_hold_strict(&Rect::sized(9, 14)));
+    }
+
+    // A tall thin plank only fits a wide box lying down
+    #[test]
+    fn fit_rotates_when_it_has_to() {
+        let shelf = Rect::sized(40, 10);
+        assert_eq!(shelf.fit(&Rect::sized(5, 30)), Some(Orientation::Rotated));
+        assert_eq!(shelf.fit(&Rect::sized(5, 5)), Some(Orientation::Upright));
+        assert_eq!(shelf.fit(&Rect::sized(41, 1)), None);
+    }
+
+    // Touching isn't overlapping
+    #[test]
+    fn touching_rectangles() {
+        let left = Rect::new(0, 0, 10, 10);
+        let right = Rect::new(10, 0, 10, 10);
+        assert!(!left.overlaps(&right));
+        assert_eq!(left.intersection(&right), None);
+        assert_eq!(left.union(&right), Rect::new(0, 0, 20, 10));
+        assert_eq!(
+            left.split_x(4),
+            Some((Rect::new(0, 0, 4, 10), Rect::new(4, 0, 6, 10)))
+        );
+        assert_eq!(left.split_x(10), None);
+        assert_eq!(
+            Rect::bounding_box(&[
+                Rect::new(0, 0, 32, 23),
+                Rect::new(5, 5, 10, 15),
+                Rect::new(20, 10, 33, 24),
+                Rect::new(99, 99, 0, 0)
+            ]),
+            Some(Rect::new(0, 0, 53, 34))
+        );
+    }
+
+    #[test]
+    fn rectangles_must_fit_in_u32() {
+        assert_eq!(Rect::try_new(u32::MAX, 0, 1, 1), None);
+        assert_eq!(Rect::try_new(0, u32::MAX - 1, 1, 2), None);
+        assert_eq!(Rect::try_new(1, 0, u32::MAX, 1), None);
+
+        // right up against the end is fine, and so is everything done with it
+        let corner = Rect::new(u32::MAX - 10, u32::MAX - 10, 10, 10);
+        assert_eq!(corner.right(), u32::MAX);
+        assert_eq!(corner.bottom(), u32::MAX);
+        let whole = Rect::sized(u32::MAX, u32::MAX);
+        assert!(whole.contains(&corner));
+        assert!(whole.overlaps(&corner));
+        assert_eq!(whole.intersection(&corner), Some(corner));
+        assert_eq!(Rect::sized(1, 1).union(&corner), whole);
+        assert_eq!(
+            corner.split_x(4),
+            Some((
+                Rect::new(u32::MAX - 10, u32::MAX - 10, 4, 10),
+                Rect::new(u32::MAX - 6, u32::MAX - 10, 6, 10)
+            ))
+        );
+    }
+
+    // Only the size matters for fit, so a rectangle at the far edge can still be turned to fit
+    #[test]
+    fn rotating_near_the_edge() {
+        let edge = Rect::new(u32::MAX - 10, 0, 10, 100);
+        assert_eq!(edge.rotated(), None);
+        assert_eq!(Rect::sized(200, 20).fit(&edge), Some(Orientation::Rotated));
+        assert_eq!(Rect::sized(5, 5).fit(&edge), None);
+        assert_eq!(edge.moved_to(u32::MAX - 9, 0), None);
+        assert_eq!(edge.moved_to(0, 0), Some(Rect::sized(10, 100)));
+    }
+
+    #[test]
+    #[should_panic(expected = "reaches past u32::MAX")]
+    fn new_rejects_rectangles_that_dont_fit() {
+        Rect::new(u32::MAX, u32::MAX, 1, 1);
+    }
+
+    fn random_rect(rng: &mut impl Rng) -> Rect {
+        Rect::new(
+            rng.gen_range(0..50),
+            rng.gen_range(0..50),
+            rng.gen_range(0..30),
+            rng.gen_range(0..30),
+        )
+    }
+
+    // The algebraic laws, checked on random rectangles
+    #[test]
+    fn laws() {
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let a = random_rect(&mut rng);
+            let b = random_rect(&mut rng);
+            let c = random_rect(&mut rng);
+
+            // overlaps and intersection agree, and both are symmetric
+            assert_eq!(a.overlaps(&b), b.overlaps(&a));
+            assert_eq!(a.overlaps(&b), a.intersection(&b).is_some());
+            assert_eq!(a.intersection(&b), b.intersection(&a));
+
+            // the intersection is inside both and is associative
+            if let Some(i) = a.intersection(&b) {
+                assert!(a.contains(&i) && b.contains(&i));
+                assert!(!i.is_empty());
+            }
+            let ab_c = a.intersection(&b).and_then(|ab| ab.intersection(&c));
+            let a_bc = b.intersection(&c).and_then(|bc| a.intersection(&bc));
+            assert_eq!(ab_c, a_bc);
+
+            // the union holds both, is commutative, associative and idempotent
+            let u = a.union(&b);
+            if !a.is_empty() || !b.is_empty() {
+                assert_eq!(u, b.union(&a));
+            }
+            assert_eq!(a.union(&b).union(&c), a.union(&b.union(&c)));
+            assert_eq!(a.union(&a), a);
+            if !a.is_empty() {
+                assert!(u.contains(&a));
+                assert_eq!(a.intersection(&a), Some(a));
+            }
+            if !b.is_empty() {
+                assert!(u.contains(&b));
+            }
+
+            // inclusion-exclusion: the union box is at least as big as what the two cover together
+            let shared = a.intersection(&b).map_or(0, |i| i.area());
+            assert!(a.area() + b.area() - shared <= u.area());
+
+            // containment
+            if a.strictly_contains(&b) {
+                assert!(a.contains(&b));
+            }
+            if a.contains(&b) && !b.is_empty() {
+                assert_eq!(a.intersection(&b), Some(b));
+                assert_eq!(a.union(&b), a);
+                assert!(a.can_hold(&b));
+            }
+            assert!(a.contains(&a));
+            assert!(!a.strictly_contains(&a));
+            assert_eq!(
+                a.can_hold_strict(&b),
+                a.can_hold(&b) && a.width != b.width && a.height != b.height
+            );
+
+            // rotation: a fit exists exactly when b or b turned on its side can be held
+            let fit = a.fit(&b);
+            let turned = Rect::sized(b.height, b.width);
+            assert_eq!(fit.is_some(), a.can_hold(&b) || a.can_hold(&turned));
+            if fit == Some(Orientation::Rotated) {
+                assert!(!a.can_hold(&b));
+            }
+            assert_eq!(a.rotated().and_then(|r| r.rotated()), Some(a));
+            assert_eq!(a.rotated().map(|r| r.area()), Some(a.area()));
+            assert_eq!(a.moved_to(b.x, b.y).map(|r| r.can_hold(&a)), Some(true));
+
+            // splitting gives two halves that don't overlap and add back up to the whole (empty rectangles can be split
+            // too, but their union forgets where the halves were)
+            let offset = rng.gen_range(0..=a.width.max(1));
+            if let Some((left, right)) = a.split_x(offset) {
+                assert!(!left.overlaps(&right));
+                assert_eq!(left.area() + right.area(), a.area());
+                if !a.is_empty() {
+                    assert_eq!(left.union(&right), a);
+                }
+            }
+            let offset = rng.gen_range(0..=a.height.max(1));
+            if let Some((top, bottom)) = a.split_y(offset) {
+                assert!(!top.overlaps(&bottom));
+                assert_eq!(top.area() + bottom.area(), a.area());
+                if !a.is_empty() {
+                    assert_eq!(top.union(&bottom), a);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,550 @@
+#![allow(dead_code)]
+use std::f64::consts::PI;
+
+// One Shape trait for everything, so a Vec<Box<dyn Shape>> can hold rectangles and circles side by side.
+// Coordinates are f64 and y grows upwards like in maths class. Boundaries count as inside, so a point on the edge
+// of a rectangle is contained by it.
+//
+// Degenerate shapes are allowed on purpose (a 0-width rectangle, a circle of radius 0, a triangle whose corners
+// are on one line): they have no area but still have a perimeter, a bounding box and a centroid.
+
+// Comparisons against 0 are done with a bit of slack since floating point arithmetic is never exact
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn distance(&self, other: &Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+// Axis-aligned box given by its lower-left (min) and upper-right (max) corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    fn around(points: &[Point]) -> Self {
+        let mut min = Point::new(f64::INFINITY, f64::INFINITY);
+        let mut max = Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for p in points {
+            min.x = min.x.min(p.x);
+            min.y = min.y.min(p.y);
+            max.x = max.x.max(p.x);
+            max.y = max.y.max(p.y);
+        }
+        if points.is_empty() {
+            min = Point::new(0.0, 0.0);
+            max = min;
+        }
+        Self { min, max }
+    }
+}
+
+pub trait Shape {
+    fn name(&self) -> &'static str;
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+    fn bounding_box(&self) -> BoundingBox;
+    fn centroid(&self) -> Point;
+    fn contains(&self, p: Point) -> bool;
+}
+
+// chap_11_struct_example and chap_12_methods keep their own u32 Rectangle since building one up is what those
+// chapters are about; this is the one the rest of the code uses
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    // lower-left corner
+    pub origin: Point,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    // Negative sizes are flipped so the origin always stays the lower-left corner
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            origin: Point::new(x.min(x + width), y.min(y + height)),
+            width: width.abs(),
+            height: height.abs(),
+        }
+    }
+}
+
+impl Shape for Rectangle {
+    fn name(&self) -> &'static str {
+        "rectangle"
+    }
+
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox {
+            min: self.origin,
+            max: Point::new(self.origin.x + self.width, self.origin.y + self.height),
+        }
+    }
+
+    fn centroid(&self) -> Point {
+        Point::new(
+            self.origin.x + self.width / 2.0,
+            self.origin.y + self.height / 2.0,
+        )
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        self.bounding_box().contains(p)
+    }
+}
+
+// A square is just a rectangle with one size, so it borrows all the maths from Rectangle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Square {
+    pub origin: Point,
+    pub size: f64,
+}
+
+impl Square {
+    pub fn new(x: f64, y: f64, size: f64) -> Self {
+        let rect = Rectangle::new(x, y, size, size);
+        Self {
+            origin: rect.origin,
+            size: rect.width,
+        }
+    }
+
+    fn as_rectangle(&self) -> Rectangle {
+        Rectangle::new(self.origin.x, self.origin.y, self.size, self.size)
+    }
+}
+
+impl Shape for Square {
+    fn name(&self) -> &'static str {
+        "square"
+    }
+
+    fn area(&self) -> f64 {
+        self.as_rectangle().area()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.as_rectangle().perimeter()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.as_rectangle().bounding_box()
+    }
+
+    fn centroid(&self) -> Point {
+        self.as_rectangle().centroid()
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        self.as_rectangle().contains(p)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f64,
+}
+
+impl Circle {
+    pub fn new(x: f64, y: f64, radius: f64) -> Self {
+        Self {
+            center: Point::new(x, y),
+            radius: radius.abs(),
+        }
+    }
+}
+
+impl Shape for Circle {
+    fn name(&self) -> &'static str {
+        "circle"
+    }
+
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        let r = self.radius;
+        BoundingBox {
+            min: Point::new(self.center.x - r, self.center.y - r),
+            max: Point::new(self.center.x + r, self.center.y + r),
+        }
+    }
+
+    fn centroid(&self) -> Point {
+        self.center
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        self.center.distance(&p) <= self.radius + EPSILON
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+}
+
+impl Triangle {
+    pub fn new(a: Point, b: Point, c: Point) -> Self {
+        Self { a, b, c }
+    }
+}
+
+// Twice the signed area of the triangle o, a, b: positive if they turn left (counter-clockwise), 0 if they're on
+// one line
+fn cross(o: Point, a: Point, b: Point) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+fn on_segment(p: Point, a: Point, b: Point) -> bool {
+    let length = a.distance(&b);
+    cross(a, b, p).abs() <= EPSILON * length.max(1.0)
+        && p.x >= a.x.min(b.x) - EPSILON
+        && p.x <= a.x.max(b.x) + EPSILON
+        && p.y >= a.y.min(b.y) - EPSILON
+        && p.y <= a.y.max(b.y) + EPSILON
+}
+
+impl Shape for Triangle {
+    fn name(&self) -> &'static str {
+        "triangle"
+    }
+
+    fn area(&self) -> f64 {
+        cross(self.a, self.b, self.c).abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.a.distance(&self.b) + self.b.distance(&self.c) + self.c.distance(&self.a)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::around(&[self.a, self.b, self.c])
+    }
+
+    // The average of the corners, which is also right for a flat triangle
+    fn centroid(&self) -> Point {
+        Point::new(
+            (self.a.x + self.b.x + self.c.x) / 3.0,
+            (self.a.y + self.b.y + self.c.y) / 3.0,
+        )
+    }
+
+    // Inside if p is on the same side of all three edges (or on one of them)
+    fn contains(&self, p: Point) -> bool {
+        if self.area() <= EPSILON {
+            return on_segment(p, self.a, self.b)
+                || on_segment(p, self.b, self.c)
+                || on_segment(p, self.c, self.a);
+        }
+        let d1 = cross(self.a, self.b, p);
+        let d2 = cross(self.b, self.c, p);
+        let d3 = cross(self.c, self.a, p);
+        let has_negative = d1 < -EPSILON || d2 < -EPSILON || d3 < -EPSILON;
+        let has_positive = d1 > EPSILON || d2 > EPSILON || d3 > EPSILON;
+        !(has_negative && has_positive)
+    }
+}
+
+// Any simple polygon (edges don't cross each other), given by its corners in order, clockwise or not.
+// The last corner connects back to the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    pub vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    // Shoelace formula: positive for counter-clockwise corners, negative for clockwise ones
+    pub fn signed_area(&self) -> f64 {
+        if self.vertices.len() < 3 {
+            return 0.0;
+        }
+        self.edges()
+            .map(|(p, q)| p.x * q.y - q.x * p.y)
+            .sum::<f64>()
+            / 2.0
+    }
+}
+
+impl Shape for Polygon {
+    fn name(&self) -> &'static str {
+        "polygon"
+    }
+
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        match self.vertices.len() {
+            0 | 1 => 0.0,
+            // a single segment, it shouldn't be counted going there and coming back
+            2 => self.vertices[0].distance(&self.vertices[1]),
+            _ => self.edges().map(|(p, q)| p.distance(&q)).sum(),
+        }
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::around(&self.vertices)
+    }
+
+    // Area-weighted centroid from the shoelace terms; a polygon with no area falls back to the average corner
+    fn centroid(&self) -> Point {
+        let area = self.signed_area();
+        if area.abs() <= EPSILON {
+            let n = self.vertices.len().max(1) as f64;
+            let x = self.vertices.iter().map(|p| p.x).sum::<f64>() / n;
+            let y = self.vertices.iter().map(|p| p.y).sum::<f64>() / n;
+            return Point::new(x, y);
+        }
+        let (mut cx, mut cy) = (0.0, 0.0);
+        for (p, q) in self.edges() {
+            let term = p.x * q.y - q.x * p.y;
+            cx += (p.x + q.x) * term;
+            cy += (p.y + q.y) * term;
+        }
+        Point::new(cx / (6.0 * area), cy / (6.0 * area))
+    }
+
+    // Even-odd rule: cast a ray to the right and count how many edges it crosses. Points on an edge are checked
+    // first since the ray test can go either way for them.
+    fn contains(&self, p: Point) -> bool {
+        if self.vertices.is_empty() {
+            return false;
+        }
+        if self.edges().any(|(a, b)| on_segment(p, a, b)) {
+            return true;
+        }
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > p.y) != (b.y > p.y) {
+                let x = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+                if p.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+pub fn start() {
+    // The same rectangles as in chap_12_methods, plus one of everything else
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Rectangle::new(0.0, 0.0, 32.0, 23.0)),
+        Box::new(Square::new(0.0, 0.0, 22.0)),
+        Box::new(Circle::new(3.0, 4.0, 5.0)),
+        Box::new(Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        )),
+        // an L shape, which is not convex
+        Box::new(Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ])),
+    ];
+
+    for shape in &shapes {
+        let bbox = shape.bounding_box();
+        let centroid = shape.centroid();
+        println!(
+            "{:<9} area: {:>8.2}  perimeter: {:>7.2}  box: ({}, {})..({}, {})  centroid: ({:.2}, {:.2})",
+            shape.name(),
+            shape.area(),
+            shape.perimeter(),
+            bbox.min.x,
+            bbox.min.y,
+            bbox.max.x,
+            bbox.max.y,
+            centroid.x,
+            centroid.y
+        );
+    }
+    let total: f64 = shapes.iter().map(|s| s.area()).sum();
+    println!("Total area: {total:.2}");
+    let biggest = shapes
+        .iter()
+        .max_by(|a, b| a.area().total_cmp(&b.area()))
+        .unwrap();
+    println!("Biggest: {}", biggest.name());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-6
+    }
+
+    fn l_shape() -> Polygon {
+        Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ])
+    }
+
+    fn right_triangle() -> Triangle {
+        Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        )
+    }
+
+    #[test]
+    fn areas_and_perimeters() {
+        assert!(close(Rectangle::new(0.0, 0.0, 32.0, 23.0).area(), 736.0));
+        assert!(close(Circle::new(3.0, 4.0, 5.0).area(), 25.0 * PI));
+        assert!(close(right_triangle().area(), 6.0));
+        assert!(close(right_triangle().perimeter(), 12.0));
+        assert!(close(l_shape().area(), 6.0));
+        assert!(close(l_shape().perimeter(), 14.0));
+    }
+
+    #[test]
+    fn containment() {
+        let l_shape = l_shape();
+        assert!(l_shape.contains(Point::new(0.5, 2.0)));
+        assert!(l_shape.contains(Point::new(4.0, 0.5))); // on the edge
+        assert!(!l_shape.contains(Point::new(2.0, 2.0))); // in the notch of the L
+        let c = l_shape.centroid();
+        assert!(close(c.x, 1.5) && close(c.y, 1.0));
+
+        let circle = Circle::new(3.0, 4.0, 5.0);
+        assert!(circle.contains(Point::new(8.0, 4.0)));
+        assert!(!circle.contains(Point::new(7.0, 8.0)));
+        assert!(right_triangle().contains(Point::new(2.0, 1.5))); // on the hypotenuse
+        assert!(!right_triangle().contains(Point::new(3.0, 3.0)));
+    }
+
+    // Clockwise corners give the same area and centroid as counter-clockwise ones
+    #[test]
+    fn clockwise_polygon() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 0.0),
+        ]);
+        assert!(close(square.signed_area(), -4.0));
+        assert!(close(square.area(), 4.0));
+        assert_eq!(square.centroid(), Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn degenerate_rectangles() {
+        let line = Rectangle::new(1.0, 1.0, 5.0, 0.0);
+        assert_eq!(line.area(), 0.0);
+        assert_eq!(line.perimeter(), 10.0);
+        assert!(line.contains(Point::new(3.0, 1.0)));
+        assert!(!line.contains(Point::new(3.0, 1.5)));
+
+        let flipped = Rectangle::new(5.0, 5.0, -2.0, -3.0);
+        assert_eq!(flipped.origin, Point::new(3.0, 2.0));
+        assert_eq!(flipped.area(), 6.0);
+    }
+
+    #[test]
+    fn degenerate_circle() {
+        let dot = Circle::new(1.0, 1.0, 0.0);
+        assert_eq!(dot.area(), 0.0);
+        assert!(dot.contains(Point::new(1.0, 1.0)));
+        assert!(!dot.contains(Point::new(1.0, 1.1)));
+    }
+
+    #[test]
+    fn degenerate_triangles() {
+        let flat = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        );
+        assert_eq!(flat.area(), 0.0);
+        assert_eq!(flat.centroid(), Point::new(1.0, 1.0));
+        assert!(flat.contains(Point::new(1.5, 1.5)));
+        assert!(!flat.contains(Point::new(1.5, 1.0)));
+
+        let same_corners = Triangle::new(
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 2.0),
+        );
+        assert_eq!(same_corners.perimeter(), 0.0);
+        assert!(same_corners.contains(Point::new(2.0, 2.0)));
+    }
+
+    #[test]
+    fn degenerate_polygons() {
+        let empty = Polygon::new(vec![]);
+        assert_eq!(empty.area(), 0.0);
+        assert_eq!(empty.perimeter(), 0.0);
+        assert!(!empty.contains(Point::new(0.0, 0.0)));
+
+        let segment = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(3.0, 4.0)]);
+        assert_eq!(segment.area(), 0.0);
+        assert_eq!(segment.perimeter(), 5.0);
+        assert_eq!(segment.centroid(), Point::new(1.5, 2.0));
+        assert!(segment.contains(Point::new(1.5, 2.0)));
+    }
+}
@@ -0,0 +1,451 @@
+#![allow(dead_code)]
+use super::parse::ShapeKind;
+use super::shapes::{Circle, Point, Polygon, Rectangle, Shape, Square, Triangle};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Saving a scene of shapes as a standalone SVG file, and reading back the files we write.
+//
+// Our shapes have y going up, SVG has it going down. Instead of converting every coordinate (which would add
+// rounding errors) the shapes go into a group with transform="scale(1,-1)", so the numbers in the file are exactly
+// the ones in the shapes and reading them back gives the very same scene.
+//
+// The reader only understands what `to_svg` writes: <svg>, one <g> with the style, and <rect>, <circle> and
+// <polygon> elements. A square is a <rect class="square"> and a triangle a <polygon class="triangle"> so they
+// come back as what they were.
+
+// The part of the world that's visible: `top` is the highest y since y goes up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scene {
+    pub shapes: Vec<ShapeKind>,
+    // None fits the viewport around the shapes with a small margin
+    pub viewport: Option<Viewport>,
+    pub stroke: String,
+    pub stroke_width: f64,
+    pub fill: String,
+}
+
+impl Scene {
+    pub fn new(shapes: Vec<ShapeKind>) -> Self {
+        Self {
+            shapes,
+            viewport: None,
+            stroke: "black".to_string(),
+            stroke_width: 1.0,
+            fill: "none".to_string(),
+        }
+    }
+
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    pub fn stroke(mut self, stroke: &str, width: f64) -> Self {
+        self.stroke = stroke.to_string();
+        self.stroke_width = width;
+        self
+    }
+
+    pub fn fill(mut self, fill: &str) -> Self {
+        self.fill = fill.to_string();
+        self
+    }
+
+    // The viewport that will be used, the fitted one if none was set
+    pub fn effective_viewport(&self) -> Viewport {
+        if let Some(viewport) = self.viewport {
+            return viewport;
+        }
+        let mut boxes = self.shapes.iter().map(|s| s.bounding_box());
+        let Some(first) = boxes.next() else {
+            return Viewport {
+                left: 0.0,
+                top: 100.0,
+                width: 100.0,
+                height: 100.0,
+            };
+        };
+        let (mut min, mut max) = (first.min, first.max);
+        for b in boxes {
+            min = Point::new(min.x.min(b.min.x), min.y.min(b.min.y));
+            max = Point::new(max.x.max(b.max.x), max.y.max(b.max.y));
+        }
+        let margin = self.stroke_width.max(1.0) * 2.0;
+        Viewport {
+            left: min.x - margin,
+            top: max.y + margin,
+            width: max.x - min.x + 2.0 * margin,
+            height: max.y - min.y + 2.0 * margin,
+        }
+    }
+
+    pub fn to_svg(&self) -> String {
+        let vp = self.effective_viewport();
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">\n",
+            vp.width, vp.height, vp.left, -vp.top, vp.width, vp.height
+        );
+        out.push_str(&format!(
+            "  <g transform=\"scale(1,-1)\" stroke=\"{}\" stroke-width=\"{}\" fill=\"{}\">\n",
+            escape(&self.stroke),
+            self.stroke_width,
+            escape(&self.fill)
+        ));
+        for shape in &self.shapes {
+            let element = match shape {
+                ShapeKind::Rectangle(r) => format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+                    r.origin.x, r.origin.y, r.width, r.height
+                ),
+                ShapeKind::Square(s) => format!(
+                    "<rect class=\"square\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+                    s.origin.x, s.origin.y, s.size, s.size
+                ),
+                ShapeKind::Circle(c) => format!(
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"/>",
+                    c.center.x, c.center.y, c.radius
+                ),
+                ShapeKind::Triangle(t) => format!(
+                    "<polygon class=\"triangle\" points=\"{}\"/>",
+                    points_attr(&[t.a, t.b, t.c])
+                ),
+                ShapeKind::Polygon(p) => {
+                    format!("<polygon points=\"{}\"/>", points_attr(&p.vertices))
+                }
+            };
+            out.push_str(&format!("    {element}\n"));
+        }
+        out.push_str("  </g>\n</svg>\n");
+        out
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_svg())
+    }
+
+    pub fn load(path: &Path) -> io::Result<Scene> {
+        let svg = fs::read_to_string(path)?;
+        parse_svg(&svg).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+fn points_attr(points: &[Point]) -> String {
+    let pairs: Vec<String> = points.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+    pairs.join(" ")
+}
+
+pub fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgError {
+    pub message: String,
+    // byte offset of the tag the problem is in
+    pub offset: usize,
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for SvgError {}
+
+struct Tag<'a> {
+    name: &'a str,
+    attributes: HashMap<&'a str, String>,
+    offset: usize,
+}
+
+impl Tag<'_> {
+    fn error(&self, message: &str) -> SvgError {
+        SvgError {
+            message: message.to_string(),
+            offset: self.offset,
+        }
+    }
+
+    fn text(&self, name: &str) -> Result<&str, SvgError> {
+        self.attributes
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| self.error(&format!("<{}> is missing the {name} attribute", self.name)))
+    }
+
+    fn number(&self, name: &str) -> Result<f64, SvgError> {
+        let text = self.text(name)?;
+        text.parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite())
+            .ok_or_else(|| self.error(&format!("{name}=\"{text}\" isn't a number")))
+    }
+
+    fn points(&self) -> Result<Vec<Point>, SvgError> {
+        let text = self.text("points")?;
+        text.split_whitespace()
+            .map(|pair| {
+                let (x, y) = pair.split_once(',').unwrap_or((pair, ""));
+                let x = x.parse::<f64>().ok().filter(|n| n.is_finite());
+                let y = y.parse::<f64>().ok().filter(|n| n.is_finite());
+                match (x, y) {
+                    (Some(x), Some(y)) => Ok(Point::new(x, y)),
+                    _ => Err(self.error(&format!("`{pair}` isn't a point like 3,4"))),
+                }
+            })
+            .collect()
+    }
+}
+
+// Splits `<name a="1" b="2"/>` into its name and attributes
+fn parse_tag(inner: &str, offset: usize) -> Result<Tag<'_>, SvgError> {
+    let inner = inner.trim_end_matches('/').trim();
+    let name_end = inner.find(char::is_whitespace).unwrap_or(inner.len());
+    let (name, mut rest) = inner.split_at(name_end);
+    let mut attributes = HashMap::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let broken = || SvgError {
+            message: format!("couldn't read the attributes of <{name}>"),
+            offset,
+        };
+        let (key, after) = rest.split_once("=\"").ok_or_else(broken)?;
+        let (value, after) = after.split_once('"').ok_or_else(broken)?;
+        attributes.insert(key.trim(), unescape(value));
+        rest = after;
+    }
+    Ok(Tag {
+        name,
+        attributes,
+        offset,
+    })
+}
+
+pub fn parse_svg(svg: &str) -> Result<Scene, SvgError> {
+    let mut scene = Scene::new(Vec::new());
+    let mut seen_svg = false;
+    let mut pos = 0;
+    while let Some(start) = svg[pos..].find('<').map(|i| pos + i) {
+        let Some(end) = svg[start..].find('>').map(|i| start + i) else {
+            return Err(SvgError {
+                message: "unclosed tag".to_string(),
+                offset: start,
+            });
+        };
+        pos = end + 1;
+        let inner = &svg[start + 1..end];
+        // the XML declaration, comments and closing tags carry nothing we need
+        if inner.starts_with('?') || inner.starts_with('!') || inner.starts_with('/') {
+            continue;
+        }
+
+        let tag = parse_tag(inner, start)?;
+        match tag.name {
+            "svg" => {
+                seen_svg = true;
+                let view_box: Vec<f64> = tag
+                    .text("viewBox")?
+                    .split_whitespace()
+                    .filter_map(|n| n.parse().ok())
+                    .collect();
+                let [x, y, width, height] = view_box[..] else {
+                    return Err(tag.error("viewBox needs 4 numbers"));
+                };
+                scene.viewport = Some(Viewport {
+                    left: x,
+                    top: -y,
+                    width,
+                    height,
+                });
+            }
+            "g" => {
+                scene.stroke = tag.text("stroke")?.to_string();
+                scene.stroke_width = tag.number("stroke-width")?;
+                scene.fill = tag.text("fill")?.to_string();
+            }
+            "rect" => {
+                let (x, y) = (tag.number("x")?, tag.number("y")?);
+                let (width, height) = (tag.number("width")?, tag.number("height")?);
+                let origin = Point::new(x, y);
+                if tag.attributes.get("class").is_some_and(|c| c == "square") {
+                    if width != height {
+                        return Err(tag.error("a square has to be as wide as it is tall"));
+                    }
+                    scene.shapes.push(ShapeKind::Square(Square {
+                        origin,
+                        size: width,
+                    }));
+                } else {
+                    scene.shapes.push(ShapeKind::Rectangle(Rectangle {
+                        origin,
+                        width,
+                        height,
+                    }));
+                }
+            }
+            "circle" => {
+                let center = Point::new(tag.number("cx")?, tag.number("cy")?);
+                let radius = tag.number("r")?;
+                if radius < 0.0 {
+                    return Err(tag.error("a circle can't have a negative radius"));
+                }
+                scene
+                    .shapes
+                    .push(ShapeKind::Circle(Circle { center, radius }));
+            }
+            "polygon" => {
+                let points = tag.points()?;
+                if tag.attributes.get("class").is_some_and(|c| c == "triangle") {
+                    let [a, b, c] = points[..] else {
+                        return Err(tag.error("a triangle needs exactly 3 points"));
+                    };
+                    scene.shapes.push(ShapeKind::Triangle(Triangle { a, b, c }));
+                } else {
+                    if points.len() < 3 {
+                        return Err(tag.error("a polygon needs at least 3 points"));
+                    }
+                    scene.shapes.push(ShapeKind::Polygon(Polygon::new(points)));
+                }
+            }
+            other => return Err(tag.error(&format!("<{other}> isn't supported"))),
+        }
+    }
+    if !seen_svg {
+        return Err(SvgError {
+            message: "no <svg> element".to_string(),
+            offset: 0,
+        });
+    }
+    Ok(scene)
+}
+
+pub fn start() {
+    // Written the way geometry::parse reads them
+    let lines = [
+        "rectangle 32x23 at 0,0",
+        "square 10 at 40,0",
+        "circle r=5 at 45.5,20",
+        "triangle 0,30 8,30 4,36.25",
+        "polygon 20,30 30,30 30,33 23,33 23,40 20,40",
+    ];
+    let shapes: Vec<ShapeKind> = lines.iter().map(|l| l.parse().unwrap()).collect();
+    let scene = Scene::new(shapes)
+        .stroke("#1f6feb", 0.5)
+        .fill("rgba(31, 111, 235, 0.2)");
+    let svg = scene.to_svg();
+    println!("{svg}");
+
+    let path = std::env::temp_dir().join("scene.svg");
+    match scene.save(&path) {
+        Ok(()) => println!("Saved to {}", path.display()),
+        Err(err) => println!("Couldn't save the scene: {err}"),
+    }
+
+    // Only the elements Scene writes can be read back
+    let err = parse_svg("<svg viewBox=\"0 0 10 10\"><path d=\"M0 0\"/></svg>").unwrap_err();
+    println!("{err}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene() -> Scene {
+        let lines = [
+            "rectangle 32x23 at 0,0",
+            "square 10 at 40,0",
+            "circle r=5 at 45.5,20",
+            "triangle 0,30 8,30 4,36.25",
+            "polygon 20,30 30,30 30,33 23,33 23,40 20,40",
+        ];
+        Scene::new(lines.iter().map(|l| l.parse().unwrap()).collect())
+            .stroke("#1f6feb", 0.5)
+            .fill("rgba(31, 111, 235, 0.2)")
+    }
+
+    #[test]
+    fn round_trips() {
+        let scene = scene();
+        let parsed = parse_svg(&scene.to_svg()).unwrap();
+        assert_eq!(parsed.shapes, scene.shapes);
+        assert_eq!(parsed.stroke, "#1f6feb");
+        assert_eq!(parsed.fill, "rgba(31, 111, 235, 0.2)");
+        assert_eq!(parse_svg(&parsed.to_svg()).unwrap(), parsed);
+
+        let custom = Scene::new(vec!["circle r=0.1 at 0.3,-0.7".parse().unwrap()])
+            .viewport(Viewport {
+                left: -1.0,
+                top: 1.0,
+                width: 2.0,
+                height: 2.0,
+            })
+            .fill("\"odd\" & <weird>");
+        assert_eq!(parse_svg(&custom.to_svg()).unwrap(), custom);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let scene = scene();
+        let path = std::env::temp_dir().join(format!("scene_test_{}.svg", std::process::id()));
+        scene.save(&path).unwrap();
+        let loaded = Scene::load(&path);
+        let _ = std::fs::remove_file(&path);
+        // the fitted viewport gets written down, so only that differs
+        assert_eq!(
+            loaded.unwrap(),
+            scene.clone().viewport(scene.effective_viewport())
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse_svg("<svg viewBox=\"0 0 10 10\"><path d=\"M0 0\"/></svg>").unwrap_err();
+        assert_eq!(err.offset, 25);
+        assert!(parse_svg("<svg viewBox=\"0 0 10\"></svg>").is_err());
+        assert!(
+            parse_svg("<svg viewBox=\"0 0 10 10\"><circle cx=\"1\" cy=\"x\" r=\"1\"/></svg>")
+                .is_err()
+        );
+        assert!(parse_svg("<g></g>").is_err());
+
+        let shape = |tag: &str| parse_svg(&format!("<svg viewBox=\"0 0 10 10\">{tag}</svg>"));
+        let err = shape("<circle cx=\"1\" cy=\"1\" r=\"-2\"/>").unwrap_err();
+        assert_eq!(err.message, "a circle can't have a negative radius");
+        assert!(shape("<circle cx=\"1\" cy=\"1\" r=\"0\"/>").is_ok());
+        let err = shape("<polygon points=\"0,0 1,1\"/>").unwrap_err();
+        assert_eq!(err.message, "a polygon needs at least 3 points");
+        assert!(shape("<polygon points=\"\"/>").is_err());
+        for points in ["0,0 1,inf 2,0", "0,0 NaN,1 2,0", "0,0 1,1e999 2,0"] {
+            let err = shape(&format!("<polygon points=\"{points}\"/>")).unwrap_err();
+            assert!(err.message.ends_with("isn't a point like 3,4"), "{points}");
+        }
+    }
+}
@@ -0,0 +1,635 @@
+#![allow(dead_code)]
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+// The 3D version of chap_10's `struct Point(i32, i32, i32)`, generic over the number type.
+//
+// Point3 and Vec3 hold the same three numbers but mean different things, the same way chap_10's Point and Color
+// do: a point is a place, a vector is a step. So the operators only exist where the result makes sense:
+//   point - point = vector      (the step from one place to another)
+//   point + vector = point      (take the step)
+//   vector + vector = vector, vector * number = vector
+// and point + point doesn't compile, because adding two places together has no meaning.
+
+// What the coordinates can be. Integers are fine for exact grid maths, lengths and rotations need the Float part.
+pub trait Number:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + fmt::Display
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+pub trait Float: Number {
+    fn sqrt(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn abs(self) -> Self;
+}
+
+macro_rules! number {
+    ($($t:ty => $zero:expr, $one:expr);*) => {
+        $(impl Number for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+        })*
+    };
+}
+
+number!(i32 => 0, 1; i64 => 0, 1; f32 => 0.0, 1.0; f64 => 0.0, 1.0);
+
+macro_rules! float {
+    ($($t:ty),*) => {
+        $(impl Float for $t {
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                <$t>::sin_cos(self)
+            }
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        })*
+    };
+}
+
+float!(f32, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Number> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    // The step from the origin to this point
+    pub fn to_vec(self) -> Vec3<T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn distance_squared(self, other: Self) -> T {
+        (self - other).length_squared()
+    }
+}
+
+impl<T: Float> Point3<T> {
+    pub fn distance(self, other: Self) -> T {
+        (self - other).length()
+    }
+
+    // t = 0 gives self, t = 1 gives other
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<T: Number> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn unit_x() -> Self {
+        Self::new(T::ONE, T::ZERO, T::ZERO)
+    }
+
+    pub fn unit_y() -> Self {
+        Self::new(T::ZERO, T::ONE, T::ZERO)
+    }
+
+    pub fn unit_z() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ONE)
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    // Perpendicular to both, pointing the way your thumb does on your right hand (x cross y = z)
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    // Works for integers too, and is enough for comparing lengths
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Float> Vec3<T> {
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    // The same direction with length 1, or None for the zero vector which has no direction
+    pub fn normalize(self) -> Option<Self> {
+        let length = self.length();
+        if length == T::ZERO {
+            None
+        } else {
+            Some(self / length)
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+// Points print like coordinates, vectors with angle brackets, so the two are easy to tell apart in output
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}, {}, {}>", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Number> Sub for Point3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, other: Self) -> Vec3<T> {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Number> Add<Vec3<T>> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, v: Vec3<T>) -> Point3<T> {
+        Point3::new(self.x + v.x, self.y + v.y, self.z + v.z)
+    }
+}
+
+impl<T: Number> Sub<Vec3<T>> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, v: Vec3<T>) -> Point3<T> {
+        Point3::new(self.x - v.x, self.y - v.y, self.z - v.z)
+    }
+}
+
+impl<T: Number> AddAssign<Vec3<T>> for Point3<T> {
+    fn add_assign(&mut self, v: Vec3<T>) {
+        *self = *self + v;
+    }
+}
+
+impl<T: Number> SubAssign<Vec3<T>> for Point3<T> {
+    fn sub_assign(&mut self, v: Vec3<T>) {
+        *self = *self - v;
+    }
+}
+
+impl<T: Number> Add for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Number> Sub for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Number> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Number> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Number> Neg for Vec3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Number> Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+
+impl<T: Number> Div<T> for Vec3<T> {
+    type Output = Self;
+
+    fn div(self, k: T) -> Self {
+        Self::new(self.x / k, self.y / k, self.z / k)
+    }
+}
+
+// 3x3 matrix, row by row. Multiplying by a vector rotates or scales it around the origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3<T> {
+    pub rows: [[T; 3]; 3],
+}
+
+// 4x4 matrix for transforms that also move things. Points get the translation and vectors don't, since a step
+// is the same step wherever it starts (it's the w = 1 vs w = 0 of homogeneous coordinates).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4<T> {
+    pub rows: [[T; 4]; 4],
+}
+
+impl<T: Number> Mat3<T> {
+    pub fn identity() -> Self {
+        Self::scale(T::ONE, T::ONE, T::ONE)
+    }
+
+    pub fn scale(x: T, y: T, z: T) -> Self {
+        let o = T::ZERO;
+        Self {
+            rows: [[x, o, o], [o, y, o], [o, o, z]],
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        let mut rows = self.rows;
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.rows[j][i];
+            }
+        }
+        Self { rows }
+    }
+
+    pub fn determinant(self) -> T {
+        let [a, b, c] = self.rows;
+        Vec3::from((a[0], a[1], a[2]))
+            .dot(Vec3::from((b[0], b[1], b[2])).cross(Vec3::from((c[0], c[1], c[2]))))
+    }
+}
+
+impl<T: Float> Mat3<T> {
+    // Counter-clockwise by `angle` radians when looking down the axis towards the origin
+    pub fn rotation_x(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        let (o, i) = (T::ZERO, T::ONE);
+        Self {
+            rows: [[i, o, o], [o, c, -s], [o, s, c]],
+        }
+    }
+
+    pub fn rotation_y(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        let (o, i) = (T::ZERO, T::ONE);
+        Self {
+            rows: [[c, o, s], [o, i, o], [-s, o, c]],
+        }
+    }
+
+    pub fn rotation_z(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        let (o, i) = (T::ZERO, T::ONE);
+        Self {
+            rows: [[c, -s, o], [s, c, o], [o, o, i]],
+        }
+    }
+
+    // Rotation around any axis (Rodrigues' formula), None if the axis is the zero vector
+    pub fn rotation_axis(axis: Vec3<T>, angle: T) -> Option<Self> {
+        let Vec3 { x, y, z } = axis.normalize()?;
+        let (s, c) = angle.sin_cos();
+        let t = T::ONE - c;
+        Some(Self {
+            rows: [
+                [t * x * x + c, t * x * y - s * z, t * x * z + s * y],
+                [t * x * y + s * z, t * y * y + c, t * y * z - s * x],
+                [t * x * z - s * y, t * y * z + s * x, t * z * z + c],
+            ],
+        })
+    }
+}
+
+impl<T: Number> Mat4<T> {
+    pub fn identity() -> Self {
+        Self::from_mat3(Mat3::identity())
+    }
+
+    pub fn scale(x: T, y: T, z: T) -> Self {
+        Self::from_mat3(Mat3::scale(x, y, z))
+    }
+
+    pub fn translation(by: Vec3<T>) -> Self {
+        let mut m = Self::identity();
+        m.rows[0][3] = by.x;
+        m.rows[1][3] = by.y;
+        m.rows[2][3] = by.z;
+        m
+    }
+
+    // The 3x3 part goes in the top left, no translation
+    pub fn from_mat3(m: Mat3<T>) -> Self {
+        let mut rows = [[T::ZERO; 4]; 4];
+        for (i, row) in m.rows.iter().enumerate() {
+            rows[i][..3].copy_from_slice(row);
+        }
+        rows[3][3] = T::ONE;
+        Self { rows }
+    }
+
+    pub fn transpose(self) -> Self {
+        let mut rows = self.rows;
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.rows[j][i];
+            }
+        }
+        Self { rows }
+    }
+}
+
+impl<T: Number> Mul for Mat3<T> {
+    type Output = Self;
+
+    // (a * b) * v == a * (b * v): b is applied first
+    fn mul(self, other: Self) -> Self {
+        let mut rows = [[T::ZERO; 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                for k in 0..3 {
+                    *value = *value + self.rows[i][k] * other.rows[k][j];
+                }
+            }
+        }
+        Self { rows }
+    }
+}
+
+impl<T: Number> Mul<Vec3<T>> for Mat3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        let row = |r: [T; 3]| Vec3::from((r[0], r[1], r[2])).dot(v);
+        Vec3::new(row(self.rows[0]), row(self.rows[1]), row(self.rows[2]))
+    }
+}
+
+impl<T: Number> Mul<Point3<T>> for Mat3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, p: Point3<T>) -> Point3<T> {
+        Point3::origin() + self * p.to_vec()
+    }
+}
+
+impl<T: Number> Mul for Mat4<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut rows = [[T::ZERO; 4]; 4];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                for k in 0..4 {
+                    *value = *value + self.rows[i][k] * other.rows[k][j];
+                }
+            }
+        }
+        Self { rows }
+    }
+}
+
+impl<T: Number> Mat4<T> {
+    // Row i of the matrix applied to (x, y, z, w)
+    fn apply(self, x: T, y: T, z: T, w: T) -> [T; 4] {
+        let mut out = [T::ZERO; 4];
+        for (i, value) in out.iter_mut().enumerate() {
+            let r = self.rows[i];
+            *value = r[0] * x + r[1] * y + r[2] * z + r[3] * w;
+        }
+        out
+    }
+}
+
+impl<T: Number> Mul<Vec3<T>> for Mat4<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        let [x, y, z, _] = self.apply(v.x, v.y, v.z, T::ZERO);
+        Vec3::new(x, y, z)
+    }
+}
+
+// Affine transforms only: a projection matrix would also need the divide by w
+impl<T: Number> Mul<Point3<T>> for Mat4<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, p: Point3<T>) -> Point3<T> {
+        let [x, y, z, _] = self.apply(p.x, p.y, p.z, T::ONE);
+        Point3::new(x, y, z)
+    }
+}
+
+pub fn start() {
+    use std::f64::consts::FRAC_PI_2;
+
+    // chap_10's origin, now with a type that knows what to do with it
+    let origin: Point3<i32> = Point3::from((0, 0, 0));
+    let corner = Point3::new(3, 4, 12);
+    let step = corner - origin;
+    println!(
+        "from {origin} to {corner} is {step}, length² {}",
+        step.length_squared()
+    );
+    // let nonsense = origin + corner; // doesn't compile: Point3 + Point3 isn't defined
+
+    let a = Point3::new(1.0, 2.0, 2.0);
+    let b = Point3::new(4.0, 6.0, 2.0);
+    println!(
+        "{a} to {b}: distance {}, halfway {}",
+        a.distance(b),
+        a.lerp(b, 0.5)
+    );
+
+    let x: Vec3<f64> = Vec3::unit_x();
+    let y = Vec3::unit_y();
+    println!("x · y = {}, x × y = {}", x.dot(y), x.cross(y));
+
+    let quarter_turn = Mat3::rotation_z(FRAC_PI_2);
+    let moved = Mat4::translation(Vec3::new(10.0, 0.0, 0.0)) * Mat4::from_mat3(quarter_turn);
+    println!(
+        "turn a quarter around z, then move 10 along x: point {} -> {}, vector {} -> {}",
+        Point3::new(1.0, 0.0, 0.0),
+        moved * Point3::new(1.0, 0.0, 0.0),
+        x,
+        moved * x
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+    use std::f64::consts::FRAC_PI_2;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn close_vec(a: Vec3<f64>, b: Vec3<f64>) -> bool {
+        close(a.x, b.x) && close(a.y, b.y) && close(a.z, b.z)
+    }
+
+    fn random_vec(rng: &mut impl Rng) -> Vec3<f64> {
+        Vec3::new(
+            rng.gen_range(-10.0..10.0),
+            rng.gen_range(-10.0..10.0),
+            rng.gen_range(-10.0..10.0),
+        )
+    }
+
+    #[test]
+    fn points_and_vectors() {
+        let origin: Point3<i32> = Point3::from((0, 0, 0));
+        let corner = Point3::new(3, 4, 12);
+        let step = corner - origin;
+        assert_eq!(step, Vec3::new(3, 4, 12));
+        assert_eq!(step.length_squared(), 169);
+        assert_eq!(origin + step, corner);
+        let mut p = origin;
+        p += step;
+        p -= Vec3::new(3, 4, 0);
+        assert_eq!(p, Point3::new(0, 0, 12));
+        assert_eq!(-step * 2, Vec3::new(-6, -8, -24));
+
+        let (a, b) = (Point3::new(1.0, 2.0, 2.0), Point3::new(4.0, 6.0, 2.0));
+        assert_eq!(a.distance(b), 5.0);
+        assert_eq!(a.lerp(b, 0.5), Point3::new(2.5, 4.0, 2.0));
+        let (x, y): (Vec3<f64>, Vec3<f64>) = (Vec3::unit_x(), Vec3::unit_y());
+        assert_eq!(x.cross(y), Vec3::unit_z());
+        assert_eq!(y.cross(x), -Vec3::unit_z());
+        assert_eq!(
+            Vec3::new(0.0, 3.0, 4.0).normalize(),
+            Some(Vec3::new(0.0, 0.6, 0.8))
+        );
+        assert_eq!(Vec3::<f64>::zero().normalize(), None);
+    }
+
+    #[test]
+    fn matrices() {
+        let (x, y): (Vec3<f64>, Vec3<f64>) = (Vec3::unit_x(), Vec3::unit_y());
+        assert_eq!(Mat3::rotation_axis(Vec3::zero(), 1.0), None);
+        assert_eq!(Mat3::<i32>::scale(2, 3, 4).determinant(), 24);
+        assert_eq!(
+            Mat4::scale(2, 3, 4) * Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+
+        let quarter_turn = Mat3::rotation_z(FRAC_PI_2);
+        assert!(close_vec(quarter_turn * x, y));
+        assert!(close_vec(Mat3::rotation_x(FRAC_PI_2) * y, Vec3::unit_z()));
+        assert!(close_vec(Mat3::rotation_y(FRAC_PI_2) * Vec3::unit_z(), x));
+        let moved = Mat4::translation(Vec3::new(10.0, 0.0, 0.0)) * Mat4::from_mat3(quarter_turn);
+        assert!(close_vec(
+            (moved * Point3::new(1.0, 0.0, 0.0)).to_vec(),
+            Vec3::new(10.0, 1.0, 0.0)
+        ));
+        assert!(close_vec(moved * x, y)); // vectors don't get translated
+        assert_eq!(
+            Mat4::<f64>::translation(x).transpose().transpose(),
+            Mat4::translation(x)
+        );
+    }
+
+    // Cross and dot product identities and rotation properties on random vectors
+    #[test]
+    fn random_laws() {
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let (u, v, w) = (
+                random_vec(&mut rng),
+                random_vec(&mut rng),
+                random_vec(&mut rng),
+            );
+            let c = u.cross(v);
+            let tolerance = 1e-9 * (1.0 + u.length_squared() * v.length_squared());
+            // the cross product is perpendicular to both, and |u × v|² + (u · v)² = |u|² |v|²
+            assert!(c.dot(u).abs() < tolerance && c.dot(v).abs() < tolerance);
+            let lagrange =
+                c.length_squared() + u.dot(v) * u.dot(v) - u.length_squared() * v.length_squared();
+            assert!(lagrange.abs() < tolerance);
+            // u · (v × w) is the determinant of the matrix with rows u, v, w
+            let m = Mat3 {
+                rows: [[u.x, u.y, u.z], [v.x, v.y, v.z], [w.x, w.y, w.z]],
+            };
+            assert!((m.determinant() - u.dot(v.cross(w))).abs() < 1e-6);
+
+            let Some(r) = Mat3::rotation_axis(w, rng.gen_range(-10.0..10.0)) else {
+                continue;
+            };
+            // rotations keep lengths and angles, have determinant 1, and their transpose undoes them
+            let (ru, rv) = (r * u, r * v);
+            assert!((ru.length() - u.length()).abs() < 1e-9);
+            assert!((ru.dot(rv) - u.dot(v)).abs() < 1e-6);
+            assert!(close(r.determinant(), 1.0));
+            assert!(close_vec(r.transpose() * ru, u));
+            // the axis itself doesn't move
+            assert!(close_vec(r * w, w));
+            // matrix products apply right to left
+            let s = Mat3::rotation_x(rng.gen_range(-3.0..3.0));
+            assert!(close_vec((r * s) * u, r * (s * u)));
+            // a translation and back returns every point where it started
+            let p = Point3::origin() + u;
+            let there_and_back = Mat4::translation(-v) * Mat4::translation(v);
+            assert!(close_vec((there_and_back * p) - p, Vec3::zero()));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+// Everything that grew out of the chapters, in a library so the benchmarks under benches/ can use it too.
+// main.rs keeps the chapters themselves and brings these in with `use`, so `crate::text::...` still works there.
+pub mod color;
+pub mod geometry;
+pub mod message;
+pub mod net;
+pub mod text;
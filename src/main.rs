@@ -19,6 +19,7 @@ mod chap_18_string;
 mod chap_19_exercises;
 mod chap_19_hashmaps;
 mod chap_20_errors;
+use the_rust_book_learning::*;
 fn main() {
     // chap_02_number_guessing_game::run();
     // variables::var();
@@ -60,5 +61,36 @@ fn main() {
     // chap_19_exercises::ex1();
     // chap_19_exercises::ex2();
     // chap_19_exercises::ex3();
+    // text::words::start();
+    // text::indexed_str::start();
+    // text::grapheme::start();
+    // text::utf8::start();
+    // text::utf8::hexdump_command();
+    // text::translit::start();
+    // text::rope::start();
+    // text::search::start();
+    // text::case::start();
+    // text::wrap::start();
+    // text::diagnostics::start();
+    // geometry::shapes::start();
+    // geometry::rect::start();
+    // geometry::packing::start();
+    // geometry::canvas::start();
+    // geometry::quadtree::start();
+    // geometry::parse::start();
+    // geometry::svg::start();
+    // geometry::vec3::start();
+    // color::rgb::start();
+    // net::addr::start();
+    // net::cidr::start();
+    // net::cidr::cidr_command();
+    // net::route::start();
+    // net::pcap::start();
+    // net::pcap::pcap_command();
+    // net::pool::start();
+    // net::pool::pool_command();
+    // message::codec::start();
+    // message::turtle::start();
+    // message::turtle::turtle_command();
     chap_20_errors::start();
 }
@@ -0,0 +1,746 @@
+#![allow(dead_code)]
+use crate::text::diagnostics::Diagnostic;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+// chap_13's Message enum (it's local to chap_13's start(), so it's repeated here) with two ways to write it down:
+//
+// A binary frame, small enough to send over a socket:
+//   version byte (VERSION), then a tag byte, then the fields
+//   0 Quit
+//   1 Move          x, y as zigzag varints
+//   2 Write         length as a varint, then that many bytes of UTF-8
+//   3 ChangeColor   r, g, b as zigzag varints
+// A varint stores 7 bits per byte, low bits first, with the top bit set on every byte but the last (LEB128), so
+// small numbers take one byte. Zigzag maps 0, -1, 1, -2, ... to 0, 1, 2, 3, ... first so small negative numbers
+// stay small too. There's exactly one valid encoding of every message: padded varints are rejected.
+//
+// And a text form, which is just what `{:?}` prints: Quit, Move { x: 1, y: -2 }, Write("hi"), ChangeColor(1, 2, 3).
+
+pub const VERSION: u8 = 1;
+// Longer strings are rejected before allocating anything for them
+pub const MAX_STRING: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    // the frame stops early; `offset` is where more bytes were needed
+    Truncated { offset: usize },
+    UnsupportedVersion(u8),
+    UnknownTag { tag: u8, offset: usize },
+    // more than 32 bits, or padded with extra zero bytes
+    BadVarint { offset: usize },
+    StringTooLong { len: u64, offset: usize },
+    InvalidUtf8 { offset: usize },
+    // decode_exact got more bytes than one message
+    TrailingBytes { offset: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated { offset } => {
+                write!(f, "the frame ends early (at byte {offset})")
+            }
+            DecodeError::UnsupportedVersion(v) => {
+                write!(f, "version {v} isn't supported (this is version {VERSION})")
+            }
+            DecodeError::UnknownTag { tag, offset } => {
+                write!(f, "unknown message tag {tag} at byte {offset}")
+            }
+            DecodeError::BadVarint { offset } => write!(f, "invalid varint at byte {offset}"),
+            DecodeError::StringTooLong { len, offset } => {
+                write!(
+                    f,
+                    "string of {len} bytes at byte {offset} is over the {MAX_STRING} byte limit"
+                )
+            }
+            DecodeError::InvalidUtf8 { offset } => {
+                write!(f, "the string at byte {offset} isn't UTF-8")
+            }
+            DecodeError::TrailingBytes { offset } => {
+                write!(f, "unexpected bytes after the message, from byte {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+fn zigzag(n: i32) -> u32 {
+    ((n << 1) ^ (n >> 31)) as u32
+}
+
+fn unzigzag(n: u32) -> i32 {
+    ((n >> 1) as i32) ^ -((n & 1) as i32)
+}
+
+fn put_varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+// Reads the frame from the front, keeping track of the position for errors
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    at: usize,
+}
+
+impl Cursor<'_> {
+    fn byte(&mut self) -> Result<u8, DecodeError> {
+        let b = *self
+            .bytes
+            .get(self.at)
+            .ok_or(DecodeError::Truncated { offset: self.at })?;
+        self.at += 1;
+        Ok(b)
+    }
+
+    fn varint(&mut self, max_bits: u32) -> Result<u64, DecodeError> {
+        let start = self.at;
+        let mut n: u64 = 0;
+        for shift in (0..).step_by(7) {
+            let b = self.byte()?;
+            if shift >= max_bits || (b as u64 & 0x7f) << shift >> shift != b as u64 & 0x7f {
+                return Err(DecodeError::BadVarint { offset: start });
+            }
+            n |= (b as u64 & 0x7f) << shift;
+            if b & 0x80 == 0 {
+                // a zero last byte means the one before didn't need its continuation bit
+                if b == 0 && shift > 0 {
+                    return Err(DecodeError::BadVarint { offset: start });
+                }
+                break;
+            }
+        }
+        if max_bits < 64 && n >> max_bits != 0 {
+            return Err(DecodeError::BadVarint { offset: start });
+        }
+        Ok(n)
+    }
+
+    fn int(&mut self) -> Result<i32, DecodeError> {
+        Ok(unzigzag(self.varint(32)? as u32))
+    }
+}
+
+impl Message {
+    pub fn tag(&self) -> u8 {
+        match self {
+            Message::Quit => 0,
+            Message::Move { .. } => 1,
+            Message::Write(_) => 2,
+            Message::ChangeColor(..) => 3,
+        }
+    }
+
+    pub fn encode_into(&self, out: &mut Vec<u8>) {
+        out.push(VERSION);
+        out.push(self.tag());
+        let int = |out: &mut Vec<u8>, n: i32| put_varint(out, zigzag(n) as u64);
+        match self {
+            Message::Quit => {}
+            Message::Move { x, y } => {
+                int(out, *x);
+                int(out, *y);
+            }
+            Message::Write(text) => {
+                put_varint(out, text.len() as u64);
+                out.extend_from_slice(text.as_bytes());
+            }
+            Message::ChangeColor(r, g, b) => {
+                int(out, *r);
+                int(out, *g);
+                int(out, *b);
+            }
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_into(&mut out);
+        out
+    }
+
+    // One message from the front of `bytes`, with how many bytes it took
+    pub fn decode(bytes: &[u8]) -> Result<(Message, usize), DecodeError> {
+        let mut cursor = Cursor { bytes, at: 0 };
+        let version = cursor.byte()?;
+        if version != VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        let tag_at = cursor.at;
+        let message = match cursor.byte()? {
+            0 => Message::Quit,
+            1 => Message::Move {
+                x: cursor.int()?,
+                y: cursor.int()?,
+            },
+            2 => {
+                let len_at = cursor.at;
+                let len = cursor.varint(64)?;
+                if len > MAX_STRING as u64 {
+                    return Err(DecodeError::StringTooLong {
+                        len,
+                        offset: len_at,
+                    });
+                }
+                let start = cursor.at;
+                let text =
+                    bytes
+                        .get(start..start + len as usize)
+                        .ok_or(DecodeError::Truncated {
+                            offset: bytes.len(),
+                        })?;
+                cursor.at += len as usize;
+                let text = std::str::from_utf8(text)
+                    .map_err(|_| DecodeError::InvalidUtf8 { offset: start })?;
+                Message::Write(text.to_string())
+            }
+            3 => Message::ChangeColor(cursor.int()?, cursor.int()?, cursor.int()?),
+            tag => {
+                return Err(DecodeError::UnknownTag {
+                    tag,
+                    offset: tag_at,
+                });
+            }
+        };
+        Ok((message, cursor.at))
+    }
+
+    // Exactly one message and nothing after it
+    pub fn decode_exact(bytes: &[u8]) -> Result<Message, DecodeError> {
+        let (message, used) = Self::decode(bytes)?;
+        if used != bytes.len() {
+            return Err(DecodeError::TrailingBytes { offset: used });
+        }
+        Ok(message)
+    }
+}
+
+// Frames one after another, as they'd come down a stream
+pub fn encode_all(messages: &[Message]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for message in messages {
+        message.encode_into(&mut out);
+    }
+    out
+}
+
+// Error offsets are from the start of `bytes`
+pub fn decode_all(bytes: &[u8]) -> Result<Vec<Message>, DecodeError> {
+    let mut messages = Vec::new();
+    let mut at = 0;
+    while at < bytes.len() {
+        let (message, used) = Message::decode(&bytes[at..]).map_err(|e| shift(e, at))?;
+        messages.push(message);
+        at += used;
+    }
+    Ok(messages)
+}
+
+fn shift(e: DecodeError, by: usize) -> DecodeError {
+    match e {
+        DecodeError::Truncated { offset } => DecodeError::Truncated {
+            offset: offset + by,
+        },
+        DecodeError::UnknownTag { tag, offset } => DecodeError::UnknownTag {
+            tag,
+            offset: offset + by,
+        },
+        DecodeError::BadVarint { offset } => DecodeError::BadVarint {
+            offset: offset + by,
+        },
+        DecodeError::StringTooLong { len, offset } => DecodeError::StringTooLong {
+            len,
+            offset: offset + by,
+        },
+        DecodeError::InvalidUtf8 { offset } => DecodeError::InvalidUtf8 {
+            offset: offset + by,
+        },
+        DecodeError::TrailingBytes { offset } => DecodeError::TrailingBytes {
+            offset: offset + by,
+        },
+        DecodeError::UnsupportedVersion(v) => DecodeError::UnsupportedVersion(v),
+    }
+}
+
+// The text form is the Debug form, so Display just reuses it
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMessageError {
+    pub message: String,
+    // byte range of the offending part of the input
+    pub span: Range<usize>,
+}
+
+impl ParseMessageError {
+    fn new(message: &str, span: Range<usize>) -> Self {
+        Self {
+            message: message.to_string(),
+            span,
+        }
+    }
+
+    pub fn diagnostic(&self) -> Diagnostic {
+        Diagnostic::error("couldn't parse the message")
+            .with_label(self.span.clone(), &self.message)
+            .with_help(
+                "expected Quit, Move { x: 1, y: 2 }, Write(\"text\") or ChangeColor(1, 2, 3)",
+            )
+    }
+
+    // The rustc-style report, `input` has to be the string that was parsed
+    pub fn render(&self, input: &str) -> String {
+        self.diagnostic().render("message", input)
+    }
+}
+
+impl fmt::Display for ParseMessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.span.start)
+    }
+}
+
+impl std::error::Error for ParseMessageError {}
+
+// A small hand-written parser over the text, one token at a time
+struct Parser<'a> {
+    input: &'a str,
+    at: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.at..]
+    }
+
+    fn skip_space(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.at = self.input.len() - trimmed.len();
+    }
+
+    // The span of whatever comes next, for errors: the next char, or the end of the input
+    fn here(&self) -> Range<usize> {
+        let len = self.rest().chars().next().map_or(0, char::len_utf8);
+        self.at..self.at + len
+    }
+
+    fn expect(&mut self, token: char) -> Result<(), ParseMessageError> {
+        self.skip_space();
+        if self.rest().starts_with(token) {
+            self.at += 1;
+            Ok(())
+        } else {
+            Err(ParseMessageError::new(
+                &format!("expected `{token}`"),
+                self.here(),
+            ))
+        }
+    }
+
+    // Consumes `token` if it's next
+    fn eat(&mut self, token: char) -> bool {
+        self.skip_space();
+        let found = self.rest().starts_with(token);
+        if found {
+            self.at += 1;
+        }
+        found
+    }
+
+    fn word(&mut self) -> (&'a str, Range<usize>) {
+        self.skip_space();
+        let len = self
+            .rest()
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(self.rest().len());
+        let start = self.at;
+        self.at += len;
+        (&self.input[start..self.at], start..self.at)
+    }
+
+    fn int(&mut self) -> Result<i32, ParseMessageError> {
+        self.skip_space();
+        let start = self.at;
+        let sign = usize::from(self.rest().starts_with('-'));
+        let digits = self.rest()[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest().len() - sign);
+        if digits == 0 {
+            return Err(ParseMessageError::new("expected a number", self.here()));
+        }
+        self.at += sign + digits;
+        self.input[start..self.at]
+            .parse()
+            .map_err(|_| ParseMessageError::new("doesn't fit in an i32", start..self.at))
+    }
+
+    // A Rust string literal, with the escapes `{:?}` produces
+    fn string(&mut self) -> Result<String, ParseMessageError> {
+        self.skip_space();
+        if !self.rest().starts_with('"') {
+            return Err(ParseMessageError::new(
+                "expected a string in double quotes",
+                self.here(),
+            ));
+        }
+        let open = self.at;
+        self.at += 1;
+        let mut out = String::new();
+        loop {
+            let Some(c) = self.rest().chars().next() else {
+                return Err(ParseMessageError::new(
+                    "this string is never closed",
+                    open..open + 1,
+                ));
+            };
+            let escape_at = self.at;
+            self.at += c.len_utf8();
+            match c {
+                '"' => return Ok(out),
+                '\\' => {
+                    let Some(e) = self.rest().chars().next() else {
+                        continue;
+                    };
+                    self.at += e.len_utf8();
+                    out.push(match e {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        '0' => '\0',
+                        '\\' | '"' | '\'' => e,
+                        'u' => self.unicode_escape(escape_at)?,
+                        _ => {
+                            return Err(ParseMessageError::new(
+                                "unknown escape",
+                                escape_at..self.at,
+                            ));
+                        }
+                    });
+                }
+                c => out.push(c),
+            }
+        }
+    }
+
+    // The {1F600} part of \u{1F600}
+    fn unicode_escape(&mut self, escape_at: usize) -> Result<char, ParseMessageError> {
+        let bad = |end: usize| {
+            ParseMessageError::new(
+                "expected a \\u{...} escape with 1 to 6 hex digits",
+                escape_at..end,
+            )
+        };
+        let rest = self.rest();
+        let close = rest.find('}').ok_or_else(|| bad(self.at))?;
+        let hex = rest
+            .strip_prefix('{')
+            .map(|r| &r[..close - 1])
+            .ok_or_else(|| bad(self.at))?;
+        let end = self.at + close + 1;
+        if hex.is_empty() || hex.len() > 6 {
+            return Err(bad(end));
+        }
+        let value = u32::from_str_radix(hex, 16).map_err(|_| bad(end))?;
+        self.at = end;
+        char::from_u32(value)
+            .ok_or_else(|| ParseMessageError::new("not a valid char", escape_at..end))
+    }
+
+    fn message(&mut self) -> Result<Message, ParseMessageError> {
+        let (name, span) = self.word();
+        let message = match name {
+            "Quit" => Message::Quit,
+            "Move" => {
+                self.expect('{')?;
+                let (mut x, mut y) = (None, None);
+                while !self.eat('}') {
+                    let (field, field_span) = self.word();
+                    let slot = match field {
+                        "x" => &mut x,
+                        "y" => &mut y,
+                        "" => {
+                            return Err(ParseMessageError::new(
+                                "expected `x`, `y` or `}`",
+                                self.here(),
+                            ));
+                        }
+                        _ => {
+                            return Err(ParseMessageError::new(
+                                "Move only has `x` and `y`",
+                                field_span,
+                            ));
+                        }
+                    };
+                    if slot.is_some() {
+                        return Err(ParseMessageError::new(
+                            &format!("`{field}` is given twice"),
+                            field_span,
+                        ));
+                    }
+                    self.expect(':')?;
+                    *slot = Some(self.int()?);
+                    if !self.eat(',') {
+                        self.expect('}')?;
+                        break;
+                    }
+                }
+                match (x, y) {
+                    (Some(x), Some(y)) => Message::Move { x, y },
+                    _ => {
+                        let missing = if x.is_none() { "x" } else { "y" };
+                        return Err(ParseMessageError::new(
+                            &format!("`{missing}` is missing"),
+                            span,
+                        ));
+                    }
+                }
+            }
+            "Write" => {
+                self.expect('(')?;
+                let text = self.string()?;
+                self.expect(')')?;
+                Message::Write(text)
+            }
+            "ChangeColor" => {
+                self.expect('(')?;
+                let r = self.int()?;
+                self.expect(',')?;
+                let g = self.int()?;
+                self.expect(',')?;
+                let b = self.int()?;
+                self.eat(',');
+                self.expect(')')?;
+                Message::ChangeColor(r, g, b)
+            }
+            "" => return Err(ParseMessageError::new("expected a message", self.here())),
+            _ => {
+                return Err(ParseMessageError::new(
+                    &format!("`{name}` isn't a message"),
+                    span,
+                ));
+            }
+        };
+        self.skip_space();
+        if !self.rest().is_empty() {
+            return Err(ParseMessageError::new(
+                "unexpected text after the message",
+                self.at..self.input.len(),
+            ));
+        }
+        Ok(message)
+    }
+}
+
+impl FromStr for Message {
+    type Err = ParseMessageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser { input: s, at: 0 }.message()
+    }
+}
+
+pub fn start() {
+    let messages = [
+        Message::Quit,
+        Message::Move { x: 10, y: -3 },
+        Message::Write(String::from("Hello World")),
+        Message::ChangeColor(255, 0, 128),
+    ];
+    for m in &messages {
+        let bytes = m.encode();
+        println!(
+            "{:<34} {} byte(s): {:02x?}",
+            m.to_string(),
+            bytes.len(),
+            bytes
+        );
+    }
+    for bad in [
+        "Move { x: 1 }",
+        "Write(\"unclosed)",
+        "ChangeColor(1, 2, 99999999999)",
+        "Jump { x: 1 }",
+    ] {
+        print!("{}", bad.parse::<Message>().unwrap_err().render(bad));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    fn random_message(rng: &mut impl Rng) -> Message {
+        let int = |rng: &mut dyn rand::RngCore| match rng.gen_range(0..4) {
+            0 => 0,
+            1 => rng.gen_range(-200..200),
+            2 => [i32::MIN, i32::MAX, -1, 63, 64, -64, -65][rng.gen_range(0..7)],
+            _ => rng.r#gen(),
+        };
+        match rng.gen_range(0..4) {
+            0 => Message::Quit,
+            1 => Message::Move {
+                x: int(rng),
+                y: int(rng),
+            },
+            2 => {
+                // ASCII, quotes and escapes, control characters, accents, CJK and emoji
+                const CHARS: [char; 16] = [
+                    'a', 'Z', ' ', '"', '\\', '\n', '\t', '\0', '\u{7f}', 'é', '\u{301}', '中',
+                    '😀', '\'', '{', '}',
+                ];
+                let len = rng.gen_range(0..20);
+                Message::Write(
+                    (0..len)
+                        .map(|_| CHARS[rng.gen_range(0..CHARS.len())])
+                        .collect(),
+                )
+            }
+            _ => Message::ChangeColor(int(rng), int(rng), int(rng)),
+        }
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(Message::Quit.encode(), [VERSION, 0]);
+        assert_eq!(Message::Move { x: 1, y: -1 }.encode(), [VERSION, 1, 2, 1]);
+        assert_eq!(
+            Message::Move { x: 64, y: -65 }.encode(),
+            [VERSION, 1, 0x80, 0x01, 0x81, 0x01]
+        );
+        assert_eq!(
+            Message::Write("hé".to_string()).encode(),
+            [VERSION, 2, 3, b'h', 0xc3, 0xa9]
+        );
+        assert_eq!(
+            Message::ChangeColor(i32::MAX, i32::MIN, 0).encode().len(),
+            2 + 5 + 5 + 1
+        );
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            Message::decode(&[]),
+            Err(DecodeError::Truncated { offset: 0 })
+        );
+        assert_eq!(
+            Message::decode(&[2, 0]),
+            Err(DecodeError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            Message::decode(&[1, 9]),
+            Err(DecodeError::UnknownTag { tag: 9, offset: 1 })
+        );
+        assert_eq!(
+            Message::decode(&[1, 1, 0x80]),
+            Err(DecodeError::Truncated { offset: 3 })
+        );
+        assert_eq!(
+            Message::decode(&[1, 1, 0x80, 0x00, 0]),
+            Err(DecodeError::BadVarint { offset: 2 })
+        ); // padded zero
+        assert_eq!(
+            Message::decode(&[1, 1, 0xff, 0xff, 0xff, 0xff, 0x1f, 0]),
+            Err(DecodeError::BadVarint { offset: 2 })
+        ); // 35 bits
+        assert_eq!(
+            Message::decode(&[1, 2, 2, 0xc3, 0x28]),
+            Err(DecodeError::InvalidUtf8 { offset: 3 })
+        );
+        assert_eq!(
+            Message::decode(&[1, 2, 0x80, 0x80, 0x80, 0x80, 0x10]),
+            Err(DecodeError::StringTooLong {
+                len: 1 << 32,
+                offset: 2
+            })
+        );
+        assert_eq!(
+            Message::decode(&[1, 2, 5, b'a']),
+            Err(DecodeError::Truncated { offset: 4 })
+        );
+        assert_eq!(
+            Message::decode_exact(&[1, 0, 1, 0]),
+            Err(DecodeError::TrailingBytes { offset: 2 })
+        );
+    }
+
+    #[test]
+    fn streams() {
+        let messages = [
+            Message::Quit,
+            Message::Move { x: 10, y: -3 },
+            Message::Write(String::from("Hello World")),
+            Message::ChangeColor(255, 0, 128),
+        ];
+        let stream = encode_all(&messages);
+        assert_eq!(decode_all(&stream).unwrap(), messages);
+        assert_eq!(
+            decode_all(&stream[..stream.len() - 1]),
+            Err(DecodeError::Truncated {
+                offset: stream.len() - 1
+            })
+        );
+    }
+
+    #[test]
+    fn text_form() {
+        assert_eq!(
+            "  Move{y:2,x:-1,}  ".parse(),
+            Ok(Message::Move { x: -1, y: 2 })
+        );
+        assert_eq!(
+            "Write(\"a\\u{1F600}\\\"\")".parse(),
+            Ok(Message::Write("a😀\"".to_string()))
+        );
+        let error_at = |s: &str| s.parse::<Message>().unwrap_err().span.start;
+        assert_eq!(error_at("Move { x: 1 }"), 0);
+        assert_eq!(error_at("Move { x: 1, x: 2 }"), 13);
+        assert_eq!(error_at("ChangeColor(1 2 3)"), 14);
+        assert_eq!(error_at("Write(\"\\q\")"), 7);
+        assert_eq!(error_at("Quit now"), 5);
+    }
+
+    // Random messages survive both forms, and broken frames give errors instead of panics
+    #[test]
+    fn random_round_trips() {
+        let mut rng = thread_rng();
+        for _ in 0..20_000 {
+            let m = random_message(&mut rng);
+            let bytes = m.encode();
+            assert_eq!(Message::decode_exact(&bytes), Ok(m.clone()));
+            assert_eq!(m.to_string().parse(), Ok(m.clone()), "{m}");
+            for cut in 0..bytes.len() {
+                assert!(matches!(
+                    Message::decode(&bytes[..cut]),
+                    Err(DecodeError::Truncated { .. })
+                ));
+            }
+            let mut broken = bytes.clone();
+            let at = rng.gen_range(0..broken.len());
+            broken[at] = rng.r#gen();
+            if let Ok((decoded, used)) = Message::decode(&broken) {
+                // whatever decodes has to encode back to the same bytes, since every message has one encoding
+                assert_eq!(decoded.encode(), broken[..used]);
+            }
+        }
+    }
+}
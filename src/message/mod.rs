@@ -0,0 +1,3 @@
+// The Message enum from chap_13_enums_and_pattern_matching, taken out of that chapter so it can be sent, stored and run.
+pub mod codec;
+pub mod turtle;
@@ -0,0 +1,526 @@
+#![allow(dead_code)]
+use super::codec::{self, Message, VERSION};
+use crate::color::rgb::Color;
+use crate::geometry::canvas::{AnsiColor, Canvas, terminal_height, terminal_width, text_width};
+use crate::geometry::svg::escape;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// Turtle graphics driven by chap_13's Message enum, so `m.call()` finally does something besides printing itself:
+//   Move { x, y }         moves the pen by (x, y) from where it is, drawing a line on the way
+//   ChangeColor(r, g, b)  the colour of everything drawn from now on (clamped into 0..=255)
+//   Write(text)           puts a label at the pen
+//   Quit                  ends the script, anything after it is ignored
+// Coordinates are whole world units with y going down, like the rows of a Canvas and like SVG.
+//
+// A script is a text file with one message per line in its `{:?}` form and `#` comments, or a file of binary
+// frames from message::codec.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stroke {
+    Line {
+        from: (i32, i32),
+        to: (i32, i32),
+        color: Color,
+    },
+    Label {
+        at: (i32, i32),
+        text: String,
+        color: Color,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turtle {
+    pub pen: (i32, i32),
+    pub color: Color,
+    pub strokes: Vec<Stroke>,
+    // set by Quit
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    // 1-based, 0 when the file couldn't be read or isn't line based
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}: {}", self.line, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+// The line without its `#` comment, leaving any # inside a Write("...") string alone
+fn strip_comment(line: &str) -> &str {
+    let (mut quoted, mut escaped) = (false, false);
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+pub fn parse_script(text: &str) -> Result<Vec<Message>, ScriptError> {
+    let mut messages = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = strip_comment(line);
+        if line.trim().is_empty() {
+            continue;
+        }
+        let message = line
+            .parse()
+            .map_err(|e: codec::ParseMessageError| ScriptError {
+                line: n + 1,
+                message: format!(
+                    "{} (column {})",
+                    e.message,
+                    line[..e.span.start].chars().count() + 1
+                ),
+            })?;
+        messages.push(message);
+    }
+    Ok(messages)
+}
+
+// Writes the messages back as a script, one per line
+pub fn to_script(messages: &[Message]) -> String {
+    messages.iter().map(|m| format!("{m}\n")).collect()
+}
+
+// Text scripts, or binary frames if the file starts with the codec's version byte (a text script never does)
+pub fn load_script(path: impl AsRef<Path>) -> Result<Vec<Message>, ScriptError> {
+    let path = path.as_ref();
+    let bytes = fs::read(path).map_err(|e| ScriptError {
+        line: 0,
+        message: format!("couldn't read {}: {e}", path.display()),
+    })?;
+    if bytes.first() == Some(&VERSION) {
+        return codec::decode_all(&bytes).map_err(|e| ScriptError {
+            line: 0,
+            message: format!("{}: {e}", path.display()),
+        });
+    }
+    let text = String::from_utf8(bytes).map_err(|_| ScriptError {
+        line: 0,
+        message: format!(
+            "{} is neither a text script nor binary messages",
+            path.display()
+        ),
+    })?;
+    parse_script(&text)
+}
+
+// The closest of the 8 basic terminal colours. Black and white become the terminal's own colour instead, so the
+// drawing shows up on both dark and light backgrounds.
+fn nearest_ansi(color: Color) -> Option<AnsiColor> {
+    const BASIC: [(AnsiColor, Color); 8] = [
+        (AnsiColor::Black, Color(0, 0, 0)),
+        (AnsiColor::Red, Color(205, 0, 0)),
+        (AnsiColor::Green, Color(0, 205, 0)),
+        (AnsiColor::Yellow, Color(205, 205, 0)),
+        (AnsiColor::Blue, Color(0, 0, 238)),
+        (AnsiColor::Magenta, Color(205, 0, 205)),
+        (AnsiColor::Cyan, Color(0, 205, 205)),
+        (AnsiColor::White, Color(229, 229, 229)),
+    ];
+    let distance = |c: Color| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(color.0, c.0) + d(color.1, c.1) + d(color.2, c.2)
+    };
+    let (nearest, _) = BASIC.iter().min_by_key(|(_, c)| distance(*c))?;
+    match nearest {
+        AnsiColor::Black | AnsiColor::White => None,
+        ansi => Some(*ansi),
+    }
+}
+
+impl Turtle {
+    // At (0, 0) with a black pen
+    pub fn new() -> Self {
+        Self {
+            pen: (0, 0),
+            color: Color::BLACK,
+            strokes: Vec::new(),
+            done: false,
+        }
+    }
+
+    // Carries out one message, returns false once the script has quit
+    pub fn step(&mut self, message: &Message) -> bool {
+        if self.done {
+            return false;
+        }
+        match message {
+            Message::Quit => self.done = true,
+            Message::Move { x, y } => {
+                let from = self.pen;
+                self.pen = (from.0.saturating_add(*x), from.1.saturating_add(*y));
+                self.strokes.push(Stroke::Line {
+                    from,
+                    to: self.pen,
+                    color: self.color,
+                });
+            }
+            Message::Write(text) => self.strokes.push(Stroke::Label {
+                at: self.pen,
+                text: text.clone(),
+                color: self.color,
+            }),
+            Message::ChangeColor(r, g, b) => self.color = Color::from_i32(*r, *g, *b),
+        }
+        !self.done
+    }
+
+    // Runs messages until Quit, returns how many were carried out (counting the Quit)
+    pub fn run<'a>(&mut self, messages: impl IntoIterator<Item = &'a Message>) -> usize {
+        let mut count = 0;
+        for message in messages {
+            if self.done {
+                break;
+            }
+            self.step(message);
+            count += 1;
+        }
+        count
+    }
+
+    // (left, top, right, bottom) around everything drawn, a label counting one unit per column Canvas::text gives it
+    pub fn bounds(&self) -> Option<(i32, i32, i32, i32)> {
+        let mut points = Vec::new();
+        for stroke in &self.strokes {
+            match stroke {
+                Stroke::Line { from, to, .. } => points.extend([*from, *to]),
+                Stroke::Label { at, text, .. } => {
+                    let width = text_width(text).saturating_sub(1) as i32;
+                    points.extend([*at, (at.0.saturating_add(width), at.1)]);
+                }
+            }
+        }
+        let left = points.iter().map(|p| p.0).min()?;
+        let top = points.iter().map(|p| p.1).min()?;
+        let right = points.iter().map(|p| p.0).max()?;
+        let bottom = points.iter().map(|p| p.1).max()?;
+        Some((left, top, right, bottom))
+    }
+
+    // The drawing on a canvas at most `max_columns` x `max_rows`, shifted so its top-left corner is the canvas's
+    pub fn to_canvas(&self, max_columns: usize, max_rows: usize) -> Canvas {
+        let Some((left, top, right, bottom)) = self.bounds() else {
+            return Canvas::new(1, 1);
+        };
+        // Scaled so the far corner lands on the last column and row, not one past them, never scaled up
+        let (span_x, span_y) = (right as f64 - left as f64, bottom as f64 - top as f64);
+        let scale = (max_columns.saturating_sub(1) as f64 / span_x)
+            .min(2.0 * max_rows.saturating_sub(1) as f64 / span_y)
+            .min(1.0);
+        let width = (span_x * scale).round() as usize + 1;
+        let height = (span_y * scale / 2.0).round() as usize + 1;
+        let mut canvas = Canvas::with_scale(width, height, scale, scale / 2.0);
+        // in i64, since the far corner can be further from the top-left one than an i32 reaches
+        let shift = |(x, y): (i32, i32)| (x as i64 - left as i64, y as i64 - top as i64);
+        for stroke in &self.strokes {
+            match stroke {
+                Stroke::Line { from, to, color } => {
+                    canvas.color = nearest_ansi(*color);
+                    let ((x0, y0), (x1, y1)) = (shift(*from), shift(*to));
+                    canvas.line(x0, y0, x1, y1);
+                }
+                Stroke::Label { at, text, color } => {
+                    canvas.color = nearest_ansi(*color);
+                    let (x, y) = shift(*at);
+                    canvas.text(x, y, text);
+                }
+            }
+        }
+        canvas
+    }
+
+    // A standalone SVG with the same coordinates as the drawing, labels in a monospace font 4 units high
+    pub fn to_svg(&self) -> String {
+        let (left, top, right, bottom) = self.bounds().unwrap_or((0, 0, 0, 0));
+        let margin = 4;
+        let (x, y) = (left as i64 - margin, top as i64 - margin);
+        let (width, height) = (
+            right as i64 - left as i64 + 2 * margin,
+            bottom as i64 - top as i64 + 2 * margin,
+        );
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{x} {y} {width} {height}\">\n",
+            width * 4,
+            height * 4
+        );
+        out.push_str("  <g stroke-width=\"0.5\" stroke-linecap=\"round\" font-family=\"monospace\" font-size=\"4\">\n");
+        for stroke in &self.strokes {
+            let element = match stroke {
+                Stroke::Line { from, to, color } => format!(
+                    "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\"/>",
+                    from.0,
+                    from.1,
+                    to.0,
+                    to.1,
+                    color.to_hex()
+                ),
+                Stroke::Label { at, text, color } => format!(
+                    "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>",
+                    at.0,
+                    at.1,
+                    color.to_hex(),
+                    escape(text)
+                ),
+            };
+            out.push_str(&format!("    {element}\n"));
+        }
+        out.push_str("  </g>\n</svg>\n");
+        out
+    }
+}
+
+impl Default for Turtle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Runs a script file: `turtle_command [script] [out.svg]`, drawing it in the terminal or writing it as SVG
+pub fn turtle_command() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/assets/house.turtle").to_string());
+    let messages = match load_script(&path) {
+        Ok(messages) => messages,
+        Err(e) => {
+            println!("{path}: {e}");
+            return;
+        }
+    };
+    let mut turtle = Turtle::new();
+    let ran = turtle.run(&messages);
+    match std::env::args().nth(2) {
+        Some(out) => match fs::write(&out, turtle.to_svg()) {
+            Ok(()) => println!("Wrote {} strokes to {out}", turtle.strokes.len()),
+            Err(e) => println!("Couldn't write {out}: {e}"),
+        },
+        None => print!(
+            "{}",
+            turtle
+                .to_canvas(
+                    terminal_width().saturating_sub(1).max(1),
+                    terminal_height().saturating_sub(1).max(1)
+                )
+                .render_ansi()
+        ),
+    }
+    if ran < messages.len() {
+        println!(
+            "(stopped at Quit, {} message(s) not run)",
+            messages.len() - ran
+        );
+    }
+}
+
+pub fn start() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/house.turtle");
+    let messages = load_script(path).unwrap();
+    let mut turtle = Turtle::new();
+    let ran = turtle.run(&messages);
+    println!(
+        "{ran} of {} messages from assets/house.turtle:",
+        messages.len()
+    );
+    print!("{}", turtle.to_canvas(80, 24).render_ansi());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    #[test]
+    fn house() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/house.turtle");
+        let messages = load_script(path).unwrap();
+        let mut turtle = Turtle::new();
+        let ran = turtle.run(&messages);
+        assert_eq!(ran, messages.len() - 1);
+        assert!(turtle.done);
+        assert_eq!(turtle.pen, (42, 20));
+        assert_eq!(turtle.strokes.len(), 9);
+        assert_eq!(turtle.bounds(), Some((0, -14, 58, 20)));
+        assert_eq!(
+            turtle.strokes.last(),
+            Some(&Stroke::Label {
+                at: (42, 20),
+                text: "home # sweet home".to_string(),
+                color: Color(34, 139, 34),
+            })
+        );
+        let svg = turtle.to_svg();
+        assert_eq!(svg.matches("<line ").count(), 8);
+        assert!(svg.contains("<line x1=\"0\" y1=\"0\" x2=\"30\" y2=\"0\" stroke=\"#cd853f\"/>"));
+        assert!(svg.contains("<text x=\"42\" y=\"20\" fill=\"#228b22\">home # sweet home</text>"));
+    }
+
+    #[test]
+    fn square() {
+        let mut square = Turtle::new();
+        square.run(
+            &parse_script("Move{x:4,y:0}\nMove{x:0,y:4}\nMove{x:-4,y:0}\nMove{x:0,y:-4}").unwrap(),
+        );
+        assert_eq!(square.to_canvas(80, 24).render(), "┌───┐\n│   │\n└───┘\n");
+        assert_eq!(Turtle::new().to_canvas(80, 24).render(), "\n");
+        assert!(!Turtle::new().to_svg().contains("<line"));
+    }
+
+    // A drawing far taller than the terminal is scaled down to fit it, instead of asking for a billion cells
+    #[test]
+    fn tall_drawings_fit_the_terminal() {
+        let mut needle = Turtle::new();
+        needle.run(&parse_script("Move { x: 0, y: 2000000000 }").unwrap());
+        let canvas = needle.to_canvas(80, 24);
+        assert_eq!((canvas.width(), canvas.height()), (1, 24));
+        assert_eq!(
+            canvas.render(),
+            "╷\n".to_string() + &"│\n".repeat(22) + "╵\n"
+        );
+    }
+
+    // The far corner is more than i32::MAX from the top-left one, which used to wrap round
+    #[test]
+    fn wide_drawings_dont_wrap() {
+        let mut zigzag = Turtle::new();
+        zigzag.run(
+            &parse_script(
+                "Move { x: -2000000000, y: 0 }\nMove { x: 2000000000, y: 1000000000 }\nMove { x: 2000000000, y: 0 }",
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            zigzag.bounds(),
+            Some((-2_000_000_000, 0, 2_000_000_000, 1_000_000_000))
+        );
+        let canvas = zigzag.to_canvas(40, 24);
+        assert_eq!((canvas.width(), canvas.height()), (40, 6));
+        assert_eq!(
+            canvas.render(),
+            "─╲──────────────────╴\n  ───╲\n      ───╲\n          ───╲\n              ───╲\n                  ──╶──────────────────╴\n"
+        );
+    }
+
+    // Control characters are written escaped and wide ones take two columns, so the label stays on its row and
+    // ends where the bounds say it does. The line down from the label's start covers its first character, and
+    // covering half of 日 blanks the other half rather than shifting the rest of the row.
+    #[test]
+    fn labels_keep_to_the_grid() {
+        let label = |text: &str| {
+            let mut turtle = Turtle::new();
+            turtle.run(&[
+                Message::Move { x: 10, y: 0 },
+                Message::Write(text.to_string()),
+                Message::Move { x: 0, y: 2 },
+            ]);
+            (turtle.bounds(), turtle.to_canvas(80, 24).render())
+        };
+        assert_eq!(
+            label("ab\ncd"),
+            (
+                Some((0, 0, 15, 2)),
+                "╶─────────╷b\\ncd\n          ╵\n".to_string()
+            )
+        );
+        assert_eq!(
+            label("日本語!"),
+            (
+                Some((0, 0, 16, 2)),
+                "╶─────────╷ 本語!\n          ╵\n".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn colours_and_comments() {
+        assert_eq!(nearest_ansi(Color(220, 20, 60)), Some(AnsiColor::Red));
+        assert_eq!(nearest_ansi(Color(250, 250, 250)), None);
+        assert_eq!(
+            strip_comment("Write(\"a # b \\\" # c\") # note"),
+            "Write(\"a # b \\\" # c\") "
+        );
+    }
+
+    #[test]
+    fn script_errors() {
+        let error = parse_script("# fine\nQuit\n  Jump { x: 1 }").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: `Jump` isn't a message (column 3)"
+        );
+        let error = parse_script("Write(\"日本\" 1)").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: expected `)` (column 12)");
+        assert_eq!(load_script("no/such.turtle").unwrap_err().line, 0);
+    }
+
+    // Random scripts: the pen ends up at the sum of the moves, every Move and Write leaves one stroke, and
+    // the text and binary forms of a script draw exactly the same thing
+    #[test]
+    fn random_scripts() {
+        let mut rng = thread_rng();
+        for _ in 0..2000 {
+            let len = rng.gen_range(0..30);
+            let messages: Vec<Message> = (0..len)
+                .map(|_| match rng.gen_range(0..10) {
+                    0 => Message::Quit,
+                    1 | 2 => Message::ChangeColor(
+                        rng.gen_range(-10..300),
+                        rng.gen_range(0..256),
+                        rng.gen_range(0..256),
+                    ),
+                    3 => Message::Write(format!("#{}", rng.gen_range(0..100))),
+                    _ => Message::Move {
+                        x: rng.gen_range(-50..50),
+                        y: rng.gen_range(-50..50),
+                    },
+                })
+                .collect();
+            let mut turtle = Turtle::new();
+            let ran = turtle.run(&messages);
+            let active = &messages[..ran];
+            let (mut x, mut y) = (0, 0);
+            for m in active {
+                if let Message::Move { x: dx, y: dy } = m {
+                    (x, y) = (x + dx, y + dy);
+                }
+            }
+            assert_eq!(turtle.pen, (x, y));
+            let drawn = active
+                .iter()
+                .filter(|m| matches!(m, Message::Move { .. } | Message::Write(_)))
+                .count();
+            assert_eq!(turtle.strokes.len(), drawn);
+            assert_eq!(turtle.done, active.contains(&Message::Quit));
+
+            let mut from_text = Turtle::new();
+            from_text.run(&parse_script(&to_script(&messages)).unwrap());
+            let mut from_bytes = Turtle::new();
+            from_bytes.run(&codec::decode_all(&codec::encode_all(&messages)).unwrap());
+            assert_eq!(from_text, turtle);
+            assert_eq!(from_bytes, turtle);
+            assert_eq!(
+                turtle.to_canvas(60, 24).render(),
+                from_bytes.to_canvas(60, 24).render()
+            );
+        }
+    }
+}
@@ -0,0 +1,674 @@
+#![allow(dead_code)]
+use crate::text::diagnostics::Diagnostic;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+// chap_13 keeps addresses as Strings (`IpAddrEnum::V4(String)`), so "999.1.2.3" and "hello" are both fine V4s
+// there. These types hold the actual numbers instead, and the only way to get one from text is through the strict
+// parsers below.
+//
+// IPv4 is the dotted quad and nothing else: exactly four decimal parts, each 0..=255, no leading zeros (some tools
+// read "010" as octal, so it's ambiguous) and no shorthand like "127.1".
+// IPv6 takes the full form, one "::" for a run of zero groups, and a dotted quad in place of the last two groups
+// ("::ffff:192.0.2.1"). Zone ids ("fe80::1%eth0") aren't addresses and are rejected.
+//
+// Display follows RFC 5952: lowercase, no leading zeros, the longest run of two or more zero groups becomes "::"
+// (the first one on a tie), and IPv4-mapped addresses keep their dotted quad.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ipv4Addr(pub [u8; 4]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ipv6Addr(pub [u16; 8]);
+
+// The same shape as chap_13's IpAddrEnum, with real addresses in the variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IpAddr {
+    V4(Ipv4Addr),
+    V6(Ipv6Addr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Unspecified,
+    Loopback,
+    // 10/8, 172.16/12, 192.168/16
+    Private,
+    // 100.64/10, carrier-grade NAT
+    Shared,
+    LinkLocal,
+    Multicast,
+    Broadcast,
+    Documentation,
+    // 198.18/15
+    Benchmarking,
+    // fc00::/7, IPv6's version of private
+    UniqueLocal,
+    // 0/8 and 240/4
+    Reserved,
+    Global,
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Category::Unspecified => "unspecified",
+            Category::Loopback => "loopback",
+            Category::Private => "private",
+            Category::Shared => "shared (CGNAT)",
+            Category::LinkLocal => "link-local",
+            Category::Multicast => "multicast",
+            Category::Broadcast => "broadcast",
+            Category::Documentation => "documentation",
+            Category::Benchmarking => "benchmarking",
+            Category::UniqueLocal => "unique local",
+            Category::Reserved => "reserved",
+            Category::Global => "global",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAddrError {
+    pub message: String,
+    // byte range of the offending part of the input
+    pub span: Range<usize>,
+}
+
+impl ParseAddrError {
+    pub fn new(message: &str, span: Range<usize>) -> Self {
+        Self {
+            message: message.to_string(),
+            span,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.span.start
+    }
+
+    pub fn diagnostic(&self) -> Diagnostic {
+        Diagnostic::error("couldn't parse the address").with_label(self.span.clone(), &self.message)
+    }
+
+    // The rustc-style report, `input` has to be the string that was parsed
+    pub fn render(&self, input: &str) -> String {
+        self.diagnostic().render("address", input)
+    }
+}
+
+impl fmt::Display for ParseAddrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.span.start)
+    }
+}
+
+impl std::error::Error for ParseAddrError {}
+
+// Parses a dotted quad that starts `offset` bytes into the original input, so errors point at the right place
+fn parse_v4(s: &str, offset: usize) -> Result<Ipv4Addr, ParseAddrError> {
+    if s.is_empty() {
+        return Err(ParseAddrError::new(
+            "expected an IPv4 address",
+            offset..offset,
+        ));
+    }
+    let mut octets = [0u8; 4];
+    let mut start = 0;
+    for (i, part) in s.split('.').enumerate() {
+        let span = offset + start..offset + start + part.len();
+        start += part.len() + 1;
+        if i == 4 {
+            let rest = span.start - 1..offset + s.len();
+            return Err(ParseAddrError::new(
+                "an IPv4 address has only four parts",
+                rest,
+            ));
+        }
+        if part.is_empty() {
+            return Err(ParseAddrError::new(
+                "this part is empty",
+                span.start..span.start + 1,
+            ));
+        }
+        if let Some(bad) = part.find(|c: char| !c.is_ascii_digit()) {
+            let at = span.start + bad;
+            let len = part[bad..].chars().next().unwrap().len_utf8();
+            return Err(ParseAddrError::new(
+                "only digits are allowed here",
+                at..at + len,
+            ));
+        }
+        if part.len() > 1 && part.starts_with('0') {
+            return Err(ParseAddrError::new("leading zeros aren't allowed", span));
+        }
+        octets[i] = match part.parse::<u8>() {
+            Ok(n) => n,
+            Err(_) => return Err(ParseAddrError::new("must be between 0 and 255", span)),
+        };
+    }
+    let parts = s.split('.').count();
+    if parts < 4 {
+        let end = offset + s.len();
+        return Err(ParseAddrError::new(
+            &format!("an IPv4 address has four parts, found {parts}"),
+            end..end,
+        ));
+    }
+    Ok(Ipv4Addr(octets))
+}
+
+// The groups of one side of a "::" (or the whole address if there isn't one), with a trailing dotted quad turned
+// into two groups when `allow_v4` says this side ends the address
+fn parse_groups(s: &str, offset: usize, allow_v4: bool) -> Result<Vec<u16>, ParseAddrError> {
+    let mut groups = Vec::new();
+    if s.is_empty() {
+        return Ok(groups);
+    }
+    let parts: Vec<&str> = s.split(':').collect();
+    let mut start = offset;
+    for (i, part) in parts.iter().enumerate() {
+        let span = start..start + part.len();
+        start += part.len() + 1;
+        if part.contains('.') {
+            if !allow_v4 || i + 1 != parts.len() {
+                return Err(ParseAddrError::new(
+                    "an embedded IPv4 address can only be at the end",
+                    span,
+                ));
+            }
+            let Ipv4Addr([a, b, c, d]) = parse_v4(part, span.start)?;
+            groups.push(u16::from_be_bytes([a, b]));
+            groups.push(u16::from_be_bytes([c, d]));
+            continue;
+        }
+        if part.is_empty() {
+            // a single ':' at either end, or ":::" somewhere
+            let at = span.start.saturating_sub(1);
+            return Err(ParseAddrError::new(
+                "expected a group of hex digits",
+                at..at + 1,
+            ));
+        }
+        if let Some(bad) = part.find(|c: char| !c.is_ascii_hexdigit()) {
+            let at = span.start + bad;
+            let len = part[bad..].chars().next().unwrap().len_utf8();
+            return Err(ParseAddrError::new(
+                "only hex digits are allowed here",
+                at..at + len,
+            ));
+        }
+        if part.len() > 4 {
+            return Err(ParseAddrError::new(
+                "a group has at most 4 hex digits",
+                span,
+            ));
+        }
+        groups.push(u16::from_str_radix(part, 16).unwrap());
+    }
+    Ok(groups)
+}
+
+fn parse_v6(s: &str) -> Result<Ipv6Addr, ParseAddrError> {
+    if s.is_empty() {
+        return Err(ParseAddrError::new("expected an IPv6 address", 0..0));
+    }
+    if let Some(zone) = s.find('%') {
+        return Err(ParseAddrError::new(
+            "zone ids aren't part of the address",
+            zone..s.len(),
+        ));
+    }
+    let Some(gap) = s.find("::") else {
+        let groups = parse_groups(s, 0, true)?;
+        if groups.len() != 8 {
+            let end = s.len();
+            return Err(ParseAddrError::new(
+                &format!(
+                    "expected 8 groups, found {} (use :: for zeros)",
+                    groups.len()
+                ),
+                end..end,
+            ));
+        }
+        let mut out = [0u16; 8];
+        out.copy_from_slice(&groups);
+        return Ok(Ipv6Addr(out));
+    };
+    let (head, tail) = (&s[..gap], &s[gap + 2..]);
+    if let Some(second) = tail.find("::") {
+        let at = gap + 2 + second;
+        return Err(ParseAddrError::new("only one :: is allowed", at..at + 2));
+    }
+    let head_groups = parse_groups(head, 0, false)?;
+    let tail_groups = parse_groups(tail, gap + 2, true)?;
+    let count = head_groups.len() + tail_groups.len();
+    if count > 7 {
+        return Err(ParseAddrError::new(
+            &format!("too many groups around :: ({count}, at most 7)"),
+            gap..gap + 2,
+        ));
+    }
+    let mut out = [0u16; 8];
+    out[..head_groups.len()].copy_from_slice(&head_groups);
+    out[8 - tail_groups.len()..].copy_from_slice(&tail_groups);
+    Ok(Ipv6Addr(out))
+}
+
+impl Ipv4Addr {
+    pub const UNSPECIFIED: Ipv4Addr = Ipv4Addr([0, 0, 0, 0]);
+    pub const LOCALHOST: Ipv4Addr = Ipv4Addr([127, 0, 0, 1]);
+    pub const BROADCAST: Ipv4Addr = Ipv4Addr([255, 255, 255, 255]);
+
+    pub fn new(a: u8, b: u8, c: u8, d: u8) -> Self {
+        Ipv4Addr([a, b, c, d])
+    }
+
+    pub fn to_bits(self) -> u32 {
+        u32::from_be_bytes(self.0)
+    }
+
+    pub fn from_bits(bits: u32) -> Self {
+        Ipv4Addr(bits.to_be_bytes())
+    }
+
+    // Whether the first `len` bits match `prefix`
+    fn in_prefix(self, prefix: [u8; 4], len: u32) -> bool {
+        let mask = u32::MAX.checked_shl(32 - len).unwrap_or(0);
+        self.to_bits() & mask == u32::from_be_bytes(prefix) & mask
+    }
+
+    pub fn category(self) -> Category {
+        let prefixes: [([u8; 4], u32, Category); 14] = [
+            ([0, 0, 0, 0], 32, Category::Unspecified),
+            ([255, 255, 255, 255], 32, Category::Broadcast),
+            ([0, 0, 0, 0], 8, Category::Reserved),
+            ([127, 0, 0, 0], 8, Category::Loopback),
+            ([10, 0, 0, 0], 8, Category::Private),
+            ([172, 16, 0, 0], 12, Category::Private),
+            ([192, 168, 0, 0], 16, Category::Private),
+            ([100, 64, 0, 0], 10, Category::Shared),
+            ([169, 254, 0, 0], 16, Category::LinkLocal),
+            ([192, 0, 2, 0], 24, Category::Documentation),
+            ([198, 51, 100, 0], 24, Category::Documentation),
+            ([203, 0, 113, 0], 24, Category::Documentation),
+            ([198, 18, 0, 0], 15, Category::Benchmarking),
+            ([224, 0, 0, 0], 4, Category::Multicast),
+        ];
+        for (prefix, len, category) in prefixes {
+            if self.in_prefix(prefix, len) {
+                return category;
+            }
+        }
+        if self.in_prefix([240, 0, 0, 0], 4) {
+            return Category::Reserved;
+        }
+        Category::Global
+    }
+
+    // ::ffff:a.b.c.d, how IPv6 sockets see IPv4 peers
+    pub fn to_mapped(self) -> Ipv6Addr {
+        let [a, b, c, d] = self.0;
+        Ipv6Addr([
+            0,
+            0,
+            0,
+            0,
+            0,
+            0xffff,
+            u16::from_be_bytes([a, b]),
+            u16::from_be_bytes([c, d]),
+        ])
+    }
+}
+
+impl Ipv6Addr {
+    pub const UNSPECIFIED: Ipv6Addr = Ipv6Addr([0; 8]);
+    pub const LOCALHOST: Ipv6Addr = Ipv6Addr([0, 0, 0, 0, 0, 0, 0, 1]);
+
+    pub fn to_bits(self) -> u128 {
+        self.0.iter().fold(0, |bits, &g| bits << 16 | g as u128)
+    }
+
+    pub fn from_bits(bits: u128) -> Self {
+        let mut groups = [0u16; 8];
+        for (i, g) in groups.iter_mut().enumerate() {
+            *g = (bits >> (112 - 16 * i)) as u16;
+        }
+        Ipv6Addr(groups)
+    }
+
+    fn in_prefix(self, prefix: u128, len: u32) -> bool {
+        let mask = u128::MAX.checked_shl(128 - len).unwrap_or(0);
+        self.to_bits() & mask == prefix & mask
+    }
+
+    // The IPv4 address inside ::ffff:a.b.c.d
+    pub fn to_ipv4_mapped(self) -> Option<Ipv4Addr> {
+        match self.0 {
+            [0, 0, 0, 0, 0, 0xffff, hi, lo] => {
+                let [a, b] = hi.to_be_bytes();
+                let [c, d] = lo.to_be_bytes();
+                Some(Ipv4Addr([a, b, c, d]))
+            }
+            _ => None,
+        }
+    }
+
+    // Mapped addresses are categorised by the IPv4 address they carry
+    pub fn category(self) -> Category {
+        if let Some(v4) = self.to_ipv4_mapped() {
+            return v4.category();
+        }
+        let prefixes: [(u128, u32, Category); 6] = [
+            (0, 128, Category::Unspecified),
+            (1, 128, Category::Loopback),
+            (0xff00 << 112, 8, Category::Multicast),
+            (0xfe80 << 112, 10, Category::LinkLocal),
+            (0xfc00 << 112, 7, Category::UniqueLocal),
+            (0x2001_0db8 << 96, 32, Category::Documentation),
+        ];
+        for (prefix, len, category) in prefixes {
+            if self.in_prefix(prefix, len) {
+                return category;
+            }
+        }
+        Category::Global
+    }
+
+    // The first longest run of at least two zero groups, as (start, length)
+    fn zero_run(self) -> Option<(usize, usize)> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut i = 0;
+        while i < 8 {
+            if self.0[i] != 0 {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < 8 && self.0[i] == 0 {
+                i += 1;
+            }
+            let len = i - start;
+            if len >= 2 && best.is_none_or(|(_, best_len)| len > best_len) {
+                best = Some((start, len));
+            }
+        }
+        best
+    }
+}
+
+impl IpAddr {
+    pub fn category(self) -> Category {
+        match self {
+            IpAddr::V4(a) => a.category(),
+            IpAddr::V6(a) => a.category(),
+        }
+    }
+
+    pub fn is_ipv4(self) -> bool {
+        matches!(self, IpAddr::V4(_))
+    }
+
+    // The address as one number, so both families can share the bit twiddling (IPv4 uses the low 32 bits)
+    pub fn to_bits(self) -> u128 {
+        match self {
+            IpAddr::V4(a) => a.to_bits() as u128,
+            IpAddr::V6(a) => a.to_bits(),
+        }
+    }
+
+    pub fn from_bits(v4: bool, bits: u128) -> Self {
+        if v4 {
+            IpAddr::V4(Ipv4Addr::from_bits(bits as u32))
+        } else {
+            IpAddr::V6(Ipv6Addr::from_bits(bits))
+        }
+    }
+}
+
+impl From<[u8; 4]> for Ipv4Addr {
+    fn from(octets: [u8; 4]) -> Self {
+        Ipv4Addr(octets)
+    }
+}
+
+impl From<Ipv4Addr> for IpAddr {
+    fn from(a: Ipv4Addr) -> Self {
+        IpAddr::V4(a)
+    }
+}
+
+impl From<Ipv6Addr> for IpAddr {
+    fn from(a: Ipv6Addr) -> Self {
+        IpAddr::V6(a)
+    }
+}
+
+impl FromStr for Ipv4Addr {
+    type Err = ParseAddrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_v4(s, 0)
+    }
+}
+
+impl FromStr for Ipv6Addr {
+    type Err = ParseAddrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_v6(s)
+    }
+}
+
+// Anything with a ':' is IPv6, everything else is IPv4
+impl FromStr for IpAddr {
+    type Err = ParseAddrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            parse_v6(s).map(IpAddr::V6)
+        } else {
+            parse_v4(s, 0).map(IpAddr::V4)
+        }
+    }
+}
+
+// The addresses are put together as a String first and handed to f.pad(), so width and alignment work like they
+// do for std's addresses: format!("{ip:<15}")
+
+impl fmt::Display for Ipv4Addr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.0;
+        f.pad(&format!("{a}.{b}.{c}.{d}"))
+    }
+}
+
+impl fmt::Display for Ipv6Addr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(v4) = self.to_ipv4_mapped() {
+            return f.pad(&format!("::ffff:{v4}"));
+        }
+        let groups = |groups: &[u16]| {
+            let text: Vec<String> = groups.iter().map(|g| format!("{g:x}")).collect();
+            text.join(":")
+        };
+        let text = match self.zero_run() {
+            Some((start, len)) => format!(
+                "{}::{}",
+                groups(&self.0[..start]),
+                groups(&self.0[start + len..])
+            ),
+            None => groups(&self.0),
+        };
+        f.pad(&text)
+    }
+}
+
+impl fmt::Display for IpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpAddr::V4(a) => a.fmt(f),
+            IpAddr::V6(a) => a.fmt(f),
+        }
+    }
+}
+
+pub fn start() {
+    for input in [
+        "127.0.0.1",
+        "192.168.1.20",
+        "100.72.0.1",
+        "255.255.255.255",
+        "::1",
+        "2001:0DB8:0000:0000:0001:0000:0000:0001",
+        "fe80::1:0:0:0",
+        "ff02::1",
+        "::ffff:10.0.0.1",
+        "2606:4700:4700::1111",
+    ] {
+        let ip: IpAddr = input.parse().unwrap();
+        println!("{input:>40} -> {ip:<24} {}", ip.category());
+    }
+
+    for input in [
+        "192.168.01.1",
+        "10.0.0.256",
+        "1.2.3",
+        "2001:db8::1::2",
+        "fe80::1%eth0",
+        "1:2:3:4:5:6:7:8:9",
+    ] {
+        let err = input.parse::<IpAddr>().unwrap_err();
+        println!("{}", err.render(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    // Random strings that look enough like addresses to hit the interesting cases
+    fn random_input(rng: &mut impl Rng) -> String {
+        const PIECES: [&str; 12] = [
+            "0", "1", "25", "255", "256", "01", "ff", "ffff", "12345", ":", "::", ".",
+        ];
+        (0..rng.gen_range(1..16))
+            .map(|_| PIECES[rng.gen_range(0..PIECES.len())])
+            .collect()
+    }
+
+    #[test]
+    fn formatting() {
+        let parse = |s: &str| s.parse::<IpAddr>().map(|ip| ip.to_string());
+        assert_eq!(
+            parse("2001:0DB8:0:0:1:0:0:1"),
+            Ok("2001:db8::1:0:0:1".to_string())
+        );
+        assert_eq!(
+            parse("2001:db8:0:1:1:1:1:1"),
+            Ok("2001:db8:0:1:1:1:1:1".to_string())
+        ); // one zero stays
+        assert_eq!(parse("0:0:0:0:0:0:0:0"), Ok("::".to_string()));
+        assert_eq!(parse("1::"), Ok("1::".to_string()));
+        assert_eq!(
+            parse("::ffff:c000:0201"),
+            Ok("::ffff:192.0.2.1".to_string())
+        );
+        assert_eq!(parse("::1.2.3.4"), Ok("::102:304".to_string()));
+        assert_eq!(parse("1:0:0:2:0:0:0:3"), Ok("1:0:0:2::3".to_string()));
+        assert_eq!(parse("1:0:0:2:0:0:3:4"), Ok("1::2:0:0:3:4".to_string()));
+    }
+
+    #[test]
+    fn padding() {
+        let v4: IpAddr = "10.0.0.1".parse().unwrap();
+        let v6: IpAddr = "2001:db8::1".parse().unwrap();
+        assert_eq!(format!("[{v4:<12}]"), "[10.0.0.1    ]");
+        assert_eq!(format!("[{v4:>12}]"), "[    10.0.0.1]");
+        assert_eq!(format!("[{v6:^15}]"), "[  2001:db8::1  ]");
+        assert_eq!(format!("[{:-<12}]", Category::Private), "[private-----]");
+        // the same as std's
+        let std_v6: std::net::IpAddr = "2001:db8::1".parse().unwrap();
+        assert_eq!(format!("{v6:>20}"), format!("{std_v6:>20}"));
+    }
+
+    #[test]
+    fn error_positions() {
+        let error_at = |s: &str| s.parse::<IpAddr>().unwrap_err().position();
+        assert_eq!(error_at("192.168.01.1"), 8);
+        assert_eq!(error_at("1.2.3.x"), 6);
+        assert_eq!(error_at("1.2.3.4.5"), 7);
+        assert_eq!(error_at("1..2.3"), 2);
+        assert_eq!(error_at("2001:db8::1::2"), 11);
+        assert_eq!(error_at(":1::"), 0);
+        assert_eq!(error_at("1::g"), 3);
+        assert_eq!(error_at("1.2.3.4::"), 0);
+        assert_eq!(error_at("::1.2.3.4:1"), 2);
+    }
+
+    #[test]
+    fn categories() {
+        let v4 = |s: &str| s.parse::<Ipv4Addr>().unwrap().category();
+        assert_eq!(v4("0.0.0.0"), Category::Unspecified);
+        assert_eq!(v4("0.1.2.3"), Category::Reserved);
+        assert_eq!(v4("172.31.255.255"), Category::Private);
+        assert_eq!(v4("172.32.0.0"), Category::Global);
+        assert_eq!(v4("169.254.1.1"), Category::LinkLocal);
+        assert_eq!(v4("239.1.1.1"), Category::Multicast);
+        assert_eq!(v4("203.0.113.9"), Category::Documentation);
+        assert_eq!(v4("198.19.0.1"), Category::Benchmarking);
+        assert_eq!(v4("250.0.0.1"), Category::Reserved);
+        let v6 = |s: &str| s.parse::<Ipv6Addr>().unwrap().category();
+        assert_eq!(v6("::"), Category::Unspecified);
+        assert_eq!(v6("fd12:3456::1"), Category::UniqueLocal);
+        assert_eq!(v6("febf::1"), Category::LinkLocal);
+        assert_eq!(v6("fec0::1"), Category::Global);
+        assert_eq!(v6("2001:db8::"), Category::Documentation);
+        assert_eq!(v6("::ffff:127.0.0.1"), Category::Loopback);
+    }
+
+    // Random addresses format like std's, and parse back to themselves
+    #[test]
+    fn random_addresses_round_trip() {
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let mut groups: [u16; 8] = rng.r#gen();
+            // lots of zeros, or "::" would hardly ever show up
+            for g in groups.iter_mut() {
+                if rng.gen_bool(0.5) {
+                    *g = 0;
+                }
+            }
+            let ours = Ipv6Addr(groups);
+            let std_addr = std::net::Ipv6Addr::from(groups);
+            assert_eq!(ours.to_string(), std_addr.to_string());
+            assert_eq!(ours.to_string().parse(), Ok(ours));
+            assert_eq!(Ipv6Addr::from_bits(ours.to_bits()), ours);
+
+            let v4 = Ipv4Addr(rng.r#gen());
+            assert_eq!(v4.to_string().parse(), Ok(v4));
+            assert_eq!(v4.to_mapped().to_ipv4_mapped(), Some(v4));
+            assert_eq!(Ipv4Addr::from_bits(v4.to_bits()), v4);
+        }
+    }
+
+    // Random junk: we accept exactly what std accepts, and get the same address
+    #[test]
+    fn accepts_what_std_accepts() {
+        let mut rng = thread_rng();
+        for _ in 0..100_000 {
+            let input = random_input(&mut rng);
+            let ours = input.parse::<IpAddr>().ok();
+            let std_ip = input.parse::<std::net::IpAddr>().ok();
+            let std_ip = std_ip.map(|ip| match ip {
+                std::net::IpAddr::V4(a) => IpAddr::V4(Ipv4Addr(a.octets())),
+                std::net::IpAddr::V6(a) => IpAddr::V6(Ipv6Addr(a.segments())),
+            });
+            assert_eq!(ours, std_ip, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,615 @@
+#![allow(dead_code)]
+use super::addr::{IpAddr, ParseAddrError};
+use std::fmt;
+use std::str::FromStr;
+
+// A block of addresses written as "network/prefix length", like 192.168.1.0/24 or fe80::/10.
+//
+// Both families are handled by the same code: an address is turned into a u128 holding its bits (IPv4 only uses
+// the low 32) and everything is done on those numbers with a `width` of 32 or 128.
+//
+// "192.168.1.5/24" isn't a network since the host part isn't zero, so parsing it as a Cidr is an error. It's still
+// a useful thing to type though (it's how interfaces are configured), so `parse_host` takes it apart into the
+// address and the network it's in.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cidr {
+    network: IpAddr,
+    len: u8,
+}
+
+fn width(v4: bool) -> u32 {
+    if v4 { 32 } else { 128 }
+}
+
+// `n` ones in the low bits
+fn ones(n: u32) -> u128 {
+    if n >= 128 { u128::MAX } else { (1 << n) - 1 }
+}
+
+// len() is the prefix length, not a size, so there's no is_empty() to go with it
+#[allow(clippy::len_without_is_empty)]
+impl Cidr {
+    // Fails if `len` is too long for the family or `network` has bits set past the prefix
+    pub fn new(network: IpAddr, len: u8) -> Option<Self> {
+        let cidr = Self::containing(network, len)?;
+        (cidr.network == network).then_some(cidr)
+    }
+
+    // The block of length `len` that `addr` is in
+    pub fn containing(addr: IpAddr, len: u8) -> Option<Self> {
+        let v4 = addr.is_ipv4();
+        if len as u32 > width(v4) {
+            return None;
+        }
+        let mut cidr = Self { network: addr, len };
+        cidr.network = IpAddr::from_bits(v4, addr.to_bits() & cidr.mask_bits());
+        Some(cidr)
+    }
+
+    // "192.168.1.5/24" -> (192.168.1.5, 192.168.1.0/24)
+    pub fn parse_host(s: &str) -> Result<(IpAddr, Cidr), ParseAddrError> {
+        let (addr, len) = parse_parts(s)?;
+        Ok((addr, Self::containing(addr, len).unwrap()))
+    }
+
+    pub fn network(&self) -> IpAddr {
+        self.network
+    }
+
+    pub fn len(&self) -> u8 {
+        self.len
+    }
+
+    pub fn is_ipv4(&self) -> bool {
+        self.network.is_ipv4()
+    }
+
+    fn width(&self) -> u32 {
+        width(self.is_ipv4())
+    }
+
+    fn host_bits(&self) -> u32 {
+        self.width() - self.len as u32
+    }
+
+    fn mask_bits(&self) -> u128 {
+        ones(self.width()) & !ones(self.host_bits())
+    }
+
+    fn first_bits(&self) -> u128 {
+        self.network.to_bits()
+    }
+
+    fn last_bits(&self) -> u128 {
+        self.first_bits() | ones(self.host_bits())
+    }
+
+    pub fn netmask(&self) -> IpAddr {
+        IpAddr::from_bits(self.is_ipv4(), self.mask_bits())
+    }
+
+    // The inverse of the netmask, as used in ACLs
+    pub fn wildcard(&self) -> IpAddr {
+        IpAddr::from_bits(self.is_ipv4(), ones(self.host_bits()))
+    }
+
+    // The last address of the block
+    pub fn last(&self) -> IpAddr {
+        IpAddr::from_bits(self.is_ipv4(), self.last_bits())
+    }
+
+    // IPv6 has no broadcast address, and a /31 or /32 doesn't have one either (RFC 3021)
+    pub fn broadcast(&self) -> Option<IpAddr> {
+        (self.is_ipv4() && self.len < 31).then(|| self.last())
+    }
+
+    // The addresses a host can use. On IPv4 that leaves out the network and broadcast addresses, except in a /31
+    // or /32 which don't have them; IPv6 doesn't reserve either, so every address counts.
+    pub fn hosts(&self) -> (IpAddr, IpAddr) {
+        if self.broadcast().is_some() {
+            let v4 = self.is_ipv4();
+            (
+                IpAddr::from_bits(v4, self.first_bits() + 1),
+                IpAddr::from_bits(v4, self.last_bits() - 1),
+            )
+        } else {
+            (self.network, self.last())
+        }
+    }
+
+    // ::/0 has 2^128 addresses, one more than a u128 holds, so that one saturates
+    pub fn address_count(&self) -> u128 {
+        ones(self.host_bits()).saturating_add(1)
+    }
+
+    pub fn host_count(&self) -> u128 {
+        match self.broadcast() {
+            Some(_) => self.address_count() - 2,
+            None => self.address_count(),
+        }
+    }
+
+    pub fn contains_addr(&self, addr: IpAddr) -> bool {
+        addr.is_ipv4() == self.is_ipv4() && addr.to_bits() & self.mask_bits() == self.first_bits()
+    }
+
+    // Whether `other` is this block or a smaller block inside it
+    pub fn contains(&self, other: &Cidr) -> bool {
+        other.len >= self.len && self.contains_addr(other.network)
+    }
+
+    // Two blocks either nest or don't touch at all
+    pub fn overlaps(&self, other: &Cidr) -> bool {
+        self.contains(other) || other.contains(self)
+    }
+
+    // The block one bit shorter that this one is half of
+    pub fn parent(&self) -> Option<Cidr> {
+        let len = self.len.checked_sub(1)?;
+        Self::containing(self.network, len)
+    }
+
+    // The blocks of length `new_len` this one splits into, in order. None if `new_len` is shorter than this one
+    // or too long for the family.
+    pub fn subnets(&self, new_len: u8) -> Option<Subnets> {
+        if new_len < self.len || new_len as u32 > self.width() {
+            return None;
+        }
+        Some(Subnets {
+            v4: self.is_ipv4(),
+            len: new_len,
+            next: Some(self.first_bits()),
+            last: self.last_bits(),
+            step_bits: self.width() - new_len as u32,
+        })
+    }
+}
+
+pub struct Subnets {
+    v4: bool,
+    len: u8,
+    next: Option<u128>,
+    last: u128,
+    step_bits: u32,
+}
+
+impl Iterator for Subnets {
+    type Item = Cidr;
+
+    fn next(&mut self) -> Option<Cidr> {
+        let start = self.next?;
+        let end = start | ones(self.step_bits);
+        self.next = if end >= self.last {
+            None
+        } else {
+            Some(end + 1)
+        };
+        Some(Cidr {
+            network: IpAddr::from_bits(self.v4, start),
+            len: self.len,
+        })
+    }
+}
+
+// The fewest blocks that cover exactly the addresses first..=last
+pub fn range_to_cidrs(first: IpAddr, last: IpAddr) -> Vec<Cidr> {
+    let v4 = first.is_ipv4();
+    if v4 != last.is_ipv4() || first > last {
+        return Vec::new();
+    }
+    let width = width(v4);
+    let (mut start, end) = (first.to_bits(), last.to_bits());
+    let mut out = Vec::new();
+    loop {
+        // as big as the alignment of `start` allows, without going past `end`
+        let aligned = start.trailing_zeros().min(width);
+        let remaining = end - start;
+        let fits = if remaining == u128::MAX {
+            128
+        } else {
+            127 - (remaining + 1).leading_zeros()
+        };
+        let size = aligned.min(fits);
+        out.push(Cidr {
+            network: IpAddr::from_bits(v4, start),
+            len: (width - size) as u8,
+        });
+        match (start | ones(size)).checked_add(1) {
+            Some(next) if next <= end => start = next,
+            _ => return out,
+        }
+    }
+}
+
+// The smallest list of blocks covering exactly the same addresses as `cidrs`: duplicates and blocks inside others
+// go away, and neighbours that make up a bigger block are merged into it. IPv4 comes first, then IPv6.
+pub fn aggregate(cidrs: &[Cidr]) -> Vec<Cidr> {
+    let mut out = Vec::new();
+    for v4 in [true, false] {
+        let mut ranges: Vec<(u128, u128)> = cidrs
+            .iter()
+            .filter(|c| c.is_ipv4() == v4)
+            .map(|c| (c.first_bits(), c.last_bits()))
+            .collect();
+        ranges.sort();
+        let mut merged: Vec<(u128, u128)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                // overlapping or right next to each other
+                Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        for (start, end) in merged {
+            out.extend(range_to_cidrs(
+                IpAddr::from_bits(v4, start),
+                IpAddr::from_bits(v4, end),
+            ));
+        }
+    }
+    out
+}
+
+// "address/len" without checking the host bits, with errors pointing into `s`
+fn parse_parts(s: &str) -> Result<(IpAddr, u8), ParseAddrError> {
+    let Some(slash) = s.find('/') else {
+        return Err(ParseAddrError::new(
+            "expected a prefix length like /24",
+            s.len()..s.len(),
+        ));
+    };
+    let addr: IpAddr = s[..slash].parse()?;
+    let len_text = &s[slash + 1..];
+    let span = slash + 1..s.len();
+    let max = width(addr.is_ipv4());
+    if len_text.is_empty() {
+        return Err(ParseAddrError::new(
+            "the prefix length is missing",
+            slash..slash + 1,
+        ));
+    }
+    if !len_text.chars().all(|c| c.is_ascii_digit())
+        || (len_text.len() > 1 && len_text.starts_with('0'))
+    {
+        return Err(ParseAddrError::new(
+            &format!("the prefix length has to be a number from 0 to {max}"),
+            span,
+        ));
+    }
+    match len_text.parse::<u32>() {
+        Ok(len) if len <= max => Ok((addr, len as u8)),
+        _ => Err(ParseAddrError::new(
+            &format!("the prefix length has to be a number from 0 to {max}"),
+            span,
+        )),
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = ParseAddrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, len) = parse_parts(s)?;
+        let cidr = Cidr::containing(addr, len).unwrap();
+        if cidr.network != addr {
+            let slash = s.find('/').unwrap();
+            return Err(ParseAddrError::new(
+                &format!("host bits are set, the network is {cidr}"),
+                0..slash,
+            ));
+        }
+        Ok(cidr)
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.network, self.len)
+    }
+}
+
+// Counts too big to read are shown as powers of two
+fn count(n: u128, host_bits: u32) -> String {
+    if host_bits > 48 {
+        format!("2^{host_bits}")
+    } else {
+        n.to_string()
+    }
+}
+
+// What `cidr <block>` prints
+pub fn describe(input: &str) -> Result<String, ParseAddrError> {
+    let (addr, cidr) = Cidr::parse_host(input)?;
+    let mut out = String::new();
+    if addr != cidr.network() {
+        out.push_str(&format!("Address:   {addr} ({})\n", addr.category()));
+    }
+    out.push_str(&format!(
+        "Network:   {cidr} ({})\n",
+        cidr.network().category()
+    ));
+    out.push_str(&format!("Netmask:   {}\n", cidr.netmask()));
+    out.push_str(&format!("Wildcard:  {}\n", cidr.wildcard()));
+    if let Some(broadcast) = cidr.broadcast() {
+        out.push_str(&format!("Broadcast: {broadcast}\n"));
+    }
+    let (first, last) = cidr.hosts();
+    out.push_str(&format!("Hosts:     {first} - {last}\n"));
+    out.push_str(&format!(
+        "Count:     {} hosts, {} addresses\n",
+        count(cidr.host_count(), cidr.host_bits()),
+        count(cidr.address_count(), cidr.host_bits())
+    ));
+    Ok(out)
+}
+
+// One command line of the calculator:
+//   <block>                      everything about a block (host bits may be set: 192.168.1.5/24)
+//   split <block> <len>          the subnets of length <len>
+//   contains <block> <addr|block>
+//   aggregate <block>...         the smallest list of blocks covering the same addresses
+//   range <first> <last>         the blocks covering a range of addresses
+pub fn run(args: &[&str]) -> Result<String, String> {
+    let cidr = |s: &str| s.parse::<Cidr>().map_err(|e| e.render(s));
+    let addr = |s: &str| s.parse::<IpAddr>().map_err(|e| e.render(s));
+    let lines = |cidrs: Vec<Cidr>| cidrs.iter().map(|c| format!("{c}\n")).collect::<String>();
+    match args {
+        ["split", block, len] => {
+            let block = cidr(block)?;
+            let new_len: u8 = len
+                .parse()
+                .map_err(|_| format!("`{len}` isn't a prefix length"))?;
+            let subnets = block
+                .subnets(new_len)
+                .ok_or_else(|| format!("/{new_len} doesn't fit inside {block}"))?;
+            const LIMIT: usize = 256;
+            let mut out = lines(subnets.take(LIMIT).collect());
+            let total = 1u128.checked_shl((new_len - block.len()) as u32);
+            if total.is_none_or(|total| total > LIMIT as u128) {
+                out.push_str(&format!(
+                    "... 2^{} subnets in total\n",
+                    new_len - block.len()
+                ));
+            }
+            Ok(out)
+        }
+        ["contains", block, item] => {
+            let block = cidr(block)?;
+            let inside = if item.contains('/') {
+                block.contains(&cidr(item)?)
+            } else {
+                block.contains_addr(addr(item)?)
+            };
+            let verb = if inside {
+                "contains"
+            } else {
+                "doesn't contain"
+            };
+            Ok(format!("{block} {verb} {item}\n"))
+        }
+        ["aggregate", blocks @ ..] if !blocks.is_empty() => {
+            let blocks: Vec<Cidr> = blocks.iter().map(|b| cidr(b)).collect::<Result<_, _>>()?;
+            Ok(lines(aggregate(&blocks)))
+        }
+        ["range", first, last] => {
+            let (first, last) = (addr(first)?, addr(last)?);
+            if first.is_ipv4() != last.is_ipv4() || first > last {
+                return Err(format!("{first} - {last} isn't a range"));
+            }
+            Ok(lines(range_to_cidrs(first, last)))
+        }
+        [block] => describe(block).map_err(|e| e.render(block)),
+        _ => Err(
+            "usage: <block> | split <block> <len> | contains <block> <addr|block> | \
+                  aggregate <block>... | range <first> <last>"
+                .to_string(),
+        ),
+    }
+}
+
+// The calculator: runs the command given on the command line, or else reads commands from stdin until EOF
+pub fn cidr_command() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let print = |result: Result<String, String>| match result {
+        Ok(out) => print!("{out}"),
+        Err(e) => println!("{e}"),
+    };
+    if !args.is_empty() {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        return print(run(&args));
+    }
+    println!(
+        "Type a block like 192.168.1.0/24, or split/contains/aggregate/range (Ctrl-D to quit):"
+    );
+    for line in std::io::stdin().lines() {
+        let line = line.expect("Failed to read the line");
+        let args: Vec<&str> = line.split_whitespace().collect();
+        if !args.is_empty() {
+            print(run(&args));
+        }
+    }
+}
+
+pub fn start() {
+    for input in ["192.168.1.5/24", "10.0.0.0/31", "fe80::/10"] {
+        println!("$ cidr {input}\n{}", describe(input).unwrap());
+    }
+    for args in [
+        &["split", "10.0.0.0/22", "24"][..],
+        &["contains", "fe80::/10", "fe80::1:2"],
+        &[
+            "aggregate",
+            "10.0.0.0/24",
+            "10.0.1.0/24",
+            "10.0.2.0/23",
+            "10.0.2.128/25",
+            "192.168.0.0/24",
+        ],
+        &["range", "192.168.0.10", "192.168.0.20"],
+        &["10.0.0.0/33"],
+    ] {
+        match run(args) {
+            Ok(out) => print!("$ cidr {}\n{out}", args.join(" ")),
+            Err(e) => println!("$ cidr {}\n{e}", args.join(" ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net::addr::{Ipv4Addr, Ipv6Addr};
+    use rand::{Rng, thread_rng};
+
+    fn random_cidr(rng: &mut impl Rng, v4: bool) -> Cidr {
+        let addr = if v4 {
+            IpAddr::V4(Ipv4Addr(rng.r#gen()))
+        } else {
+            // a narrow slice of the space, so blocks overlap and touch often enough to merge
+            IpAddr::V6(Ipv6Addr::from_bits(
+                0x2001_0db8 << 96 | rng.gen_range(0..1u128 << 12) << 84,
+            ))
+        };
+        let len = if v4 {
+            rng.gen_range(0..=32)
+        } else {
+            rng.gen_range(20..=128)
+        };
+        Cidr::containing(addr, len).unwrap()
+    }
+
+    fn c(s: &str) -> Cidr {
+        s.parse().unwrap()
+    }
+
+    fn a(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn block_addresses() {
+        let net = c("192.168.1.0/24");
+        assert_eq!(net.netmask(), a("255.255.255.0"));
+        assert_eq!(net.wildcard(), a("0.0.0.255"));
+        assert_eq!(net.broadcast(), Some(a("192.168.1.255")));
+        assert_eq!(net.hosts(), (a("192.168.1.1"), a("192.168.1.254")));
+        assert_eq!(net.host_count(), 254);
+        assert_eq!(c("10.0.0.0/31").hosts(), (a("10.0.0.0"), a("10.0.0.1")));
+        assert_eq!(c("10.0.0.0/31").host_count(), 2);
+        assert_eq!(c("10.0.0.7/32").host_count(), 1);
+        assert_eq!(c("0.0.0.0/0").address_count(), 1 << 32);
+        assert_eq!(c("::/0").address_count(), u128::MAX);
+        assert_eq!(c("fe80::/10").netmask(), a("ffc0::"));
+        assert_eq!(
+            c("fe80::/10").last(),
+            a("febf:ffff:ffff:ffff:ffff:ffff:ffff:ffff")
+        );
+        assert_eq!(c("fe80::/10").broadcast(), None);
+    }
+
+    #[test]
+    fn containment_and_subnets() {
+        assert!(c("10.0.0.0/8").contains(&c("10.20.0.0/16")));
+        assert!(!c("10.20.0.0/16").contains(&c("10.0.0.0/8")));
+        assert!(c("10.0.0.0/8").overlaps(&c("10.20.0.0/16")));
+        assert!(!c("10.0.0.0/8").contains_addr(a("::ffff:10.0.0.1")));
+        assert_eq!(c("10.0.1.0/24").parent(), Some(c("10.0.0.0/23")));
+
+        let subnets: Vec<Cidr> = c("10.0.0.0/22").subnets(24).unwrap().collect();
+        assert_eq!(
+            subnets,
+            [
+                c("10.0.0.0/24"),
+                c("10.0.1.0/24"),
+                c("10.0.2.0/24"),
+                c("10.0.3.0/24")
+            ]
+        );
+        assert_eq!(c("255.255.255.0/24").subnets(26).unwrap().count(), 4);
+        assert!(c("10.0.0.0/24").subnets(23).is_none());
+        assert_eq!(c("::/0").subnets(0).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn aggregating_and_ranges() {
+        assert_eq!(
+            aggregate(&[
+                c("10.0.0.0/24"),
+                c("10.0.1.0/24"),
+                c("10.0.2.0/23"),
+                c("10.0.2.128/25")
+            ]),
+            [c("10.0.0.0/22")]
+        );
+        assert_eq!(
+            aggregate(&[c("::/1"), c("8000::/1"), c("0.0.0.0/0")]),
+            [c("0.0.0.0/0"), c("::/0")]
+        );
+        assert_eq!(
+            range_to_cidrs(a("192.168.0.10"), a("192.168.0.20")),
+            [
+                c("192.168.0.10/31"),
+                c("192.168.0.12/30"),
+                c("192.168.0.16/30"),
+                c("192.168.0.20/32")
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error_at = |s: &str| s.parse::<Cidr>().unwrap_err().position();
+        assert_eq!(error_at("10.0.0.1/24"), 0); // host bits
+        assert_eq!(error_at("10.0.0.0/33"), 9);
+        assert_eq!(error_at("10.0.0.0"), 8);
+        assert_eq!(error_at("10.0.0.0/"), 8);
+        assert_eq!(error_at("10.0.0.0/08"), 9);
+        assert_eq!(error_at("10.0.0/8"), 6);
+        assert_eq!(
+            Cidr::parse_host("10.1.2.3/8"),
+            Ok((a("10.1.2.3"), c("10.0.0.0/8")))
+        );
+    }
+
+    // Random lists: aggregating keeps exactly the same addresses, with no block inside or next to a mergeable
+    // neighbour, and never more blocks than it started with
+    #[test]
+    fn random_aggregates() {
+        let mut rng = thread_rng();
+        for round in 0..2_000 {
+            let v4 = round % 2 == 0;
+            let blocks: Vec<Cidr> = (0..rng.gen_range(1..12))
+                .map(|_| random_cidr(&mut rng, v4))
+                .collect();
+            let merged = aggregate(&blocks);
+            assert!(merged.len() <= blocks.len());
+            for probe in blocks.iter().chain(&merged) {
+                for addr in [probe.network(), probe.last()] {
+                    let before = blocks.iter().any(|b| b.contains_addr(addr));
+                    let after = merged.iter().any(|b| b.contains_addr(addr));
+                    assert_eq!(before, after, "{addr} in {blocks:?} vs {merged:?}");
+                }
+            }
+            for pair in merged.windows(2) {
+                assert!(
+                    pair[0].last().to_bits() + 1 < pair[1].network().to_bits()
+                        || pair[0].parent() != pair[1].parent()
+                );
+                assert!(!pair[0].overlaps(&pair[1]));
+            }
+            assert_eq!(aggregate(&merged), merged);
+
+            // splitting and aggregating gets back to the start
+            let block = blocks[0];
+            let extra = rng.gen_range(0..=6u8).min(width(v4) as u8 - block.len());
+            let parts: Vec<Cidr> = block.subnets(block.len() + extra).unwrap().collect();
+            assert_eq!(parts.len(), 1 << extra);
+            assert_eq!(aggregate(&parts), [block]);
+            assert_eq!(range_to_cidrs(block.network(), block.last()), [block]);
+            assert_eq!(block.to_string().parse(), Ok(block));
+        }
+    }
+}
@@ -0,0 +1,6 @@
+// Networking that grew out of the IpAddr enums and the route() stub in chap_13_enums_and_pattern_matching.
+pub mod addr;
+pub mod cidr;
+pub mod pcap;
+pub mod pool;
+pub mod route;
@@ -0,0 +1,853 @@
+#![allow(dead_code)]
+use super::addr::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+
+// Reads classic pcap captures (what `tcpdump -w` writes, not the newer pcapng) and counts the IPv4/IPv6 flows in
+// them.
+//
+// A pcap file is a 24 byte header followed by records, each a 16 byte header and the captured bytes of one frame.
+// The magic number at the start says which byte order the rest of the file uses, and whether the timestamps are in
+// micro- or nanoseconds. Only Ethernet captures (link type 1) are supported.
+//
+// Frames are decoded as Ethernet (with an optional 802.1Q VLAN tag), then IPv4 or IPv6 (skipping IPv6 extension
+// headers), then TCP or UDP. A capture may keep only the start of each frame (the snap length), which is fine as
+// long as the headers are there: counts use the frame's original length.
+//
+// The fixtures in assets/pcap are written by scripts/pcap_fixtures.py, which computes the checksums itself:
+//   sample_le.pcap        12 frames: TCP and UDP over IPv4 and IPv6, a VLAN tag, an IPv6 hop-by-hop header,
+//                         one IPv4 header with a broken checksum and one ARP frame
+//   sample_be_nanos.pcap  the same frames, big-endian with nanosecond timestamps
+//   snaplen_64.pcap       the first 4 frames cut to 64 bytes
+//   truncated.pcap        3 frames with the file cut off in the middle of the last one
+
+const ETHERNET: u32 = 1;
+const TCP: u8 = 6;
+const UDP: u8 = 17;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PcapError {
+    Io(String),
+    // shorter than the 24 byte file header
+    TooShort,
+    BadMagic(u32),
+    UnsupportedLinkType(u32),
+    // the file ends in the middle of a record
+    Truncated {
+        record: usize,
+        offset: usize,
+    },
+    // a record claims to be longer than the snap length allows
+    BadRecordLength {
+        record: usize,
+        offset: usize,
+        len: u32,
+    },
+    // the fraction of a second is a whole second or more
+    BadTimestamp {
+        record: usize,
+        offset: usize,
+        frac: u32,
+    },
+}
+
+impl fmt::Display for PcapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PcapError::Io(e) => write!(f, "{e}"),
+            PcapError::TooShort => write!(f, "too short to be a pcap file"),
+            PcapError::BadMagic(magic) => {
+                write!(f, "not a pcap file (magic number {magic:#010x})")
+            }
+            PcapError::UnsupportedLinkType(link) => {
+                write!(f, "link type {link} isn't supported, only Ethernet (1) is")
+            }
+            PcapError::Truncated { record, offset } => {
+                write!(
+                    f,
+                    "the file ends in the middle of record {record} (at byte {offset})"
+                )
+            }
+            PcapError::BadRecordLength {
+                record,
+                offset,
+                len,
+            } => write!(
+                f,
+                "record {record} at byte {offset} claims {len} bytes, more than the snap length"
+            ),
+            PcapError::BadTimestamp {
+                record,
+                offset,
+                frac,
+            } => write!(
+                f,
+                "record {record} at byte {offset} has {frac} as the fraction of a second"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PcapError {}
+
+// Why a frame couldn't be decoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    pub layer: &'static str,
+    pub message: String,
+}
+
+impl DecodeError {
+    fn new(layer: &'static str, message: &str) -> Self {
+        Self {
+            layer,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.layer, self.message)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+// Network byte order (big-endian) reads that return None past the end instead of panicking
+fn be16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        bytes.get(at..at + 2)?.try_into().unwrap(),
+    ))
+}
+
+fn be32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        bytes.get(at..at + 4)?.try_into().unwrap(),
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcapHeader {
+    pub big_endian: bool,
+    pub nanos: bool,
+    pub version: (u16, u16),
+    pub snaplen: u32,
+    pub link_type: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub ts_sec: u32,
+    // always nanoseconds, microsecond files are scaled up
+    pub ts_nanos: u32,
+    // the length of the frame on the wire, data can be shorter
+    pub orig_len: u32,
+    pub data: &'a [u8],
+}
+
+// Goes through the records of a capture held in memory. After an error it stops.
+pub struct Reader<'a> {
+    pub header: PcapHeader,
+    bytes: &'a [u8],
+    offset: usize,
+    record: usize,
+    failed: bool,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Result<Self, PcapError> {
+        if bytes.len() < 24 {
+            return Err(PcapError::TooShort);
+        }
+        let magic = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let (big_endian, nanos) = match magic {
+            0xa1b2c3d4 => (false, false),
+            0xa1b23c4d => (false, true),
+            0xd4c3b2a1 => (true, false),
+            0x4d3cb2a1 => (true, true),
+            _ => return Err(PcapError::BadMagic(magic)),
+        };
+        let mut reader = Self {
+            header: PcapHeader {
+                big_endian,
+                nanos,
+                version: (0, 0),
+                snaplen: 0,
+                link_type: 0,
+            },
+            bytes,
+            offset: 24,
+            record: 0,
+            failed: false,
+        };
+        reader.header.version = (reader.u16_at(4), reader.u16_at(6));
+        reader.header.snaplen = reader.u32_at(16);
+        reader.header.link_type = reader.u32_at(20);
+        if reader.header.link_type != ETHERNET {
+            return Err(PcapError::UnsupportedLinkType(reader.header.link_type));
+        }
+        Ok(reader)
+    }
+
+    // Reads in the file's own byte order
+    fn u16_at(&self, at: usize) -> u16 {
+        let b: [u8; 2] = self.bytes[at..at + 2].try_into().unwrap();
+        if self.header.big_endian {
+            u16::from_be_bytes(b)
+        } else {
+            u16::from_le_bytes(b)
+        }
+    }
+
+    fn u32_at(&self, at: usize) -> u32 {
+        let b: [u8; 4] = self.bytes[at..at + 4].try_into().unwrap();
+        if self.header.big_endian {
+            u32::from_be_bytes(b)
+        } else {
+            u32::from_le_bytes(b)
+        }
+    }
+}
+
+impl<'a> Iterator for Reader<'a> {
+    type Item = Result<Record<'a>, PcapError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.offset == self.bytes.len() {
+            return None;
+        }
+        let at = self.offset;
+        self.record += 1;
+        let truncated = PcapError::Truncated {
+            record: self.record,
+            offset: at,
+        };
+        self.failed = true; // until this record turns out fine
+        if self.bytes.len() - at < 16 {
+            return Some(Err(truncated));
+        }
+        let incl_len = self.u32_at(at + 8);
+        // some writers leave the snap length at 0 or set it lower than what they keep, so anything up to 64k passes;
+        // past that it's more likely a corrupt length than a real frame
+        if incl_len > self.header.snaplen.max(65535) {
+            return Some(Err(PcapError::BadRecordLength {
+                record: self.record,
+                offset: at,
+                len: incl_len,
+            }));
+        }
+        let Some(data) = self.bytes.get(at + 16..at + 16 + incl_len as usize) else {
+            return Some(Err(truncated));
+        };
+        let frac = self.u32_at(at + 4);
+        let per_second = if self.header.nanos {
+            1_000_000_000
+        } else {
+            1_000_000
+        };
+        if frac >= per_second {
+            return Some(Err(PcapError::BadTimestamp {
+                record: self.record,
+                offset: at,
+                frac,
+            }));
+        }
+        let record = Record {
+            ts_sec: self.u32_at(at),
+            ts_nanos: if self.header.nanos { frac } else { frac * 1000 },
+            orig_len: self.u32_at(at + 12),
+            data,
+        };
+        self.offset = at + 16 + incl_len as usize;
+        self.failed = false;
+        Some(Ok(record))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ethernet {
+    pub dst: [u8; 6],
+    pub src: [u8; 6],
+    pub vlan: Option<u16>,
+    pub ethertype: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv4Header {
+    pub src: Ipv4Addr,
+    pub dst: Ipv4Addr,
+    pub protocol: u8,
+    pub ttl: u8,
+    pub header_len: usize,
+    pub total_len: u16,
+    pub checksum: u16,
+    pub checksum_ok: bool,
+    // in bytes; only the first fragment has the TCP/UDP header
+    pub fragment_offset: u16,
+    pub more_fragments: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv6Header {
+    pub src: Ipv6Addr,
+    pub dst: Ipv6Addr,
+    pub hop_limit: u8,
+    pub payload_len: u16,
+    // the protocol after any extension headers
+    pub protocol: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Ipv4(Ipv4Header),
+    Ipv6(Ipv6Header),
+    // ARP and everything else that isn't IP, by ethertype
+    Other(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TcpHeader {
+    pub src_port: u16,
+    pub dst_port: u16,
+    pub seq: u32,
+    pub ack: u32,
+    pub flags: u8,
+    pub header_len: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UdpHeader {
+    pub src_port: u16,
+    pub dst_port: u16,
+    pub len: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Tcp(TcpHeader),
+    Udp(UdpHeader),
+    // some other protocol number (ICMP is 1)
+    Other(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet {
+    pub ethernet: Ethernet,
+    pub network: Network,
+    // None for frames that aren't IP, and for IPv4 fragments after the first
+    pub transport: Option<Transport>,
+}
+
+// The ones' complement sum used by the IPv4 header checksum. Summing a header including its checksum field gives
+// 0xffff when the checksum is right.
+pub fn internet_checksum_sum(bytes: &[u8]) -> u16 {
+    let mut sum: u32 = bytes
+        .chunks(2)
+        .map(|pair| u16::from_be_bytes([pair[0], *pair.get(1).unwrap_or(&0)]) as u32)
+        .sum();
+    while sum > 0xffff {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    sum as u16
+}
+
+fn decode_ethernet(frame: &[u8]) -> Result<(Ethernet, &[u8]), DecodeError> {
+    let short = || DecodeError::new("ethernet", "frame is shorter than the header");
+    let mut ethernet = Ethernet {
+        dst: frame.get(0..6).ok_or_else(short)?.try_into().unwrap(),
+        src: frame.get(6..12).ok_or_else(short)?.try_into().unwrap(),
+        vlan: None,
+        ethertype: be16(frame, 12).ok_or_else(short)?,
+    };
+    let mut rest = 14;
+    if ethernet.ethertype == 0x8100 {
+        ethernet.vlan = Some(be16(frame, 14).ok_or_else(short)? & 0x0fff);
+        ethernet.ethertype = be16(frame, 16).ok_or_else(short)?;
+        rest = 18;
+    }
+    Ok((ethernet, &frame[rest..]))
+}
+
+fn decode_ipv4(bytes: &[u8]) -> Result<(Ipv4Header, &[u8]), DecodeError> {
+    let short = || DecodeError::new("ipv4", "packet is shorter than the header");
+    let first = *bytes.first().ok_or_else(short)?;
+    if first >> 4 != 4 {
+        return Err(DecodeError::new(
+            "ipv4",
+            &format!("version is {}, not 4", first >> 4),
+        ));
+    }
+    let header_len = (first & 0x0f) as usize * 4;
+    if header_len < 20 {
+        return Err(DecodeError::new(
+            "ipv4",
+            &format!("header length {header_len} is under 20"),
+        ));
+    }
+    let header = bytes.get(..header_len).ok_or_else(short)?;
+    let flags_fragment = be16(header, 6).unwrap();
+    let ip = Ipv4Header {
+        src: Ipv4Addr(header[12..16].try_into().unwrap()),
+        dst: Ipv4Addr(header[16..20].try_into().unwrap()),
+        protocol: header[9],
+        ttl: header[8],
+        header_len,
+        total_len: be16(header, 2).unwrap(),
+        checksum: be16(header, 10).unwrap(),
+        checksum_ok: internet_checksum_sum(header) == 0xffff,
+        fragment_offset: (flags_fragment & 0x1fff) * 8,
+        more_fragments: flags_fragment & 0x2000 != 0,
+    };
+    if (ip.total_len as usize) < header_len {
+        return Err(DecodeError::new(
+            "ipv4",
+            "total length is shorter than the header",
+        ));
+    }
+    // anything after total_len is Ethernet padding; the capture may also have cut the packet short
+    let end = (ip.total_len as usize).min(bytes.len());
+    Ok((ip, &bytes[header_len..end]))
+}
+
+fn decode_ipv6(bytes: &[u8]) -> Result<(Ipv6Header, &[u8]), DecodeError> {
+    let short = || DecodeError::new("ipv6", "packet is shorter than the header");
+    let header = bytes.get(..40).ok_or_else(short)?;
+    if header[0] >> 4 != 6 {
+        return Err(DecodeError::new(
+            "ipv6",
+            &format!("version is {}, not 6", header[0] >> 4),
+        ));
+    }
+    let mut groups = [0u16; 8];
+    let mut read = |at: usize| {
+        for (i, g) in groups.iter_mut().enumerate() {
+            *g = be16(header, at + 2 * i).unwrap();
+        }
+        Ipv6Addr(groups)
+    };
+    let (src, dst) = (read(8), read(24));
+    let payload_len = be16(header, 4).unwrap();
+    let mut protocol = header[6];
+    let end = (40 + payload_len as usize).min(bytes.len());
+    let mut at = 40;
+    // hop-by-hop, routing, destination options: next header, then length in 8 byte units not counting the first 8
+    // fragment: always 8 bytes
+    while matches!(protocol, 0 | 43 | 44 | 60) {
+        let next = *bytes
+            .get(at)
+            .ok_or_else(|| DecodeError::new("ipv6", "extension header is cut off"))?;
+        let len = if protocol == 44 {
+            8
+        } else {
+            (*bytes
+                .get(at + 1)
+                .ok_or_else(|| DecodeError::new("ipv6", "extension header is cut off"))?
+                as usize
+                + 1)
+                * 8
+        };
+        protocol = next;
+        at += len;
+    }
+    let ip = Ipv6Header {
+        src,
+        dst,
+        hop_limit: header[7],
+        payload_len,
+        protocol,
+    };
+    Ok((ip, bytes.get(at..end).unwrap_or(&[])))
+}
+
+fn decode_transport(protocol: u8, bytes: &[u8]) -> Result<Transport, DecodeError> {
+    match protocol {
+        TCP => {
+            let short = || DecodeError::new("tcp", "segment is shorter than the header");
+            let header_len = (*bytes.get(12).ok_or_else(short)? >> 4) as usize * 4;
+            if header_len < 20 {
+                return Err(DecodeError::new(
+                    "tcp",
+                    &format!("header length {header_len} is under 20"),
+                ));
+            }
+            Ok(Transport::Tcp(TcpHeader {
+                src_port: be16(bytes, 0).ok_or_else(short)?,
+                dst_port: be16(bytes, 2).ok_or_else(short)?,
+                seq: be32(bytes, 4).ok_or_else(short)?,
+                ack: be32(bytes, 8).ok_or_else(short)?,
+                flags: *bytes.get(13).ok_or_else(short)?,
+                header_len,
+            }))
+        }
+        UDP => {
+            let short = || DecodeError::new("udp", "datagram is shorter than the header");
+            Ok(Transport::Udp(UdpHeader {
+                src_port: be16(bytes, 0).ok_or_else(short)?,
+                dst_port: be16(bytes, 2).ok_or_else(short)?,
+                len: be16(bytes, 4).ok_or_else(short)?,
+            }))
+        }
+        other => Ok(Transport::Other(other)),
+    }
+}
+
+pub fn decode(frame: &[u8]) -> Result<Packet, DecodeError> {
+    let (ethernet, rest) = decode_ethernet(frame)?;
+    let (network, transport) = match ethernet.ethertype {
+        0x0800 => {
+            let (ip, rest) = decode_ipv4(rest)?;
+            let transport = if ip.fragment_offset == 0 {
+                Some(decode_transport(ip.protocol, rest)?)
+            } else {
+                None
+            };
+            (Network::Ipv4(ip), transport)
+        }
+        0x86dd => {
+            let (ip, rest) = decode_ipv6(rest)?;
+            (
+                Network::Ipv6(ip),
+                Some(decode_transport(ip.protocol, rest)?),
+            )
+        }
+        other => (Network::Other(other), None),
+    };
+    Ok(Packet {
+        ethernet,
+        network,
+        transport,
+    })
+}
+
+// One direction of a conversation. Ports are 0 for protocols that don't have them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FlowKey {
+    pub protocol: u8,
+    pub src: IpAddr,
+    pub src_port: u16,
+    pub dst: IpAddr,
+    pub dst_port: u16,
+}
+
+impl FlowKey {
+    pub fn of(packet: &Packet) -> Option<Self> {
+        let (src, dst, protocol) = match packet.network {
+            Network::Ipv4(ip) => (IpAddr::V4(ip.src), IpAddr::V4(ip.dst), ip.protocol),
+            Network::Ipv6(ip) => (IpAddr::V6(ip.src), IpAddr::V6(ip.dst), ip.protocol),
+            Network::Other(_) => return None,
+        };
+        let (src_port, dst_port) = match packet.transport {
+            Some(Transport::Tcp(t)) => (t.src_port, t.dst_port),
+            Some(Transport::Udp(u)) => (u.src_port, u.dst_port),
+            _ => (0, 0),
+        };
+        Some(Self {
+            protocol,
+            src,
+            src_port,
+            dst,
+            dst_port,
+        })
+    }
+}
+
+fn endpoint(addr: IpAddr, port: u16) -> String {
+    match addr {
+        IpAddr::V4(a) => format!("{a}:{port}"),
+        IpAddr::V6(a) => format!("[{a}]:{port}"),
+    }
+}
+
+impl fmt::Display for FlowKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let protocol = match self.protocol {
+            TCP => "TCP".to_string(),
+            UDP => "UDP".to_string(),
+            1 => "ICMP".to_string(),
+            58 => "ICMPv6".to_string(),
+            n => format!("proto {n}"),
+        };
+        write!(
+            f,
+            "{protocol} {} -> {}",
+            endpoint(self.src, self.src_port),
+            endpoint(self.dst, self.dst_port)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FlowStats {
+    pub packets: u64,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Report {
+    pub packets: u64,
+    pub non_ip: u64,
+    // IPv4 packets whose header checksum is wrong; their addresses can't be trusted so they're left out of flows
+    pub bad_checksum: u64,
+    pub undecodable: Vec<(usize, DecodeError)>,
+    pub flows: HashMap<FlowKey, FlowStats>,
+    // set when the capture ended early; everything before that is still counted
+    pub error: Option<PcapError>,
+}
+
+impl Report {
+    // Biggest flows first
+    pub fn flows_by_bytes(&self) -> Vec<(FlowKey, FlowStats)> {
+        let mut flows: Vec<(FlowKey, FlowStats)> =
+            self.flows.iter().map(|(k, s)| (*k, *s)).collect();
+        flows.sort_by(|a, b| b.1.bytes.cmp(&a.1.bytes).then(a.0.cmp(&b.0)));
+        flows
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} packets, {} flows, {} not IP, {} with a bad IPv4 checksum, {} undecodable",
+            self.packets,
+            self.flows.len(),
+            self.non_ip,
+            self.bad_checksum,
+            self.undecodable.len()
+        )?;
+        for (key, stats) in self.flows_by_bytes() {
+            writeln!(
+                f,
+                "  {:>4} packets {:>8} bytes  {key}",
+                stats.packets, stats.bytes
+            )?;
+        }
+        for (record, e) in &self.undecodable {
+            writeln!(f, "  record {record}: {e}")?;
+        }
+        if let Some(e) = &self.error {
+            writeln!(f, "  stopped early: {e}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn analyse(bytes: &[u8]) -> Result<Report, PcapError> {
+    let mut report = Report::default();
+    for (n, record) in Reader::new(bytes)?.enumerate() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                report.error = Some(e);
+                break;
+            }
+        };
+        report.packets += 1;
+        let packet = match decode(record.data) {
+            Ok(packet) => packet,
+            Err(e) => {
+                report.undecodable.push((n + 1, e));
+                continue;
+            }
+        };
+        if let Network::Ipv4(ip) = packet.network
+            && !ip.checksum_ok
+        {
+            report.bad_checksum += 1;
+            continue;
+        }
+        let Some(key) = FlowKey::of(&packet) else {
+            report.non_ip += 1;
+            continue;
+        };
+        let stats = report.flows.entry(key).or_default();
+        stats.packets += 1;
+        stats.bytes += record.orig_len as u64;
+    }
+    Ok(report)
+}
+
+pub fn analyse_file(path: &str) -> Result<Report, PcapError> {
+    let bytes = fs::read(path).map_err(|e| PcapError::Io(format!("couldn't read {path}: {e}")))?;
+    analyse(&bytes)
+}
+
+// The flows command: prints the report for the capture given as the first command line argument
+pub fn pcap_command() {
+    let Some(path) = std::env::args().nth(1) else {
+        println!("usage: give the path of a pcap file");
+        return;
+    };
+    match analyse_file(&path) {
+        Ok(report) => print!("{report}"),
+        Err(e) => println!("{path}: {e}"),
+    }
+}
+
+pub fn start() {
+    let sample = include_bytes!("../../assets/pcap/sample_le.pcap");
+    let report = analyse(sample).unwrap();
+    print!("sample_le.pcap: {report}");
+
+    for record in Reader::new(sample).unwrap().take(3) {
+        let record = record.unwrap();
+        let packet = decode(record.data).unwrap();
+        if let (Network::Ipv4(ip), Some(Transport::Tcp(tcp))) = (packet.network, packet.transport) {
+            println!(
+                "  {}.{:06}  {} -> {}  ttl {}  flags {:#04x}  seq {}",
+                record.ts_sec,
+                record.ts_nanos / 1000,
+                endpoint(IpAddr::V4(ip.src), tcp.src_port),
+                endpoint(IpAddr::V4(ip.dst), tcp.dst_port),
+                ip.ttl,
+                tcp.flags,
+                tcp.seq
+            );
+        }
+    }
+
+    let cut = analyse(include_bytes!("../../assets/pcap/truncated.pcap")).unwrap();
+    print!("truncated.pcap: {cut}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = include_bytes!("../../assets/pcap/sample_le.pcap");
+
+    #[test]
+    fn flows() {
+        let report = analyse(SAMPLE).unwrap();
+        let flow = |s: &str| {
+            report
+                .flows_by_bytes()
+                .into_iter()
+                .find(|(key, _)| key.to_string() == s)
+                .map(|(_, stats)| (stats.packets, stats.bytes))
+        };
+        assert_eq!(report.packets, 12);
+        assert_eq!(report.flows.len(), 7);
+        assert_eq!((report.non_ip, report.bad_checksum), (1, 1));
+        assert!(report.undecodable.is_empty() && report.error.is_none());
+        assert_eq!(
+            flow("TCP 192.168.1.10:51000 -> 93.184.216.34:80"),
+            Some((2, 145))
+        );
+        assert_eq!(
+            flow("TCP 93.184.216.34:80 -> 192.168.1.10:51000"),
+            Some((2, 151))
+        );
+        assert_eq!(
+            flow("UDP 192.168.1.1:53 -> 192.168.1.10:40000"),
+            Some((1, 86))
+        );
+        assert_eq!(
+            flow("TCP [2001:db8::10]:52000 -> [2001:db8::1]:443"),
+            Some((2, 201))
+        );
+        assert_eq!(flow("UDP [fe80::1]:5353 -> [ff02::1]:5353"), Some((1, 75))); // behind a hop-by-hop header
+        assert_eq!(flow("UDP 10.0.0.5:1234 -> 10.0.0.6:5678"), Some((1, 52))); // VLAN 42
+    }
+
+    // Same frames, other byte order and nanosecond timestamps
+    #[test]
+    fn big_endian_nanoseconds() {
+        let report = analyse(SAMPLE).unwrap();
+        let be = include_bytes!("../../assets/pcap/sample_be_nanos.pcap");
+        assert_eq!(analyse(be).unwrap(), report);
+        let be_reader = Reader::new(be).unwrap();
+        assert!(be_reader.header.big_endian && be_reader.header.nanos);
+        let timestamps: Vec<(u32, u32)> = be_reader
+            .map(|r| r.unwrap())
+            .map(|r| (r.ts_sec, r.ts_nanos))
+            .collect();
+        let le_timestamps: Vec<(u32, u32)> = Reader::new(SAMPLE)
+            .unwrap()
+            .map(|r| r.unwrap())
+            .map(|r| (r.ts_sec, r.ts_nanos))
+            .collect();
+        assert_eq!(timestamps, le_timestamps);
+        assert_eq!(timestamps[2], (1_700_000_002, 2_000_000));
+    }
+
+    // Cut to 64 bytes: the headers are still there and the counts use the original lengths
+    #[test]
+    fn snap_length() {
+        let short = analyse(include_bytes!("../../assets/pcap/snaplen_64.pcap")).unwrap();
+        assert_eq!(
+            short.flows_by_bytes()[0].1,
+            FlowStats {
+                packets: 2,
+                bytes: 151
+            }
+        );
+        assert!(
+            Reader::new(include_bytes!("../../assets/pcap/snaplen_64.pcap"))
+                .unwrap()
+                .all(|r| r.unwrap().data.len() <= 64)
+        );
+    }
+
+    // The file stops in the middle of the third record, the first two still count
+    #[test]
+    fn truncated() {
+        let cut = analyse(include_bytes!("../../assets/pcap/truncated.pcap")).unwrap();
+        assert_eq!(cut.packets, 2);
+        assert_eq!(
+            cut.error,
+            Some(PcapError::Truncated {
+                record: 3,
+                offset: 164
+            })
+        );
+        assert!(
+            cut.to_string().ends_with(
+                "stopped early: the file ends in the middle of record 3 (at byte 164)\n"
+            )
+        );
+    }
+
+    // A microsecond fraction of a million or more would overflow when turned into nanoseconds
+    #[test]
+    fn bad_timestamps() {
+        let mut corrupt = SAMPLE.to_vec();
+        corrupt[24 + 4..24 + 8].copy_from_slice(&4_295_000u32.to_le_bytes());
+        let mut reader = Reader::new(&corrupt).unwrap();
+        assert_eq!(
+            reader.next(),
+            Some(Err(PcapError::BadTimestamp {
+                record: 1,
+                offset: 24,
+                frac: 4_295_000
+            }))
+        );
+        assert_eq!(reader.next(), None);
+        corrupt[24 + 4..24 + 8].copy_from_slice(&999_999u32.to_le_bytes());
+        let first = Reader::new(&corrupt).unwrap().next().unwrap().unwrap();
+        assert_eq!(first.ts_nanos, 999_999_000);
+    }
+
+    #[test]
+    fn broken_headers() {
+        assert_eq!(analyse(b"not a pcap"), Err(PcapError::TooShort));
+        assert_eq!(analyse(&[0u8; 24]), Err(PcapError::BadMagic(0)));
+        let mut raw_ip = SAMPLE[..24].to_vec();
+        raw_ip[20] = 101;
+        assert_eq!(analyse(&raw_ip), Err(PcapError::UnsupportedLinkType(101)));
+        let first = Reader::new(SAMPLE).unwrap().next().unwrap().unwrap().data;
+        assert_eq!(
+            decode(&first[..30]),
+            Err(DecodeError::new(
+                "ipv4",
+                "packet is shorter than the header"
+            ))
+        );
+        let mut wrong_version = first.to_vec();
+        wrong_version[14] = 0x65;
+        assert_eq!(decode(&wrong_version).unwrap_err().layer, "ipv4");
+        assert_eq!(internet_checksum_sum(&first[14..34]), 0xffff);
+    }
+}
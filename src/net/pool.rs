@@ -0,0 +1,889 @@
+#![allow(dead_code)]
+use super::addr::{IpAddr, Ipv4Addr};
+use super::cidr::Cidr;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Hands out IPv4 addresses from a subnet, the way a DHCP server does.
+//
+// Every address in the subnet's host range is either excluded (never handed out: gateways, servers with fixed
+// addresses), reserved for one client (it always gets that address, nobody else does), leased to a client until
+// some time, or free. A lease that has run out is free again, but the client that had it gets it back if nobody
+// else took it in the meantime.
+//
+// Time comes from a Clock, so the demo and the tests can move time forward instead of waiting.
+//
+// The pool is saved as a text file, one setting per line:
+//   subnet 192.168.1.0/24
+//   lease-time 3600
+//   exclude 192.168.1.1-192.168.1.9
+//   reserve 192.168.1.50 printer
+//   lease 192.168.1.10 laptop 1700003600        (the last number is when it expires, in Unix seconds)
+// Everything after a # is a comment, which is why client names are single words without one. Loading runs the same
+// checks as the methods do, so a hand-edited file can't lease an excluded address or someone else's reservation.
+
+pub trait Clock {
+    // seconds since the Unix epoch
+    fn now(&self) -> u64;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs())
+    }
+}
+
+// A clock that only moves when told to
+pub struct ManualClock {
+    now: Cell<u64>,
+}
+
+impl ManualClock {
+    pub fn new(now: u64) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    pub fn advance(&self, seconds: u64) {
+        self.now.set(self.now.get() + seconds);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> u64 {
+        self.now.get()
+    }
+}
+
+// Clocks can be shared, so a test can keep a handle to the one inside the pool
+impl<C: Clock> Clock for &C {
+    fn now(&self) -> u64 {
+        (*self).now()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lease {
+    pub client: String,
+    pub expires: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PoolError {
+    NotIpv4(Cidr),
+    // no free address left
+    Exhausted,
+    OutsideSubnet(Ipv4Addr),
+    Excluded(Ipv4Addr),
+    ReservedFor(Ipv4Addr, String),
+    LeasedTo(Ipv4Addr, String),
+    NoLease(String),
+    // empty, or has whitespace or a # in it, which the pool file couldn't hold
+    BadClient(String),
+    Parse { line: usize, message: String },
+    Io(String),
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::NotIpv4(cidr) => write!(f, "{cidr} isn't an IPv4 subnet"),
+            PoolError::Exhausted => write!(f, "no free addresses left"),
+            PoolError::OutsideSubnet(addr) => {
+                write!(f, "{addr} isn't a host address in the subnet")
+            }
+            PoolError::Excluded(addr) => write!(f, "{addr} is excluded"),
+            PoolError::ReservedFor(addr, client) => write!(f, "{addr} is reserved for {client}"),
+            PoolError::LeasedTo(addr, client) => write!(f, "{addr} is leased to {client}"),
+            PoolError::NoLease(what) => write!(f, "{what} has no lease"),
+            PoolError::BadClient(client) => write!(
+                f,
+                "{client:?} can't be a client name, it has to be one word without a #"
+            ),
+            PoolError::Parse { line, message } => write!(f, "line {line}: {message}"),
+            PoolError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum State {
+    Free,
+    Excluded,
+    Reserved(String),
+    Leased(Lease),
+    // ran out, but kept for the client that had it
+    Expired(Lease),
+}
+
+pub struct Pool<C: Clock> {
+    subnet: Cidr,
+    first: u32,
+    last: u32,
+    pub lease_time: u64,
+    // inclusive ranges, as given
+    excluded: Vec<(u32, u32)>,
+    reservations: BTreeMap<u32, String>,
+    leases: BTreeMap<u32, Lease>,
+    clock: C,
+}
+
+impl<C: Clock> Pool<C> {
+    pub fn new(subnet: Cidr, lease_time: u64, clock: C) -> Result<Self, PoolError> {
+        let (IpAddr::V4(first), IpAddr::V4(last)) = subnet.hosts() else {
+            return Err(PoolError::NotIpv4(subnet));
+        };
+        Ok(Self {
+            subnet,
+            first: first.to_bits(),
+            last: last.to_bits(),
+            lease_time,
+            excluded: Vec::new(),
+            reservations: BTreeMap::new(),
+            leases: BTreeMap::new(),
+            clock,
+        })
+    }
+
+    pub fn subnet(&self) -> Cidr {
+        self.subnet
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    fn check_in_range(&self, addr: Ipv4Addr) -> Result<u32, PoolError> {
+        let bits = addr.to_bits();
+        if bits < self.first || bits > self.last {
+            return Err(PoolError::OutsideSubnet(addr));
+        }
+        Ok(bits)
+    }
+
+    fn is_excluded(&self, bits: u32) -> bool {
+        self.excluded
+            .iter()
+            .any(|&(from, to)| from <= bits && bits <= to)
+    }
+
+    fn check_client(client: &str) -> Result<(), PoolError> {
+        if client.is_empty() || client.contains(|c: char| c == '#' || c.is_whitespace()) {
+            return Err(PoolError::BadClient(client.to_string()));
+        }
+        Ok(())
+    }
+
+    fn is_active(&self, lease: &Lease) -> bool {
+        lease.expires > self.clock.now()
+    }
+
+    pub fn state(&self, addr: Ipv4Addr) -> State {
+        let bits = addr.to_bits();
+        if bits < self.first || bits > self.last || self.is_excluded(bits) {
+            return State::Excluded;
+        }
+        if let Some(lease) = self.leases.get(&bits) {
+            return if self.is_active(lease) {
+                State::Leased(lease.clone())
+            } else {
+                State::Expired(lease.clone())
+            };
+        }
+        match self.reservations.get(&bits) {
+            Some(client) => State::Reserved(client.clone()),
+            None => State::Free,
+        }
+    }
+
+    pub fn exclude(&mut self, from: Ipv4Addr, to: Ipv4Addr) -> Result<(), PoolError> {
+        let (from, to) = (self.check_in_range(from)?, self.check_in_range(to)?);
+        let (from, to) = (from.min(to), from.max(to));
+        for (&bits, lease) in self.leases.range(from..=to) {
+            if self.is_active(lease) {
+                return Err(PoolError::LeasedTo(
+                    Ipv4Addr::from_bits(bits),
+                    lease.client.clone(),
+                ));
+            }
+        }
+        if let Some((&bits, client)) = self.reservations.range(from..=to).next() {
+            return Err(PoolError::ReservedFor(
+                Ipv4Addr::from_bits(bits),
+                client.clone(),
+            ));
+        }
+        self.excluded.push((from, to));
+        Ok(())
+    }
+
+    // The client gets `addr` whenever it asks. Replaces any earlier reservation for the same client.
+    pub fn reserve(&mut self, addr: Ipv4Addr, client: &str) -> Result<(), PoolError> {
+        Self::check_client(client)?;
+        let bits = self.check_in_range(addr)?;
+        if self.is_excluded(bits) {
+            return Err(PoolError::Excluded(addr));
+        }
+        if let Some(other) = self.reservations.get(&bits)
+            && other != client
+        {
+            return Err(PoolError::ReservedFor(addr, other.clone()));
+        }
+        if let Some(lease) = self.leases.get(&bits)
+            && lease.client != client
+            && self.is_active(lease)
+        {
+            return Err(PoolError::LeasedTo(addr, lease.client.clone()));
+        }
+        self.reservations.retain(|_, c| c != client);
+        self.reservations.insert(bits, client.to_string());
+        Ok(())
+    }
+
+    pub fn unreserve(&mut self, addr: Ipv4Addr) -> Option<String> {
+        self.reservations.remove(&addr.to_bits())
+    }
+
+    // The address the client should use now, leased (or renewed) until now + lease_time. In order of preference:
+    // its reservation, the address it already has (even if that ran out), then the lowest free address. When the
+    // pool is full, expired leases of other clients are taken over, oldest first.
+    pub fn lease(&mut self, client: &str) -> Result<Ipv4Addr, PoolError> {
+        Self::check_client(client)?;
+        let now = self.clock.now();
+        let reserved = self
+            .reservations
+            .iter()
+            .find(|(_, c)| *c == client)
+            .map(|(&bits, _)| bits);
+        let previous = self
+            .leases
+            .iter()
+            .find(|(bits, lease)| {
+                lease.client == client
+                    && !self.reservations.contains_key(bits)
+                    && !self.is_excluded(**bits)
+            })
+            .map(|(&bits, _)| bits);
+        let bits = match reserved.or(previous) {
+            Some(bits) => bits,
+            None => self.free_address(now)?,
+        };
+        // a client only ever holds one address
+        self.leases
+            .retain(|&b, lease| lease.client != client || b == bits);
+        self.leases.insert(
+            bits,
+            Lease {
+                client: client.to_string(),
+                expires: now.saturating_add(self.lease_time),
+            },
+        );
+        Ok(Ipv4Addr::from_bits(bits))
+    }
+
+    fn free_address(&self, now: u64) -> Result<u32, PoolError> {
+        let usable = |bits: &u32| !self.is_excluded(*bits) && !self.reservations.contains_key(bits);
+        if let Some(bits) =
+            (self.first..=self.last).find(|b| usable(b) && !self.leases.contains_key(b))
+        {
+            return Ok(bits);
+        }
+        self.leases
+            .iter()
+            .filter(|(bits, lease)| lease.expires <= now && usable(bits))
+            .min_by_key(|(_, lease)| lease.expires)
+            .map(|(&bits, _)| bits)
+            .ok_or(PoolError::Exhausted)
+    }
+
+    // Only extends a lease the client already has, unlike lease() which may hand out a new address. A lease that
+    // ran out after its address was excluded or reserved for someone else can't be renewed.
+    pub fn renew(&mut self, client: &str) -> Result<Ipv4Addr, PoolError> {
+        let no_lease = || PoolError::NoLease(client.to_string());
+        let bits = self
+            .leases
+            .iter()
+            .find(|(_, lease)| lease.client == client)
+            .map(|(&bits, _)| bits)
+            .ok_or_else(no_lease)?;
+        let taken = self.reservations.get(&bits).is_some_and(|c| c != client);
+        if taken || self.is_excluded(bits) {
+            self.leases.remove(&bits);
+            return Err(no_lease());
+        }
+        self.leases.get_mut(&bits).unwrap().expires =
+            self.clock.now().saturating_add(self.lease_time);
+        Ok(Ipv4Addr::from_bits(bits))
+    }
+
+    pub fn release(&mut self, addr: Ipv4Addr) -> Result<Lease, PoolError> {
+        self.leases
+            .remove(&addr.to_bits())
+            .ok_or_else(|| PoolError::NoLease(addr.to_string()))
+    }
+
+    pub fn release_client(&mut self, client: &str) -> Result<Ipv4Addr, PoolError> {
+        let bits = self
+            .leases
+            .iter()
+            .find(|(_, lease)| lease.client == client)
+            .map(|(&bits, _)| bits)
+            .ok_or_else(|| PoolError::NoLease(client.to_string()))?;
+        self.leases.remove(&bits);
+        Ok(Ipv4Addr::from_bits(bits))
+    }
+
+    // Forgets leases that ran out more than `grace` seconds ago, returns how many
+    pub fn expire(&mut self, grace: u64) -> usize {
+        let now = self.clock.now();
+        let before = self.leases.len();
+        self.leases
+            .retain(|_, lease| lease.expires.saturating_add(grace) > now);
+        before - self.leases.len()
+    }
+
+    // Every address that isn't plain free, in order
+    pub fn entries(&self) -> Vec<(Ipv4Addr, State)> {
+        let mut bits: Vec<u32> = self
+            .leases
+            .keys()
+            .chain(self.reservations.keys())
+            .copied()
+            .collect();
+        bits.sort();
+        bits.dedup();
+        bits.into_iter()
+            .map(|b| (Ipv4Addr::from_bits(b), self.state(Ipv4Addr::from_bits(b))))
+            .collect()
+    }
+
+    pub fn free_count(&self) -> usize {
+        (self.first..=self.last)
+            .filter(|&b| self.state(Ipv4Addr::from_bits(b)) == State::Free)
+            .count()
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = format!("subnet {}\nlease-time {}\n", self.subnet, self.lease_time);
+        for &(from, to) in &self.excluded {
+            let (from, to) = (Ipv4Addr::from_bits(from), Ipv4Addr::from_bits(to));
+            if from == to {
+                out.push_str(&format!("exclude {from}\n"));
+            } else {
+                out.push_str(&format!("exclude {from}-{to}\n"));
+            }
+        }
+        for (&bits, client) in &self.reservations {
+            out.push_str(&format!("reserve {} {client}\n", Ipv4Addr::from_bits(bits)));
+        }
+        for (&bits, lease) in &self.leases {
+            out.push_str(&format!(
+                "lease {} {} {}\n",
+                Ipv4Addr::from_bits(bits),
+                lease.client,
+                lease.expires
+            ));
+        }
+        out
+    }
+
+    pub fn from_text(text: &str, clock: C) -> Result<Self, PoolError> {
+        let mut pool: Option<Self> = None;
+        let mut clock = Some(clock);
+        for (n, line) in text.lines().enumerate() {
+            let error = |message: String| PoolError::Parse {
+                line: n + 1,
+                message,
+            };
+            let addr = |s: &str| {
+                s.parse::<Ipv4Addr>()
+                    .map_err(|e| error(format!("`{s}`: {e}")))
+            };
+            let number = |s: &str| {
+                s.parse::<u64>()
+                    .map_err(|_| error(format!("`{s}` isn't a number")))
+            };
+            let words: Vec<&str> = line.split('#').next().unwrap().split_whitespace().collect();
+            if words.is_empty() {
+                continue;
+            }
+            if let ["subnet", subnet] = words[..] {
+                if pool.is_some() {
+                    return Err(error("the subnet is given twice".to_string()));
+                }
+                let subnet: Cidr = subnet
+                    .parse()
+                    .map_err(|e| error(format!("`{subnet}`: {e}")))?;
+                pool = Some(
+                    Self::new(subnet, 3600, clock.take().unwrap())
+                        .map_err(|e| error(e.to_string()))?,
+                );
+                continue;
+            }
+            let Some(pool) = pool.as_mut() else {
+                return Err(error("the file has to start with the subnet".to_string()));
+            };
+            match words[..] {
+                ["lease-time", seconds] => pool.lease_time = number(seconds)?,
+                ["exclude", range] => {
+                    let (from, to) = range.split_once('-').unwrap_or((range, range));
+                    pool.exclude(addr(from)?, addr(to)?)
+                        .map_err(|e| error(e.to_string()))?;
+                }
+                ["reserve", at, client] => pool
+                    .reserve(addr(at)?, client)
+                    .map_err(|e| error(e.to_string()))?,
+                ["lease", at, client, expires] => {
+                    let lease = Lease {
+                        client: client.to_string(),
+                        expires: number(expires)?,
+                    };
+                    pool.load_lease(addr(at)?, lease)
+                        .map_err(|e| error(e.to_string()))?;
+                }
+                _ => {
+                    return Err(error(format!(
+                        "don't know what `{}` means",
+                        words.join(" ")
+                    )));
+                }
+            }
+        }
+        pool.ok_or(PoolError::Parse {
+            line: 0,
+            message: "there's no subnet line".to_string(),
+        })
+    }
+
+    // A lease line from the file, held to what lease() and reserve() promise: one address per client, and no
+    // running lease on an excluded address or on one reserved for somebody else. Expired leases may sit there,
+    // exclude() and reserve() leave them in place too.
+    fn load_lease(&mut self, addr: Ipv4Addr, lease: Lease) -> Result<(), PoolError> {
+        Self::check_client(&lease.client)?;
+        let bits = self.check_in_range(addr)?;
+        if let Some(other) = self.leases.get(&bits) {
+            return Err(PoolError::LeasedTo(addr, other.client.clone()));
+        }
+        // a client only ever holds one address
+        if let Some((&held, _)) = self.leases.iter().find(|(_, l)| l.client == lease.client) {
+            return Err(PoolError::LeasedTo(Ipv4Addr::from_bits(held), lease.client));
+        }
+        if self.is_active(&lease) {
+            if self.is_excluded(bits) {
+                return Err(PoolError::Excluded(addr));
+            }
+            if let Some(other) = self.reservations.get(&bits)
+                && *other != lease.client
+            {
+                return Err(PoolError::ReservedFor(addr, other.clone()));
+            }
+        }
+        self.leases.insert(bits, lease);
+        Ok(())
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), PoolError> {
+        fs::write(path, self.to_text())
+            .map_err(|e| PoolError::Io(format!("couldn't write {path}: {e}")))
+    }
+
+    pub fn load(path: &str, clock: C) -> Result<Self, PoolError> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| PoolError::Io(format!("couldn't read {path}: {e}")))?;
+        Self::from_text(&text, clock)
+    }
+
+    // One admin command. The bool says whether the pool changed and should be saved.
+    pub fn admin(&mut self, line: &str) -> Result<(String, bool), String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let addr = |s: &str| s.parse::<Ipv4Addr>().map_err(|e| e.render(s));
+        let now = self.clock.now();
+        match words[..] {
+            ["list"] => {
+                let mut out = format!(
+                    "{}: {} free, lease time {}s\n",
+                    self.subnet,
+                    self.free_count(),
+                    self.lease_time
+                );
+                for (addr, state) in self.entries() {
+                    let line = match state {
+                        State::Reserved(client) => format!("reserved for {client}"),
+                        State::Leased(lease) => {
+                            format!("leased to {} for {}s more", lease.client, lease.expires - now)
+                        }
+                        State::Expired(lease) => {
+                            format!("was leased to {}, ran out {}s ago", lease.client, now - lease.expires)
+                        }
+                        State::Free | State::Excluded => continue,
+                    };
+                    out.push_str(&format!("  {addr:<15} {line}\n"));
+                }
+                for &(from, to) in &self.excluded {
+                    out.push_str(&format!(
+                        "  {:<15} excluded{}\n",
+                        Ipv4Addr::from_bits(from).to_string(),
+                        if from == to {
+                            String::new()
+                        } else {
+                            format!(" through {}", Ipv4Addr::from_bits(to))
+                        }
+                    ));
+                }
+                Ok((out, false))
+            }
+            ["lease", client] => {
+                let addr = self.lease(client).map_err(|e| e.to_string())?;
+                Ok((format!("{client} gets {addr}\n"), true))
+            }
+            ["renew", client] => {
+                let addr = self.renew(client).map_err(|e| e.to_string())?;
+                Ok((format!("{client} keeps {addr}\n"), true))
+            }
+            ["release", what] => {
+                let message = if what.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                    let lease = self.release(addr(what)?).map_err(|e| e.to_string())?;
+                    format!("{what} released from {}\n", lease.client)
+                } else {
+                    let addr = self.release_client(what).map_err(|e| e.to_string())?;
+                    format!("{addr} released from {what}\n")
+                };
+                Ok((message, true))
+            }
+            ["reserve", at, client] => {
+                self.reserve(addr(at)?, client).map_err(|e| e.to_string())?;
+                Ok((format!("{at} reserved for {client}\n"), true))
+            }
+            ["unreserve", at] => match self.unreserve(addr(at)?) {
+                Some(client) => Ok((format!("{at} is no longer reserved for {client}\n"), true)),
+                None => Err(format!("{at} isn't reserved")),
+            },
+            ["exclude", range] => {
+                let (from, to) = range.split_once('-').unwrap_or((range, range));
+                self.exclude(addr(from)?, addr(to)?).map_err(|e| e.to_string())?;
+                Ok((format!("{range} excluded\n"), true))
+            }
+            ["expire"] => {
+                let removed = self.expire(0);
+                Ok((format!("forgot {removed} expired lease(s)\n"), removed > 0))
+            }
+            _ => Err("commands: list | lease <client> | renew <client> | release <address|client> | \
+                      reserve <address> <client> | unreserve <address> | exclude <address>[-<address>] | expire"
+                .to_string()),
+        }
+    }
+}
+
+// The admin tool: the pool file is the first command line argument (a /24 is set up if it doesn't exist yet),
+// commands are read from stdin and the file is saved after every change
+pub fn pool_command() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "pool.txt".to_string());
+    let loaded = match fs::read_to_string(&path) {
+        Ok(text) => Pool::from_text(&text, SystemClock),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            println!("{path} doesn't exist yet, starting with 192.168.1.0/24");
+            Pool::new("192.168.1.0/24".parse().unwrap(), 3600, SystemClock)
+        }
+        Err(e) => Err(PoolError::Io(e.to_string())),
+    };
+    let mut pool = match loaded {
+        Ok(pool) => pool,
+        Err(e) => {
+            println!("{path}: {e}");
+            return;
+        }
+    };
+    println!("Type a command, or anything else for help (Ctrl-D to quit):");
+    for line in std::io::stdin().lines() {
+        let line = line.expect("Failed to read the line");
+        if line.trim().is_empty() {
+            continue;
+        }
+        match pool.admin(&line) {
+            Ok((out, changed)) => {
+                print!("{out}");
+                if changed && let Err(e) = pool.save(&path) {
+                    println!("{e}");
+                }
+            }
+            Err(e) => println!("{e}"),
+        }
+    }
+}
+
+pub fn start() {
+    let clock = ManualClock::new(1_700_000_000);
+    let mut pool = Pool::new("192.168.1.0/29".parse().unwrap(), 600, &clock).unwrap();
+
+    for command in [
+        "exclude 192.168.1.1",
+        "reserve 192.168.1.6 printer",
+        "lease laptop",
+        "lease phone",
+        "lease tv",
+        "lease laptop",
+        "lease printer",
+        "lease fridge",
+        "lease toaster",
+        "list",
+    ] {
+        match pool.admin(command) {
+            Ok((out, _)) => print!("> {command}\n{out}"),
+            Err(e) => println!("> {command}\n{e}"),
+        }
+    }
+
+    let path = std::env::temp_dir().join("pool_demo.txt");
+    let path = path.to_str().unwrap();
+    match pool.save(path) {
+        Ok(()) => println!("Saved to {path}:\n{}", pool.to_text()),
+        Err(e) => println!("{e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    fn a(s: &str) -> Ipv4Addr {
+        s.parse().unwrap()
+    }
+
+    // The same pool as start(): .1 excluded, .6 reserved for the printer and .2 to .5 leased out
+    fn demo_pool(clock: &ManualClock) -> Pool<&ManualClock> {
+        let mut pool = Pool::new("192.168.1.0/29".parse().unwrap(), 600, clock).unwrap();
+        pool.exclude(a("192.168.1.1"), a("192.168.1.1")).unwrap();
+        pool.reserve(a("192.168.1.6"), "printer").unwrap();
+        for client in ["laptop", "phone", "tv", "laptop", "printer", "fridge"] {
+            pool.lease(client).unwrap();
+        }
+        assert_eq!(pool.lease("toaster"), Err(PoolError::Exhausted));
+        pool
+    }
+
+    #[test]
+    fn leases_over_time() {
+        let clock = ManualClock::new(1_700_000_000);
+        let mut pool = demo_pool(&clock);
+        assert_eq!(
+            pool.state(a("192.168.1.2")),
+            State::Leased(Lease {
+                client: "laptop".to_string(),
+                expires: 1_700_000_600
+            })
+        );
+        assert_eq!(pool.lease("kettle"), Err(PoolError::Exhausted));
+        assert_eq!(
+            pool.reserve(a("192.168.1.1"), "kettle"),
+            Err(PoolError::Excluded(a("192.168.1.1")))
+        );
+        assert_eq!(
+            pool.reserve(a("192.168.1.9"), "kettle"),
+            Err(PoolError::OutsideSubnet(a("192.168.1.9")))
+        );
+        assert_eq!(
+            pool.reserve(a("192.168.1.3"), "kettle"),
+            Err(PoolError::LeasedTo(a("192.168.1.3"), "phone".to_string()))
+        );
+        assert_eq!(
+            pool.renew("kettle"),
+            Err(PoolError::NoLease("kettle".to_string()))
+        );
+
+        // Time passes: the laptop renews, the others run out
+        clock.advance(300);
+        assert_eq!(pool.renew("laptop"), Ok(a("192.168.1.2")));
+        clock.advance(400);
+        assert_eq!(
+            pool.state(a("192.168.1.3")),
+            State::Expired(Lease {
+                client: "phone".to_string(),
+                expires: 1_700_000_600
+            })
+        );
+        // a newcomer takes over an expired lease, the tv gets back its own address
+        assert_eq!(pool.lease("kettle"), Ok(a("192.168.1.3")));
+        assert_eq!(pool.lease("tv"), Ok(a("192.168.1.4")));
+        assert_eq!(pool.lease("phone"), Ok(a("192.168.1.5"))); // the fridge's, since the kettle has the phone's
+        assert_eq!(pool.lease("fridge"), Err(PoolError::Exhausted));
+        assert_eq!(pool.release_client("tv"), Ok(a("192.168.1.4")));
+        assert_eq!(pool.lease("fridge"), Ok(a("192.168.1.4")));
+        assert_eq!(pool.lease("printer"), Ok(a("192.168.1.6")));
+        assert_eq!(pool.free_count(), 0);
+
+        // Saving and loading keeps everything
+        let loaded = Pool::from_text(&pool.to_text(), &clock).unwrap();
+        assert_eq!(loaded.to_text(), pool.to_text());
+
+        clock.advance(1_000);
+        assert_eq!(pool.expire(0), 5);
+        assert_eq!(pool.free_count(), 4); // .1 is excluded and .6 is still the printer's
+    }
+
+    #[test]
+    fn file_errors() {
+        let clock = ManualClock::new(1_700_000_000);
+        let error = |text: &str| Pool::from_text(text, &clock).err().unwrap().to_string();
+        assert_eq!(
+            error("lease-time 60"),
+            "line 1: the file has to start with the subnet"
+        );
+        assert_eq!(
+            error("subnet 10.0.0.0/30\nlease 10.0.0.9 x 1"),
+            "line 2: 10.0.0.9 isn't a host address in the subnet"
+        );
+        assert_eq!(
+            error("subnet fe80::/64"),
+            "line 1: fe80::/64 isn't an IPv4 subnet"
+        );
+        assert_eq!(
+            error("subnet 10.0.0.0/24\nfoo bar"),
+            "line 2: don't know what `foo bar` means"
+        );
+    }
+
+    // A # starts a comment in the file, so a name with one in it would be cut short when loaded
+    #[test]
+    fn client_names_are_single_words() {
+        let clock = ManualClock::new(0);
+        let mut pool = Pool::new("10.0.0.0/29".parse().unwrap(), 60, &clock).unwrap();
+        for name in ["", "a#b", "my laptop", "tab\there"] {
+            assert_eq!(
+                pool.lease(name),
+                Err(PoolError::BadClient(name.to_string()))
+            );
+            assert_eq!(
+                pool.reserve(a("10.0.0.2"), name),
+                Err(PoolError::BadClient(name.to_string()))
+            );
+        }
+        assert!(pool.admin("lease a#b").is_err());
+        assert_eq!(pool.lease("laptop-2"), Ok(a("10.0.0.1")));
+        assert!(pool.to_text().contains("lease 10.0.0.1 laptop-2 60\n"));
+    }
+
+    // Lease lines get the checks lease() and reserve() would have made; expired leases may stay where they are
+    #[test]
+    fn loaded_leases_are_checked() {
+        let clock = ManualClock::new(1_000);
+        let error = |text: &str| Pool::from_text(text, &clock).err().unwrap().to_string();
+        let head = "subnet 10.0.0.0/29\nexclude 10.0.0.1\nreserve 10.0.0.2 printer\n";
+        assert_eq!(
+            error(&format!("{head}lease 10.0.0.1 laptop 2000")),
+            "line 4: 10.0.0.1 is excluded"
+        );
+        assert_eq!(
+            error(&format!("{head}lease 10.0.0.2 laptop 2000")),
+            "line 4: 10.0.0.2 is reserved for printer"
+        );
+        assert_eq!(
+            error(&format!(
+                "{head}lease 10.0.0.3 laptop 2000\nlease 10.0.0.4 laptop 2000"
+            )),
+            "line 5: 10.0.0.3 is leased to laptop"
+        );
+        assert_eq!(
+            error(&format!(
+                "{head}lease 10.0.0.3 a 2000\nlease 10.0.0.3 b 2000"
+            )),
+            "line 5: 10.0.0.3 is leased to a"
+        );
+        assert_eq!(
+            error(&format!("{head}lease 10.0.0.3 lap#top 2000")),
+            "line 4: don't know what `lease 10.0.0.3 lap` means"
+        );
+
+        let text = format!(
+            "{head}lease 10.0.0.1 old 500\nlease 10.0.0.2 printer 2000\nlease 10.0.0.3 tv 2000\n"
+        );
+        let pool = Pool::from_text(&text, &clock).unwrap();
+        assert_eq!(
+            pool.to_text(),
+            text.replace(
+                "subnet 10.0.0.0/29\n",
+                "subnet 10.0.0.0/29\nlease-time 3600\n"
+            )
+        );
+    }
+
+    #[test]
+    fn long_lease_times_saturate() {
+        let clock = ManualClock::new(1_700_000_000);
+        let mut pool = Pool::new("10.0.0.0/29".parse().unwrap(), u64::MAX, &clock).unwrap();
+        assert_eq!(pool.lease("laptop"), Ok(a("10.0.0.1")));
+        assert_eq!(pool.renew("laptop"), Ok(a("10.0.0.1")));
+        assert_eq!(
+            pool.state(a("10.0.0.1")),
+            State::Leased(Lease {
+                client: "laptop".to_string(),
+                expires: u64::MAX
+            })
+        );
+        assert_eq!(pool.expire(u64::MAX), 0);
+    }
+
+    #[test]
+    fn random_commands() {
+        // Random commands: no address is ever leased to two clients, no client holds two addresses, and leases stay
+        // clear of exclusions and other clients' reservations
+        let mut rng = thread_rng();
+        let clients = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+        for _ in 0..200 {
+            let clock = ManualClock::new(0);
+            let mut pool = Pool::new("10.0.0.0/28".parse().unwrap(), 100, &clock).unwrap();
+            for _ in 0..200 {
+                let client = clients[rng.gen_range(0..clients.len())];
+                let addr = Ipv4Addr::new(10, 0, 0, rng.gen_range(0..16));
+                let _ = match rng.gen_range(0..7) {
+                    0 | 1 => pool.lease(client).map(|_| ()),
+                    2 => pool.renew(client).map(|_| ()),
+                    3 => pool.release_client(client).map(|_| ()),
+                    4 => pool.reserve(addr, client),
+                    5 => pool.exclude(addr, addr),
+                    _ => {
+                        clock.advance(rng.gen_range(0..60));
+                        Ok(())
+                    }
+                };
+                let active: Vec<(u32, &Lease)> = pool
+                    .leases
+                    .iter()
+                    .filter(|(_, lease)| pool.is_active(lease))
+                    .map(|(&bits, lease)| (bits, lease))
+                    .collect();
+                for (bits, lease) in &active {
+                    assert!(!pool.is_excluded(*bits));
+                    assert!(
+                        pool.reservations
+                            .get(bits)
+                            .is_none_or(|c| *c == lease.client)
+                    );
+                }
+                let mut holders: Vec<&str> =
+                    pool.leases.values().map(|l| l.client.as_str()).collect();
+                holders.sort();
+                let count = holders.len();
+                holders.dedup();
+                assert_eq!(holders.len(), count);
+            }
+            assert_eq!(
+                Pool::from_text(&pool.to_text(), &clock).unwrap().to_text(),
+                pool.to_text()
+            );
+        }
+    }
+}
@@ -0,0 +1,461 @@
+#![allow(dead_code)]
+use super::addr::IpAddr;
+use super::cidr::Cidr;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// What chap_13's empty `route()` would need: a table of prefixes and where to send packets for them, answering
+// "where does this address go" with the longest (most specific) prefix that contains it.
+//
+// Each family has its own binary trie. The prefix 10.0.0.0/8 is stored 8 levels down, following its first 8 bits
+// (0 goes left, 1 goes right), so a lookup walks down the address's bits and remembers the last route it passed.
+// That's at most 32 steps for IPv4 and 128 for IPv6, however many routes there are.
+//
+// Route files have one route per line, blank lines and # comments are skipped:
+//   10.0.0.0/8      via 10.255.0.1
+//   192.168.1.0/24  dev eth0
+//   198.51.100.0/24 blackhole
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextHop {
+    Gateway(IpAddr),
+    Interface(String),
+    // dropped on purpose
+    Blackhole,
+}
+
+impl fmt::Display for NextHop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NextHop::Gateway(addr) => write!(f, "via {addr}"),
+            NextHop::Interface(name) => write!(f, "dev {name}"),
+            NextHop::Blackhole => write!(f, "blackhole"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteFileError {
+    // 1-based, 0 when the file couldn't be read at all
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RouteFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}: {}", self.line, self.message)
+        }
+    }
+}
+
+impl std::error::Error for RouteFileError {}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    children: [Option<Box<Node<T>>>; 2],
+    value: Option<T>,
+}
+
+impl<T> Node<T> {
+    fn new() -> Self {
+        Self {
+            children: [None, None],
+            value: None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.is_none() && self.children.iter().all(Option::is_none)
+    }
+}
+
+// One family's trie. Addresses are u128s with the `width` bits that matter at the bottom.
+#[derive(Debug, Clone)]
+struct Trie<T> {
+    root: Node<T>,
+    width: u32,
+    len: usize,
+}
+
+impl<T> Trie<T> {
+    fn new(width: u32) -> Self {
+        Self {
+            root: Node::new(),
+            width,
+            len: 0,
+        }
+    }
+
+    // Bit `depth` of the address, counting from the most significant one
+    fn bit(&self, bits: u128, depth: u32) -> usize {
+        (bits >> (self.width - 1 - depth) & 1) as usize
+    }
+
+    fn insert(&mut self, bits: u128, len: u8, value: T) -> Option<T> {
+        let mut node = &mut self.root;
+        for depth in 0..len as u32 {
+            let bit = (bits >> (self.width - 1 - depth) & 1) as usize;
+            node = node.children[bit].get_or_insert_with(|| Box::new(Node::new()));
+        }
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    fn get(&self, bits: u128, len: u8) -> Option<&T> {
+        let mut node = &self.root;
+        for depth in 0..len as u32 {
+            node = node.children[self.bit(bits, depth)].as_deref()?;
+        }
+        node.value.as_ref()
+    }
+
+    // Removes the route and any nodes left with nothing below them, so deleting routes gives the memory back
+    fn remove(&mut self, bits: u128, len: u8) -> Option<T> {
+        fn remove_below<T>(node: &mut Node<T>, path: &[usize]) -> Option<T> {
+            let Some((&bit, rest)) = path.split_first() else {
+                return node.value.take();
+            };
+            let child = node.children[bit].as_deref_mut()?;
+            let removed = remove_below(child, rest);
+            if child.is_empty() {
+                node.children[bit] = None;
+            }
+            removed
+        }
+        let path: Vec<usize> = (0..len as u32).map(|d| self.bit(bits, d)).collect();
+        let removed = remove_below(&mut self.root, &path);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    // The longest prefix of `bits` that has a route, as (prefix length, route)
+    fn lookup(&self, bits: u128) -> Option<(u8, &T)> {
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|v| (0, v));
+        for depth in 0..self.width {
+            match node.children[self.bit(bits, depth)].as_deref() {
+                Some(child) => node = child,
+                None => break,
+            }
+            if let Some(value) = &node.value {
+                best = Some((depth as u8 + 1, value));
+            }
+        }
+        best
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RoutingTable<T> {
+    v4: Trie<T>,
+    v6: Trie<T>,
+}
+
+impl<T> Default for RoutingTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> RoutingTable<T> {
+    pub fn new() -> Self {
+        Self {
+            v4: Trie::new(32),
+            v6: Trie::new(128),
+        }
+    }
+
+    fn trie(&self, v4: bool) -> &Trie<T> {
+        if v4 { &self.v4 } else { &self.v6 }
+    }
+
+    fn trie_mut(&mut self, v4: bool) -> &mut Trie<T> {
+        if v4 { &mut self.v4 } else { &mut self.v6 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.v4.len + self.v6.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Returns the route that was there before, if any
+    pub fn insert(&mut self, prefix: Cidr, hop: T) -> Option<T> {
+        let bits = prefix.network().to_bits();
+        self.trie_mut(prefix.is_ipv4())
+            .insert(bits, prefix.len(), hop)
+    }
+
+    pub fn remove(&mut self, prefix: &Cidr) -> Option<T> {
+        let bits = prefix.network().to_bits();
+        self.trie_mut(prefix.is_ipv4()).remove(bits, prefix.len())
+    }
+
+    // The route for exactly this prefix
+    pub fn get(&self, prefix: &Cidr) -> Option<&T> {
+        let bits = prefix.network().to_bits();
+        self.trie(prefix.is_ipv4()).get(bits, prefix.len())
+    }
+
+    // The most specific route for `addr`, with the prefix it came from
+    pub fn lookup(&self, addr: IpAddr) -> Option<(Cidr, &T)> {
+        let (len, hop) = self.trie(addr.is_ipv4()).lookup(addr.to_bits())?;
+        Some((Cidr::containing(addr, len).unwrap(), hop))
+    }
+
+    // Every route in prefix order: IPv4 then IPv6, by network address, shorter prefixes first on a tie (the same
+    // order as sorting the Cidrs)
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            stack: vec![(&self.v6.root, false, 0, 0), (&self.v4.root, true, 0, 0)],
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    // nodes still to visit as (node, is IPv4, bits so far, depth), the next one on top
+    stack: Vec<(&'a Node<T>, bool, u128, u8)>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Cidr, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, v4, bits, depth)) = self.stack.pop() {
+            let width = if v4 { 32 } else { 128 };
+            // right child first, so the left one comes off the stack first
+            for bit in [1u128, 0] {
+                if let Some(child) = node.children[bit as usize].as_deref() {
+                    let child_bits = bits | bit << (width - 1 - depth as u32);
+                    self.stack.push((child, v4, child_bits, depth + 1));
+                }
+            }
+            if let Some(value) = &node.value {
+                let prefix = Cidr::new(IpAddr::from_bits(v4, bits), depth).unwrap();
+                return Some((prefix, value));
+            }
+        }
+        None
+    }
+}
+
+impl RoutingTable<NextHop> {
+    pub fn from_text(text: &str) -> Result<Self, RouteFileError> {
+        let mut table = Self::new();
+        for (n, line) in text.lines().enumerate() {
+            let error = |message: String| RouteFileError {
+                line: n + 1,
+                message,
+            };
+            let line = line.split('#').next().unwrap().trim();
+            let words: Vec<&str> = line.split_whitespace().collect();
+            if words.is_empty() {
+                continue;
+            }
+            let prefix: Cidr = words[0]
+                .parse()
+                .map_err(|e| error(format!("`{}`: {e}", words[0])))?;
+            let hop = match words[1..] {
+                ["via", gateway] => NextHop::Gateway(
+                    gateway
+                        .parse()
+                        .map_err(|e| error(format!("`{gateway}`: {e}")))?,
+                ),
+                ["dev", name] => NextHop::Interface(name.to_string()),
+                ["blackhole"] => NextHop::Blackhole,
+                _ => {
+                    return Err(error(format!(
+                        "expected `via <address>`, `dev <name>` or `blackhole` after {prefix}"
+                    )));
+                }
+            };
+            if table.insert(prefix, hop).is_some() {
+                return Err(error(format!("{prefix} is already in the table")));
+            }
+        }
+        Ok(table)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RouteFileError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| RouteFileError {
+            line: 0,
+            message: format!("couldn't read {}: {e}", path.display()),
+        })?;
+        Self::from_text(&text)
+    }
+
+    // Writes the table back in the file format, one route per line in prefix order
+    pub fn to_text(&self) -> String {
+        self.iter()
+            .map(|(prefix, hop)| format!("{:<24} {hop}\n", prefix.to_string()))
+            .collect()
+    }
+}
+
+pub fn start() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/routes.txt");
+    let table = RoutingTable::load(path).unwrap();
+    println!(
+        "{} routes from assets/routes.txt:\n{}",
+        table.len(),
+        table.to_text()
+    );
+
+    for addr in [
+        "10.1.2.3",
+        "10.20.0.9",
+        "192.168.1.77",
+        "8.8.8.8",
+        "198.51.100.7",
+        "2001:db8:1::5",
+        "fe80::1",
+    ] {
+        let ip: IpAddr = addr.parse().unwrap();
+        match table.lookup(ip) {
+            Some((prefix, hop)) => println!("{addr:>16} -> {hop} (matched {prefix})"),
+            None => println!("{addr:>16} -> no route"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    // The linear scan to check the trie against
+    fn brute_lookup<T>(routes: &[(Cidr, T)], addr: IpAddr) -> Option<(Cidr, &T)> {
+        routes
+            .iter()
+            .filter(|(prefix, _)| prefix.contains_addr(addr))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(prefix, hop)| (*prefix, hop))
+    }
+
+    fn random_addr(rng: &mut impl Rng, v4: bool) -> IpAddr {
+        if v4 {
+            IpAddr::from_bits(true, rng.r#gen::<u32>() as u128)
+        } else {
+            // mostly inside 2001:db8::/32 so the prefixes have something to match
+            IpAddr::from_bits(false, 0x2001_0db8 << 96 | rng.r#gen::<u128>() >> 32)
+        }
+    }
+
+    fn random_prefix(rng: &mut impl Rng, v4: bool) -> Cidr {
+        let len = if v4 {
+            rng.gen_range(0..=24)
+        } else {
+            rng.gen_range(28..=64)
+        };
+        Cidr::containing(random_addr(rng, v4), len).unwrap()
+    }
+
+    fn c(s: &str) -> Cidr {
+        s.parse().unwrap()
+    }
+
+    fn a(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn route_file() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/routes.txt");
+        let table = RoutingTable::load(path).unwrap();
+        let gw = |s: &str| NextHop::Gateway(a(s));
+        assert_eq!(
+            table.lookup(a("10.20.0.9")),
+            Some((c("10.20.0.0/16"), &gw("10.255.0.2")))
+        );
+        assert_eq!(table.lookup(a("10.1.2.3")).unwrap().0, c("10.0.0.0/8"));
+        assert_eq!(table.lookup(a("8.8.8.8")).unwrap().0, c("0.0.0.0/0"));
+        assert_eq!(table.lookup(a("fe80::1")).unwrap().0, c("::/0"));
+        assert_eq!(
+            table.lookup(a("198.51.100.7")).unwrap().1,
+            &NextHop::Blackhole
+        );
+        assert_eq!(
+            RoutingTable::from_text(&table.to_text()).unwrap().to_text(),
+            table.to_text()
+        );
+    }
+
+    #[test]
+    fn file_errors() {
+        let error = |text: &str| RoutingTable::from_text(text).unwrap_err().to_string();
+        assert_eq!(
+            error("# routes\n10.0.0.0/8 via 10.0.0.1\n10.0.0.0/8 dev eth0"),
+            "line 3: 10.0.0.0/8 is already in the table"
+        );
+        assert_eq!(
+            error("10.0.0.0/8 via eth0"),
+            "line 1: `eth0`: only digits are allowed here (at byte 0)"
+        );
+        assert!(error("10.0.0.0/8").starts_with("line 1: expected `via"));
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut small = RoutingTable::new();
+        assert_eq!(small.insert(c("10.0.0.0/8"), 1), None);
+        assert_eq!(small.insert(c("10.0.0.0/8"), 2), Some(1));
+        small.insert(c("10.1.0.0/16"), 3);
+        small.insert(c("0.0.0.0/0"), 4);
+        small.insert(c("::/0"), 5);
+        assert_eq!(small.remove(&c("10.0.0.0/8")), Some(2));
+        assert_eq!(small.remove(&c("10.0.0.0/8")), None);
+        assert_eq!(small.get(&c("10.1.0.0/16")), Some(&3));
+        assert_eq!(small.lookup(a("10.2.0.0")), Some((c("0.0.0.0/0"), &4)));
+        let order: Vec<i32> = small.iter().map(|(_, v)| *v).collect();
+        assert_eq!(order, [4, 3, 5]);
+        small.remove(&c("10.1.0.0/16"));
+        assert!(small.v4.root.children.iter().all(Option::is_none)); // pruned
+    }
+
+    // Random tables, inserts and removes checked against a plain list
+    #[test]
+    fn random_tables() {
+        let mut rng = thread_rng();
+        for round in 0..200 {
+            let v4 = round % 2 == 0;
+            let mut table = RoutingTable::new();
+            let mut routes: Vec<(Cidr, usize)> = Vec::new();
+            for i in 0..rng.gen_range(0..200) {
+                let prefix = random_prefix(&mut rng, v4);
+                let old = routes.iter().position(|(p, _)| *p == prefix);
+                assert_eq!(table.insert(prefix, i), old.map(|at| routes[at].1));
+                match old {
+                    Some(at) => routes[at].1 = i,
+                    None => routes.push((prefix, i)),
+                }
+            }
+            for _ in 0..rng.gen_range(0..50) {
+                if routes.is_empty() {
+                    break;
+                }
+                let (prefix, value) = routes.swap_remove(rng.gen_range(0..routes.len()));
+                assert_eq!(table.remove(&prefix), Some(value));
+            }
+            assert_eq!(table.len(), routes.len());
+            for _ in 0..200 {
+                let addr = random_addr(&mut rng, v4);
+                assert_eq!(table.lookup(addr), brute_lookup(&routes, addr));
+            }
+            routes.sort();
+            let listed: Vec<(Cidr, usize)> = table.iter().map(|(p, v)| (p, *v)).collect();
+            assert_eq!(listed, routes);
+        }
+    }
+}
@@ -0,0 +1,307 @@
+#![allow(dead_code)]
+
+// Case conversion for identifiers like our module names (chap_15_if_let_and_let_else_syntax).
+// Everything goes through `split_words`, which understands all the usual ways of gluing words together:
+// "if_let", "if-let", "ifLet", "IfLet", "IF_LET", and acronyms like "HTTPServer" -> ["HTTP", "Server"].
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharKind {
+    Upper,
+    Lower,
+    Digit,
+    Separator,
+}
+
+fn kind(c: char) -> CharKind {
+    if c.is_uppercase() {
+        CharKind::Upper
+    } else if c.is_numeric() {
+        CharKind::Digit
+    } else if c.is_alphanumeric() {
+        CharKind::Lower // also covers scripts without case, like Devanagari
+    } else {
+        CharKind::Separator
+    }
+}
+
+// Mixed-case acronyms can't be split by the rules below ("IPv4" would become I | Pv4), so they're listed by hand
+const MIXED_ACRONYMS: &[&str] = &["IPv4", "IPv6", "iOS", "macOS", "GHz", "MHz", "kHz"];
+
+// Word boundaries:
+// - any separator char (_ - space . etc) ends a word and is dropped
+// - lower -> Upper starts a new word: "ifLet" -> if | Let
+// - Upper Upper lower: the last capital starts a new word: "HTTPServer" -> HTTP | Server
+// - digit -> Upper starts a new word: "Ipv6Addr" -> Ipv6 | Addr
+// Digits otherwise stick to the word they follow ("utf8", "ipv6"), and a word that starts with digits stays
+// separate ("chap_15" -> chap | 15).
+pub fn split_words(s: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (idx, c) = chars[i];
+        let current = kind(c);
+        if current == CharKind::Separator {
+            if let Some(begin) = start.take() {
+                words.push(&s[begin..idx]);
+            }
+            i += 1;
+            continue;
+        }
+
+        let prev = if i > 0 {
+            kind(chars[i - 1].1)
+        } else {
+            CharKind::Separator
+        };
+        let can_start_word =
+            start.is_none() || (prev != CharKind::Upper && current == CharKind::Upper);
+        let acronym = MIXED_ACRONYMS.iter().find(|acronym| {
+            let rest = &s[idx..];
+            rest.starts_with(*acronym)
+                && !rest[acronym.len()..].starts_with(|c: char| c.is_lowercase())
+        });
+        if let Some(acronym) = acronym.filter(|_| can_start_word) {
+            if let Some(begin) = start.take() {
+                words.push(&s[begin..idx]);
+            }
+            words.push(&s[idx..idx + acronym.len()]);
+            i += acronym.chars().count();
+            continue;
+        }
+
+        match start {
+            None => start = Some(idx),
+            Some(begin) => {
+                let next = chars.get(i + 1).map(|&(_, c)| kind(c));
+                let boundary = match (prev, current) {
+                    (CharKind::Lower, CharKind::Upper) => true,
+                    (CharKind::Digit, CharKind::Upper) => true,
+                    (CharKind::Upper, CharKind::Upper) => next == Some(CharKind::Lower),
+                    _ => false,
+                };
+                if boundary {
+                    words.push(&s[begin..idx]);
+                    start = Some(idx);
+                }
+            }
+        }
+        i += 1;
+    }
+    if let Some(begin) = start {
+        words.push(&s[begin..]);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn join_lower(s: &str, separator: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+pub fn to_snake_case(s: &str) -> String {
+    join_lower(s, "_")
+}
+
+pub fn to_kebab_case(s: &str) -> String {
+    join_lower(s, "-")
+}
+
+pub fn to_screaming_snake_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|w| w.to_uppercase())
+        .collect::<Vec<String>>()
+        .join("_")
+}
+
+// Acronyms are treated as words, the way Rust names types: "HTTPServer" -> "HttpServer"
+pub fn to_pascal_case(s: &str) -> String {
+    split_words(s).iter().map(|w| capitalize(w)).collect()
+}
+
+pub fn to_camel_case(s: &str) -> String {
+    let mut out = String::new();
+    for (i, word) in split_words(s).iter().enumerate() {
+        if i == 0 {
+            out.push_str(&word.to_lowercase());
+        } else {
+            out.push_str(&capitalize(word));
+        }
+    }
+    out
+}
+
+// Short words that stay lowercase in a title unless they're the first or last word
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "the", "to",
+    "vs",
+];
+
+// "borrowing_and_references" -> "Borrowing and References". Words that were written in capitals
+// ("IPv4", "UTF") are kept as they are.
+pub fn to_title_case(s: &str) -> String {
+    let words = split_words(s);
+    let last = words.len().saturating_sub(1);
+    let mut titled = Vec::with_capacity(words.len());
+    for (i, word) in words.iter().enumerate() {
+        let lower = word.to_lowercase();
+        let is_acronym = MIXED_ACRONYMS.contains(word)
+            || (word.chars().filter(|c| c.is_uppercase()).count() > 1
+                && word.chars().all(|c| !c.is_lowercase()));
+        if is_acronym {
+            titled.push(word.to_string());
+        } else if i != 0 && i != last && MINOR_WORDS.contains(&lower.as_str()) {
+            titled.push(lower);
+        } else {
+            titled.push(capitalize(word));
+        }
+    }
+    titled.join(" ")
+}
+
+// "chap_15_if_let_and_let_else_syntax" -> "Chapter 15: If Let and Let Else Syntax"
+// Names that don't follow the chap_<number>_<topic> pattern just get title cased.
+pub fn chapter_title(module_name: &str) -> String {
+    let mut parts = module_name.splitn(3, '_');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some("chap"), Some(number), topic) if number.chars().all(|c| c.is_ascii_digit()) => {
+            let number: u32 = number.parse().unwrap_or(0);
+            match topic {
+                Some(topic) => format!("Chapter {number}: {}", to_title_case(topic)),
+                None => format!("Chapter {number}"),
+            }
+        }
+        _ => to_title_case(module_name),
+    }
+}
+
+pub fn start() {
+    let modules = [
+        "chap_02_number_guessing_game",
+        "chap_08_borrowing_and_references",
+        "chap_13_enums_and_pattern_matching",
+        "chap_15_if_let_and_let_else_syntax",
+        "chap_16_crates_modules_and_packages",
+        "chap_20_errors",
+    ];
+    for module in modules {
+        println!("{module} -> {}", chapter_title(module));
+    }
+
+    for name in [
+        "if_let_syntax",
+        "HTTPServer",
+        "parseIPv4Address",
+        "Ipv6Addr",
+        "utf8_to_utf16",
+        "XMLHttpRequest2",
+    ] {
+        println!(
+            "{name}: {} | {} | {} | {} | {} | {}",
+            to_snake_case(name),
+            to_kebab_case(name),
+            to_camel_case(name),
+            to_pascal_case(name),
+            to_title_case(name),
+            to_screaming_snake_case(name)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words() {
+        assert_eq!(split_words("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(
+            split_words("parseIPv4Address"),
+            ["parse", "IPv4", "Address"]
+        );
+        assert_eq!(split_words("utf8_to_utf16"), ["utf8", "to", "utf16"]);
+        assert!(split_words("__").is_empty());
+    }
+
+    #[test]
+    fn conversions() {
+        let convert = |name| {
+            [
+                to_snake_case(name),
+                to_kebab_case(name),
+                to_camel_case(name),
+                to_pascal_case(name),
+                to_title_case(name),
+                to_screaming_snake_case(name),
+            ]
+        };
+        assert_eq!(
+            convert("if_let_syntax"),
+            [
+                "if_let_syntax",
+                "if-let-syntax",
+                "ifLetSyntax",
+                "IfLetSyntax",
+                "If Let Syntax",
+                "IF_LET_SYNTAX"
+            ]
+        );
+        assert_eq!(
+            convert("parseIPv4Address"),
+            [
+                "parse_ipv4_address",
+                "parse-ipv4-address",
+                "parseIpv4Address",
+                "ParseIpv4Address",
+                "Parse IPv4 Address",
+                "PARSE_IPV4_ADDRESS"
+            ]
+        );
+        assert_eq!(
+            convert("utf8_to_utf16"),
+            [
+                "utf8_to_utf16",
+                "utf8-to-utf16",
+                "utf8ToUtf16",
+                "Utf8ToUtf16",
+                "Utf8 to Utf16",
+                "UTF8_TO_UTF16"
+            ]
+        );
+        assert_eq!(to_snake_case("XMLHttpRequest2"), "xml_http_request2");
+        assert_eq!(to_title_case("HTTPServer"), "HTTP Server");
+        assert_eq!(to_pascal_case("chap_15_if_let"), "Chap15IfLet");
+        assert_eq!(to_camel_case("Ipv6Addr"), "ipv6Addr");
+        assert_eq!(to_screaming_snake_case("maxChunkSize"), "MAX_CHUNK_SIZE");
+    }
+
+    #[test]
+    fn chapter_titles() {
+        assert_eq!(
+            chapter_title("chap_15_if_let_and_let_else_syntax"),
+            "Chapter 15: If Let and Let Else Syntax"
+        );
+        assert_eq!(
+            chapter_title("chap_08_borrowing_and_references"),
+            "Chapter 8: Borrowing and References"
+        );
+        assert_eq!(chapter_title("chap_20_errors"), "Chapter 20: Errors");
+    }
+}
@@ -0,0 +1,802 @@
+// Generated by scripts/char_names.py from the Unicode 16.0.0 character database, do not edit by hand.
+// Only the blocks this crate prints (ASCII, Latin-1, Cyrillic, Devanagari, General Punctuation, the euro sign,
+// flags) are included, everything else falls back to "U+XXXX" in utf8::char_name.
+
+pub const CHAR_NAMES: &[(char, &str)] = &[
+    ('\u{0}', "NULL"),
+    ('\u{1}', "START OF HEADING"),
+    ('\u{2}', "START OF TEXT"),
+    ('\u{3}', "END OF TEXT"),
+    ('\u{4}', "END OF TRANSMISSION"),
+    ('\u{5}', "ENQUIRY"),
+    ('\u{6}', "ACKNOWLEDGE"),
+    ('\u{7}', "ALERT"),
+    ('\u{8}', "BACKSPACE"),
+    ('\u{9}', "CHARACTER TABULATION"),
+    ('\u{a}', "LINE FEED"),
+    ('\u{b}', "LINE TABULATION"),
+    ('\u{c}', "FORM FEED"),
+    ('\u{d}', "CARRIAGE RETURN"),
+    ('\u{e}', "SHIFT OUT"),
+    ('\u{f}', "SHIFT IN"),
+    ('\u{10}', "DATA LINK ESCAPE"),
+    ('\u{11}', "DEVICE CONTROL ONE"),
+    ('\u{12}', "DEVICE CONTROL TWO"),
+    ('\u{13}', "DEVICE CONTROL THREE"),
+    ('\u{14}', "DEVICE CONTROL FOUR"),
+    ('\u{15}', "NEGATIVE ACKNOWLEDGE"),
+    ('\u{16}', "SYNCHRONOUS IDLE"),
+    ('\u{17}', "END OF TRANSMISSION BLOCK"),
+    ('\u{18}', "CANCEL"),
+    ('\u{19}', "END OF MEDIUM"),
+    ('\u{1a}', "SUBSTITUTE"),
+    ('\u{1b}', "ESCAPE"),
+    ('\u{1c}', "INFORMATION SEPARATOR FOUR"),
+    ('\u{1d}', "INFORMATION SEPARATOR THREE"),
+    ('\u{1e}', "INFORMATION SEPARATOR TWO"),
+    ('\u{1f}', "INFORMATION SEPARATOR ONE"),
+    ('\u{20}', "SPACE"),
+    ('\u{21}', "EXCLAMATION MARK"),
+    ('\u{22}', "QUOTATION MARK"),
+    ('\u{23}', "NUMBER SIGN"),
+    ('\u{24}', "DOLLAR SIGN"),
+    ('\u{25}', "PERCENT SIGN"),
+    ('\u{26}', "AMPERSAND"),
+    ('\u{27}', "APOSTROPHE"),
+    ('\u{28}', "LEFT PARENTHESIS"),
+    ('\u{29}', "RIGHT PARENTHESIS"),
+    ('\u{2a}', "ASTERISK"),
+    ('\u{2b}', "PLUS SIGN"),
+    ('\u{2c}', "COMMA"),
+    ('\u{2d}', "HYPHEN-MINUS"),
+    ('\u{2e}', "FULL STOP"),
+    ('\u{2f}', "SOLIDUS"),
+    ('\u{30}', "DIGIT ZERO"),
+    ('\u{31}', "DIGIT ONE"),
+    ('\u{32}', "DIGIT TWO"),
+    ('\u{33}', "DIGIT THREE"),
+    ('\u{34}', "DIGIT FOUR"),
+    ('\u{35}', "DIGIT FIVE"),
+    ('\u{36}', "DIGIT SIX"),
+    ('\u{37}', "DIGIT SEVEN"),
+    ('\u{38}', "DIGIT EIGHT"),
+    ('\u{39}', "DIGIT NINE"),
+    ('\u{3a}', "COLON"),
+    ('\u{3b}', "SEMICOLON"),
+    ('\u{3c}', "LESS-THAN SIGN"),
+    ('\u{3d}', "EQUALS SIGN"),
+    ('\u{3e}', "GREATER-THAN SIGN"),
+    ('\u{3f}', "QUESTION MARK"),
+    ('\u{40}', "COMMERCIAL AT"),
+    ('\u{41}', "LATIN CAPITAL LETTER A"),
+    ('\u{42}', "LATIN CAPITAL LETTER B"),
+    ('\u{43}', "LATIN CAPITAL LETTER C"),
+    ('\u{44}', "LATIN CAPITAL LETTER D"),
+    ('\u{45}', "LATIN CAPITAL LETTER E"),
+    ('\u{46}', "LATIN CAPITAL LETTER F"),
+    ('\u{47}', "LATIN CAPITAL LETTER G"),
+    ('\u{48}', "LATIN CAPITAL LETTER H"),
+    ('\u{49}', "LATIN CAPITAL LETTER I"),
+    ('\u{4a}', "LATIN CAPITAL LETTER J"),
+    ('\u{4b}', "LATIN CAPITAL LETTER K"),
+    ('\u{4c}', "LATIN CAPITAL LETTER L"),
+    ('\u{4d}', "LATIN CAPITAL LETTER M"),
+    ('\u{4e}', "LATIN CAPITAL LETTER N"),
+    ('\u{4f}', "LATIN CAPITAL LETTER O"),
+    ('\u{50}', "LATIN CAPITAL LETTER P"),
+    ('\u{51}', "LATIN CAPITAL LETTER Q"),
+    ('\u{52}', "LATIN CAPITAL LETTER R"),
+    ('\u{53}', "LATIN CAPITAL LETTER S"),
+    ('\u{54}', "LATIN CAPITAL LETTER T"),
+    ('\u{55}', "LATIN CAPITAL LETTER U"),
+    ('\u{56}', "LATIN CAPITAL LETTER V"),
+    ('\u{57}', "LATIN CAPITAL LETTER W"),
+    ('\u{58}', "LATIN CAPITAL LETTER X"),
+    ('\u{59}', "LATIN CAPITAL LETTER Y"),
+    ('\u{5a}', "LATIN CAPITAL LETTER Z"),
+    ('\u{5b}', "LEFT SQUARE BRACKET"),
+    ('\u{5c}', "REVERSE SOLIDUS"),
+    ('\u{5d}', "RIGHT SQUARE BRACKET"),
+    ('\u{5e}', "CIRCUMFLEX ACCENT"),
+    ('\u{5f}', "LOW LINE"),
+    ('\u{60}', "GRAVE ACCENT"),
+    ('\u{61}', "LATIN SMALL LETTER A"),
+    ('\u{62}', "LATIN SMALL LETTER B"),
+    ('\u{63}', "LATIN SMALL LETTER C"),
+    ('\u{64}', "LATIN SMALL LETTER D"),
+    ('\u{65}', "LATIN SMALL LETTER E"),
+    ('\u{66}', "LATIN SMALL LETTER F"),
+    ('\u{67}', "LATIN SMALL LETTER G"),
+    ('\u{68}', "LATIN SMALL LETTER H"),
+    ('\u{69}', "LATIN SMALL LETTER I"),
+    ('\u{6a}', "LATIN SMALL LETTER J"),
+    ('\u{6b}', "LATIN SMALL LETTER K"),
+    ('\u{6c}', "LATIN SMALL LETTER L"),
+    ('\u{6d}', "LATIN SMALL LETTER M"),
+    ('\u{6e}', "LATIN SMALL LETTER N"),
+    ('\u{6f}', "LATIN SMALL LETTER O"),
+    ('\u{70}', "LATIN SMALL LETTER P"),
+    ('\u{71}', "LATIN SMALL LETTER Q"),
+    ('\u{72}', "LATIN SMALL LETTER R"),
+    ('\u{73}', "LATIN SMALL LETTER S"),
+    ('\u{74}', "LATIN SMALL LETTER T"),
+    ('\u{75}', "LATIN SMALL LETTER U"),
+    ('\u{76}', "LATIN SMALL LETTER V"),
+    ('\u{77}', "LATIN SMALL LETTER W"),
+    ('\u{78}', "LATIN SMALL LETTER X"),
+    ('\u{79}', "LATIN SMALL LETTER Y"),
+    ('\u{7a}', "LATIN SMALL LETTER Z"),
+    ('\u{7b}', "LEFT CURLY BRACKET"),
+    ('\u{7c}', "VERTICAL LINE"),
+    ('\u{7d}', "RIGHT CURLY BRACKET"),
+    ('\u{7e}', "TILDE"),
+    ('\u{7f}', "DELETE"),
+    ('\u{82}', "BREAK PERMITTED HERE"),
+    ('\u{83}', "NO BREAK HERE"),
+    ('\u{84}', "INDEX"),
+    ('\u{85}', "NEXT LINE"),
+    ('\u{86}', "START OF SELECTED AREA"),
+    ('\u{87}', "END OF SELECTED AREA"),
+    ('\u{88}', "CHARACTER TABULATION SET"),
+    ('\u{89}', "CHARACTER TABULATION WITH JUSTIFICATION"),
+    ('\u{8a}', "LINE TABULATION SET"),
+    ('\u{8b}', "PARTIAL LINE FORWARD"),
+    ('\u{8c}', "PARTIAL LINE BACKWARD"),
+    ('\u{8d}', "REVERSE LINE FEED"),
+    ('\u{8e}', "SINGLE SHIFT TWO"),
+    ('\u{8f}', "SINGLE SHIFT THREE"),
+    ('\u{90}', "DEVICE CONTROL STRING"),
+    ('\u{91}', "PRIVATE USE ONE"),
+    ('\u{92}', "PRIVATE USE TWO"),
+    ('\u{93}', "SET TRANSMIT STATE"),
+    ('\u{94}', "CANCEL CHARACTER"),
+    ('\u{95}', "MESSAGE WAITING"),
+    ('\u{96}', "START OF GUARDED AREA"),
+    ('\u{97}', "END OF GUARDED AREA"),
+    ('\u{98}', "START OF STRING"),
+    ('\u{9a}', "SINGLE CHARACTER INTRODUCER"),
+    ('\u{9b}', "CONTROL SEQUENCE INTRODUCER"),
+    ('\u{9c}', "STRING TERMINATOR"),
+    ('\u{9d}', "OPERATING SYSTEM COMMAND"),
+    ('\u{9e}', "PRIVACY MESSAGE"),
+    ('\u{9f}', "APPLICATION PROGRAM COMMAND"),
+    ('\u{a0}', "NO-BREAK SPACE"),
+    ('\u{a1}', "INVERTED EXCLAMATION MARK"),
+    ('\u{a2}', "CENT SIGN"),
+    ('\u{a3}', "POUND SIGN"),
+    ('\u{a4}', "CURRENCY SIGN"),
+    ('\u{a5}', "YEN SIGN"),
+    ('\u{a6}', "BROKEN BAR"),
+    ('\u{a7}', "SECTION SIGN"),
+    ('\u{a8}', "DIAERESIS"),
+    ('\u{a9}', "COPYRIGHT SIGN"),
+    ('\u{aa}', "FEMININE ORDINAL INDICATOR"),
+    ('\u{ab}', "LEFT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    ('\u{ac}', "NOT SIGN"),
+    ('\u{ad}', "SOFT HYPHEN"),
+    ('\u{ae}', "REGISTERED SIGN"),
+    ('\u{af}', "MACRON"),
+    ('\u{b0}', "DEGREE SIGN"),
+    ('\u{b1}', "PLUS-MINUS SIGN"),
+    ('\u{b2}', "SUPERSCRIPT TWO"),
+    ('\u{b3}', "SUPERSCRIPT THREE"),
+    ('\u{b4}', "ACUTE ACCENT"),
+    ('\u{b5}', "MICRO SIGN"),
+    ('\u{b6}', "PILCROW SIGN"),
+    ('\u{b7}', "MIDDLE DOT"),
+    ('\u{b8}', "CEDILLA"),
+    ('\u{b9}', "SUPERSCRIPT ONE"),
+    ('\u{ba}', "MASCULINE ORDINAL INDICATOR"),
+    ('\u{bb}', "RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    ('\u{bc}', "VULGAR FRACTION ONE QUARTER"),
+    ('\u{bd}', "VULGAR FRACTION ONE HALF"),
+    ('\u{be}', "VULGAR FRACTION THREE QUARTERS"),
+    ('\u{bf}', "INVERTED QUESTION MARK"),
+    ('\u{c0}', "LATIN CAPITAL LETTER A WITH GRAVE"),
+    ('\u{c1}', "LATIN CAPITAL LETTER A WITH ACUTE"),
+    ('\u{c2}', "LATIN CAPITAL LETTER A WITH CIRCUMFLEX"),
+    ('\u{c3}', "LATIN CAPITAL LETTER A WITH TILDE"),
+    ('\u{c4}', "LATIN CAPITAL LETTER A WITH DIAERESIS"),
+    ('\u{c5}', "LATIN CAPITAL LETTER A WITH RING ABOVE"),
+    ('\u{c6}', "LATIN CAPITAL LETTER AE"),
+    ('\u{c7}', "LATIN CAPITAL LETTER C WITH CEDILLA"),
+    ('\u{c8}', "LATIN CAPITAL LETTER E WITH GRAVE"),
+    ('\u{c9}', "LATIN CAPITAL LETTER E WITH ACUTE"),
+    ('\u{ca}', "LATIN CAPITAL LETTER E WITH CIRCUMFLEX"),
+    ('\u{cb}', "LATIN CAPITAL LETTER E WITH DIAERESIS"),
+    ('\u{cc}', "LATIN CAPITAL LETTER I WITH GRAVE"),
+    ('\u{cd}', "LATIN CAPITAL LETTER I WITH ACUTE"),
+    ('\u{ce}', "LATIN CAPITAL LETTER I WITH CIRCUMFLEX"),
+    ('\u{cf}', "LATIN CAPITAL LETTER I WITH DIAERESIS"),
+    ('\u{d0}', "LATIN CAPITAL LETTER ETH"),
+    ('\u{d1}', "LATIN CAPITAL LETTER N WITH TILDE"),
+    ('\u{d2}', "LATIN CAPITAL LETTER O WITH GRAVE"),
+    ('\u{d3}', "LATIN CAPITAL LETTER O WITH ACUTE"),
+    ('\u{d4}', "LATIN CAPITAL LETTER O WITH CIRCUMFLEX"),
+    ('\u{d5}', "LATIN CAPITAL LETTER O WITH TILDE"),
+    ('\u{d6}', "LATIN CAPITAL LETTER O WITH DIAERESIS"),
+    ('\u{d7}', "MULTIPLICATION SIGN"),
+    ('\u{d8}', "LATIN CAPITAL LETTER O WITH STROKE"),
+    ('\u{d9}', "LATIN CAPITAL LETTER U WITH GRAVE"),
+    ('\u{da}', "LATIN CAPITAL LETTER U WITH ACUTE"),
+    ('\u{db}', "LATIN CAPITAL LETTER U WITH CIRCUMFLEX"),
+    ('\u{dc}', "LATIN CAPITAL LETTER U WITH DIAERESIS"),
+    ('\u{dd}', "LATIN CAPITAL LETTER Y WITH ACUTE"),
+    ('\u{de}', "LATIN CAPITAL LETTER THORN"),
+    ('\u{df}', "LATIN SMALL LETTER SHARP S"),
+    ('\u{e0}', "LATIN SMALL LETTER A WITH GRAVE"),
+    ('\u{e1}', "LATIN SMALL LETTER A WITH ACUTE"),
+    ('\u{e2}', "LATIN SMALL LETTER A WITH CIRCUMFLEX"),
+    ('\u{e3}', "LATIN SMALL LETTER A WITH TILDE"),
+    ('\u{e4}', "LATIN SMALL LETTER A WITH DIAERESIS"),
+    ('\u{e5}', "LATIN SMALL LETTER A WITH RING ABOVE"),
+    ('\u{e6}', "LATIN SMALL LETTER AE"),
+    ('\u{e7}', "LATIN SMALL LETTER C WITH CEDILLA"),
+    ('\u{e8}', "LATIN SMALL LETTER E WITH GRAVE"),
+    ('\u{e9}', "LATIN SMALL LETTER E WITH ACUTE"),
+    ('\u{ea}', "LATIN SMALL LETTER E WITH CIRCUMFLEX"),
+    ('\u{eb}', "LATIN SMALL LETTER E WITH DIAERESIS"),
+    ('\u{ec}', "LATIN SMALL LETTER I WITH GRAVE"),
+    ('\u{ed}', "LATIN SMALL LETTER I WITH ACUTE"),
+    ('\u{ee}', "LATIN SMALL LETTER I WITH CIRCUMFLEX"),
+    ('\u{ef}', "LATIN SMALL LETTER I WITH DIAERESIS"),
+    ('\u{f0}', "LATIN SMALL LETTER ETH"),
+    ('\u{f1}', "LATIN SMALL LETTER N WITH TILDE"),
+    ('\u{f2}', "LATIN SMALL LETTER O WITH GRAVE"),
+    ('\u{f3}', "LATIN SMALL LETTER O WITH ACUTE"),
+    ('\u{f4}', "LATIN SMALL LETTER O WITH CIRCUMFLEX"),
+    ('\u{f5}', "LATIN SMALL LETTER O WITH TILDE"),
+    ('\u{f6}', "LATIN SMALL LETTER O WITH DIAERESIS"),
+    ('\u{f7}', "DIVISION SIGN"),
+    ('\u{f8}', "LATIN SMALL LETTER O WITH STROKE"),
+    ('\u{f9}', "LATIN SMALL LETTER U WITH GRAVE"),
+    ('\u{fa}', "LATIN SMALL LETTER U WITH ACUTE"),
+    ('\u{fb}', "LATIN SMALL LETTER U WITH CIRCUMFLEX"),
+    ('\u{fc}', "LATIN SMALL LETTER U WITH DIAERESIS"),
+    ('\u{fd}', "LATIN SMALL LETTER Y WITH ACUTE"),
+    ('\u{fe}', "LATIN SMALL LETTER THORN"),
+    ('\u{ff}', "LATIN SMALL LETTER Y WITH DIAERESIS"),
+    ('\u{400}', "CYRILLIC CAPITAL LETTER IE WITH GRAVE"),
+    ('\u{401}', "CYRILLIC CAPITAL LETTER IO"),
+    ('\u{402}', "CYRILLIC CAPITAL LETTER DJE"),
+    ('\u{403}', "CYRILLIC CAPITAL LETTER GJE"),
+    ('\u{404}', "CYRILLIC CAPITAL LETTER UKRAINIAN IE"),
+    ('\u{405}', "CYRILLIC CAPITAL LETTER DZE"),
+    (
+        '\u{406}',
+        "CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I",
+    ),
+    ('\u{407}', "CYRILLIC CAPITAL LETTER YI"),
+    ('\u{408}', "CYRILLIC CAPITAL LETTER JE"),
+    ('\u{409}', "CYRILLIC CAPITAL LETTER LJE"),
+    ('\u{40a}', "CYRILLIC CAPITAL LETTER NJE"),
+    ('\u{40b}', "CYRILLIC CAPITAL LETTER TSHE"),
+    ('\u{40c}', "CYRILLIC CAPITAL LETTER KJE"),
+    ('\u{40d}', "CYRILLIC CAPITAL LETTER I WITH GRAVE"),
+    ('\u{40e}', "CYRILLIC CAPITAL LETTER SHORT U"),
+    ('\u{40f}', "CYRILLIC CAPITAL LETTER DZHE"),
+    ('\u{410}', "CYRILLIC CAPITAL LETTER A"),
+    ('\u{411}', "CYRILLIC CAPITAL LETTER BE"),
+    ('\u{412}', "CYRILLIC CAPITAL LETTER VE"),
+    ('\u{413}', "CYRILLIC CAPITAL LETTER GHE"),
+    ('\u{414}', "CYRILLIC CAPITAL LETTER DE"),
+    ('\u{415}', "CYRILLIC CAPITAL LETTER IE"),
+    ('\u{416}', "CYRILLIC CAPITAL LETTER ZHE"),
+    ('\u{417}', "CYRILLIC CAPITAL LETTER ZE"),
+    ('\u{418}', "CYRILLIC CAPITAL LETTER I"),
+    ('\u{419}', "CYRILLIC CAPITAL LETTER SHORT I"),
+    ('\u{41a}', "CYRILLIC CAPITAL LETTER KA"),
+    ('\u{41b}', "CYRILLIC CAPITAL LETTER EL"),
+    ('\u{41c}', "CYRILLIC CAPITAL LETTER EM"),
+    ('\u{41d}', "CYRILLIC CAPITAL LETTER EN"),
+    ('\u{41e}', "CYRILLIC CAPITAL LETTER O"),
+    ('\u{41f}', "CYRILLIC CAPITAL LETTER PE"),
+    ('\u{420}', "CYRILLIC CAPITAL LETTER ER"),
+    ('\u{421}', "CYRILLIC CAPITAL LETTER ES"),
+    ('\u{422}', "CYRILLIC CAPITAL LETTER TE"),
+    ('\u{423}', "CYRILLIC CAPITAL LETTER U"),
+    ('\u{424}', "CYRILLIC CAPITAL LETTER EF"),
+    ('\u{425}', "CYRILLIC CAPITAL LETTER HA"),
+    ('\u{426}', "CYRILLIC CAPITAL LETTER TSE"),
+    ('\u{427}', "CYRILLIC CAPITAL LETTER CHE"),
+    ('\u{428}', "CYRILLIC CAPITAL LETTER SHA"),
+    ('\u{429}', "CYRILLIC CAPITAL LETTER SHCHA"),
+    ('\u{42a}', "CYRILLIC CAPITAL LETTER HARD SIGN"),
+    ('\u{42b}', "CYRILLIC CAPITAL LETTER YERU"),
+    ('\u{42c}', "CYRILLIC CAPITAL LETTER SOFT SIGN"),
+    ('\u{42d}', "CYRILLIC CAPITAL LETTER E"),
+    ('\u{42e}', "CYRILLIC CAPITAL LETTER YU"),
+    ('\u{42f}', "CYRILLIC CAPITAL LETTER YA"),
+    ('\u{430}', "CYRILLIC SMALL LETTER A"),
+    ('\u{431}', "CYRILLIC SMALL LETTER BE"),
+    ('\u{432}', "CYRILLIC SMALL LETTER VE"),
+    ('\u{433}', "CYRILLIC SMALL LETTER GHE"),
+    ('\u{434}', "CYRILLIC SMALL LETTER DE"),
+    ('\u{435}', "CYRILLIC SMALL LETTER IE"),
+    ('\u{436}', "CYRILLIC SMALL LETTER ZHE"),
+    ('\u{437}', "CYRILLIC SMALL LETTER ZE"),
+    ('\u{438}', "CYRILLIC SMALL LETTER I"),
+    ('\u{439}', "CYRILLIC SMALL LETTER SHORT I"),
+    ('\u{43a}', "CYRILLIC SMALL LETTER KA"),
+    ('\u{43b}', "CYRILLIC SMALL LETTER EL"),
+    ('\u{43c}', "CYRILLIC SMALL LETTER EM"),
+    ('\u{43d}', "CYRILLIC SMALL LETTER EN"),
+    ('\u{43e}', "CYRILLIC SMALL LETTER O"),
+    ('\u{43f}', "CYRILLIC SMALL LETTER PE"),
+    ('\u{440}', "CYRILLIC SMALL LETTER ER"),
+    ('\u{441}', "CYRILLIC SMALL LETTER ES"),
+    ('\u{442}', "CYRILLIC SMALL LETTER TE"),
+    ('\u{443}', "CYRILLIC SMALL LETTER U"),
+    ('\u{444}', "CYRILLIC SMALL LETTER EF"),
+    ('\u{445}', "CYRILLIC SMALL LETTER HA"),
+    ('\u{446}', "CYRILLIC SMALL LETTER TSE"),
+    ('\u{447}', "CYRILLIC SMALL LETTER CHE"),
+    ('\u{448}', "CYRILLIC SMALL LETTER SHA"),
+    ('\u{449}', "CYRILLIC SMALL LETTER SHCHA"),
+    ('\u{44a}', "CYRILLIC SMALL LETTER HARD SIGN"),
+    ('\u{44b}', "CYRILLIC SMALL LETTER YERU"),
+    ('\u{44c}', "CYRILLIC SMALL LETTER SOFT SIGN"),
+    ('\u{44d}', "CYRILLIC SMALL LETTER E"),
+    ('\u{44e}', "CYRILLIC SMALL LETTER YU"),
+    ('\u{44f}', "CYRILLIC SMALL LETTER YA"),
+    ('\u{450}', "CYRILLIC SMALL LETTER IE WITH GRAVE"),
+    ('\u{451}', "CYRILLIC SMALL LETTER IO"),
+    ('\u{452}', "CYRILLIC SMALL LETTER DJE"),
+    ('\u{453}', "CYRILLIC SMALL LETTER GJE"),
+    ('\u{454}', "CYRILLIC SMALL LETTER UKRAINIAN IE"),
+    ('\u{455}', "CYRILLIC SMALL LETTER DZE"),
+    ('\u{456}', "CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I"),
+    ('\u{457}', "CYRILLIC SMALL LETTER YI"),
+    ('\u{458}', "CYRILLIC SMALL LETTER JE"),
+    ('\u{459}', "CYRILLIC SMALL LETTER LJE"),
+    ('\u{45a}', "CYRILLIC SMALL LETTER NJE"),
+    ('\u{45b}', "CYRILLIC SMALL LETTER TSHE"),
+    ('\u{45c}', "CYRILLIC SMALL LETTER KJE"),
+    ('\u{45d}', "CYRILLIC SMALL LETTER I WITH GRAVE"),
+    ('\u{45e}', "CYRILLIC SMALL LETTER SHORT U"),
+    ('\u{45f}', "CYRILLIC SMALL LETTER DZHE"),
+    ('\u{460}', "CYRILLIC CAPITAL LETTER OMEGA"),
+    ('\u{461}', "CYRILLIC SMALL LETTER OMEGA"),
+    ('\u{462}', "CYRILLIC CAPITAL LETTER YAT"),
+    ('\u{463}', "CYRILLIC SMALL LETTER YAT"),
+    ('\u{464}', "CYRILLIC CAPITAL LETTER IOTIFIED E"),
+    ('\u{465}', "CYRILLIC SMALL LETTER IOTIFIED E"),
+    ('\u{466}', "CYRILLIC CAPITAL LETTER LITTLE YUS"),
+    ('\u{467}', "CYRILLIC SMALL LETTER LITTLE YUS"),
+    ('\u{468}', "CYRILLIC CAPITAL LETTER IOTIFIED LITTLE YUS"),
+    ('\u{469}', "CYRILLIC SMALL LETTER IOTIFIED LITTLE YUS"),
+    ('\u{46a}', "CYRILLIC CAPITAL LETTER BIG YUS"),
+    ('\u{46b}', "CYRILLIC SMALL LETTER BIG YUS"),
+    ('\u{46c}', "CYRILLIC CAPITAL LETTER IOTIFIED BIG YUS"),
+    ('\u{46d}', "CYRILLIC SMALL LETTER IOTIFIED BIG YUS"),
+    ('\u{46e}', "CYRILLIC CAPITAL LETTER KSI"),
+    ('\u{46f}', "CYRILLIC SMALL LETTER KSI"),
+    ('\u{470}', "CYRILLIC CAPITAL LETTER PSI"),
+    ('\u{471}', "CYRILLIC SMALL LETTER PSI"),
+    ('\u{472}', "CYRILLIC CAPITAL LETTER FITA"),
+    ('\u{473}', "CYRILLIC SMALL LETTER FITA"),
+    ('\u{474}', "CYRILLIC CAPITAL LETTER IZHITSA"),
+    ('\u{475}', "CYRILLIC SMALL LETTER IZHITSA"),
+    (
+        '\u{476}',
+        "CYRILLIC CAPITAL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT",
+    ),
+    (
+        '\u{477}',
+        "CYRILLIC SMALL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT",
+    ),
+    ('\u{478}', "CYRILLIC CAPITAL LETTER UK"),
+    ('\u{479}', "CYRILLIC SMALL LETTER UK"),
+    ('\u{47a}', "CYRILLIC CAPITAL LETTER ROUND OMEGA"),
+    ('\u{47b}', "CYRILLIC SMALL LETTER ROUND OMEGA"),
+    ('\u{47c}', "CYRILLIC CAPITAL LETTER OMEGA WITH TITLO"),
+    ('\u{47d}', "CYRILLIC SMALL LETTER OMEGA WITH TITLO"),
+    ('\u{47e}', "CYRILLIC CAPITAL LETTER OT"),
+    ('\u{47f}', "CYRILLIC SMALL LETTER OT"),
+    ('\u{480}', "CYRILLIC CAPITAL LETTER KOPPA"),
+    ('\u{481}', "CYRILLIC SMALL LETTER KOPPA"),
+    ('\u{482}', "CYRILLIC THOUSANDS SIGN"),
+    ('\u{483}', "COMBINING CYRILLIC TITLO"),
+    ('\u{484}', "COMBINING CYRILLIC PALATALIZATION"),
+    ('\u{485}', "COMBINING CYRILLIC DASIA PNEUMATA"),
+    ('\u{486}', "COMBINING CYRILLIC PSILI PNEUMATA"),
+    ('\u{487}', "COMBINING CYRILLIC POKRYTIE"),
+    ('\u{488}', "COMBINING CYRILLIC HUNDRED THOUSANDS SIGN"),
+    ('\u{489}', "COMBINING CYRILLIC MILLIONS SIGN"),
+    ('\u{48a}', "CYRILLIC CAPITAL LETTER SHORT I WITH TAIL"),
+    ('\u{48b}', "CYRILLIC SMALL LETTER SHORT I WITH TAIL"),
+    ('\u{48c}', "CYRILLIC CAPITAL LETTER SEMISOFT SIGN"),
+    ('\u{48d}', "CYRILLIC SMALL LETTER SEMISOFT SIGN"),
+    ('\u{48e}', "CYRILLIC CAPITAL LETTER ER WITH TICK"),
+    ('\u{48f}', "CYRILLIC SMALL LETTER ER WITH TICK"),
+    ('\u{490}', "CYRILLIC CAPITAL LETTER GHE WITH UPTURN"),
+    ('\u{491}', "CYRILLIC SMALL LETTER GHE WITH UPTURN"),
+    ('\u{492}', "CYRILLIC CAPITAL LETTER GHE WITH STROKE"),
+    ('\u{493}', "CYRILLIC SMALL LETTER GHE WITH STROKE"),
+    ('\u{494}', "CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK"),
+    ('\u{495}', "CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK"),
+    ('\u{496}', "CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER"),
+    ('\u{497}', "CYRILLIC SMALL LETTER ZHE WITH DESCENDER"),
+    ('\u{498}', "CYRILLIC CAPITAL LETTER ZE WITH DESCENDER"),
+    ('\u{499}', "CYRILLIC SMALL LETTER ZE WITH DESCENDER"),
+    ('\u{49a}', "CYRILLIC CAPITAL LETTER KA WITH DESCENDER"),
+    ('\u{49b}', "CYRILLIC SMALL LETTER KA WITH DESCENDER"),
+    ('\u{49c}', "CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE"),
+    ('\u{49d}', "CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE"),
+    ('\u{49e}', "CYRILLIC CAPITAL LETTER KA WITH STROKE"),
+    ('\u{49f}', "CYRILLIC SMALL LETTER KA WITH STROKE"),
+    ('\u{4a0}', "CYRILLIC CAPITAL LETTER BASHKIR KA"),
+    ('\u{4a1}', "CYRILLIC SMALL LETTER BASHKIR KA"),
+    ('\u{4a2}', "CYRILLIC CAPITAL LETTER EN WITH DESCENDER"),
+    ('\u{4a3}', "CYRILLIC SMALL LETTER EN WITH DESCENDER"),
+    ('\u{4a4}', "CYRILLIC CAPITAL LIGATURE EN GHE"),
+    ('\u{4a5}', "CYRILLIC SMALL LIGATURE EN GHE"),
+    ('\u{4a6}', "CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK"),
+    ('\u{4a7}', "CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK"),
+    ('\u{4a8}', "CYRILLIC CAPITAL LETTER ABKHASIAN HA"),
+    ('\u{4a9}', "CYRILLIC SMALL LETTER ABKHASIAN HA"),
+    ('\u{4aa}', "CYRILLIC CAPITAL LETTER ES WITH DESCENDER"),
+    ('\u{4ab}', "CYRILLIC SMALL LETTER ES WITH DESCENDER"),
+    ('\u{4ac}', "CYRILLIC CAPITAL LETTER TE WITH DESCENDER"),
+    ('\u{4ad}', "CYRILLIC SMALL LETTER TE WITH DESCENDER"),
+    ('\u{4ae}', "CYRILLIC CAPITAL LETTER STRAIGHT U"),
+    ('\u{4af}', "CYRILLIC SMALL LETTER STRAIGHT U"),
+    ('\u{4b0}', "CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE"),
+    ('\u{4b1}', "CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE"),
+    ('\u{4b2}', "CYRILLIC CAPITAL LETTER HA WITH DESCENDER"),
+    ('\u{4b3}', "CYRILLIC SMALL LETTER HA WITH DESCENDER"),
+    ('\u{4b4}', "CYRILLIC CAPITAL LIGATURE TE TSE"),
+    ('\u{4b5}', "CYRILLIC SMALL LIGATURE TE TSE"),
+    ('\u{4b6}', "CYRILLIC CAPITAL LETTER CHE WITH DESCENDER"),
+    ('\u{4b7}', "CYRILLIC SMALL LETTER CHE WITH DESCENDER"),
+    (
+        '\u{4b8}',
+        "CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE",
+    ),
+    ('\u{4b9}', "CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE"),
+    ('\u{4ba}', "CYRILLIC CAPITAL LETTER SHHA"),
+    ('\u{4bb}', "CYRILLIC SMALL LETTER SHHA"),
+    ('\u{4bc}', "CYRILLIC CAPITAL LETTER ABKHASIAN CHE"),
+    ('\u{4bd}', "CYRILLIC SMALL LETTER ABKHASIAN CHE"),
+    (
+        '\u{4be}',
+        "CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER",
+    ),
+    (
+        '\u{4bf}',
+        "CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER",
+    ),
+    ('\u{4c0}', "CYRILLIC LETTER PALOCHKA"),
+    ('\u{4c1}', "CYRILLIC CAPITAL LETTER ZHE WITH BREVE"),
+    ('\u{4c2}', "CYRILLIC SMALL LETTER ZHE WITH BREVE"),
+    ('\u{4c3}', "CYRILLIC CAPITAL LETTER KA WITH HOOK"),
+    ('\u{4c4}', "CYRILLIC SMALL LETTER KA WITH HOOK"),
+    ('\u{4c5}', "CYRILLIC CAPITAL LETTER EL WITH TAIL"),
+    ('\u{4c6}', "CYRILLIC SMALL LETTER EL WITH TAIL"),
+    ('\u{4c7}', "CYRILLIC CAPITAL LETTER EN WITH HOOK"),
+    ('\u{4c8}', "CYRILLIC SMALL LETTER EN WITH HOOK"),
+    ('\u{4c9}', "CYRILLIC CAPITAL LETTER EN WITH TAIL"),
+    ('\u{4ca}', "CYRILLIC SMALL LETTER EN WITH TAIL"),
+    ('\u{4cb}', "CYRILLIC CAPITAL LETTER KHAKASSIAN CHE"),
+    ('\u{4cc}', "CYRILLIC SMALL LETTER KHAKASSIAN CHE"),
+    ('\u{4cd}', "CYRILLIC CAPITAL LETTER EM WITH TAIL"),
+    ('\u{4ce}', "CYRILLIC SMALL LETTER EM WITH TAIL"),
+    ('\u{4cf}', "CYRILLIC SMALL LETTER PALOCHKA"),
+    ('\u{4d0}', "CYRILLIC CAPITAL LETTER A WITH BREVE"),
+    ('\u{4d1}', "CYRILLIC SMALL LETTER A WITH BREVE"),
+    ('\u{4d2}', "CYRILLIC CAPITAL LETTER A WITH DIAERESIS"),
+    ('\u{4d3}', "CYRILLIC SMALL LETTER A WITH DIAERESIS"),
+    ('\u{4d4}', "CYRILLIC CAPITAL LIGATURE A IE"),
+    ('\u{4d5}', "CYRILLIC SMALL LIGATURE A IE"),
+    ('\u{4d6}', "CYRILLIC CAPITAL LETTER IE WITH BREVE"),
+    ('\u{4d7}', "CYRILLIC SMALL LETTER IE WITH BREVE"),
+    ('\u{4d8}', "CYRILLIC CAPITAL LETTER SCHWA"),
+    ('\u{4d9}', "CYRILLIC SMALL LETTER SCHWA"),
+    ('\u{4da}', "CYRILLIC CAPITAL LETTER SCHWA WITH DIAERESIS"),
+    ('\u{4db}', "CYRILLIC SMALL LETTER SCHWA WITH DIAERESIS"),
+    ('\u{4dc}', "CYRILLIC CAPITAL LETTER ZHE WITH DIAERESIS"),
+    ('\u{4dd}', "CYRILLIC SMALL LETTER ZHE WITH DIAERESIS"),
+    ('\u{4de}', "CYRILLIC CAPITAL LETTER ZE WITH DIAERESIS"),
+    ('\u{4df}', "CYRILLIC SMALL LETTER ZE WITH DIAERESIS"),
+    ('\u{4e0}', "CYRILLIC CAPITAL LETTER ABKHASIAN DZE"),
+    ('\u{4e1}', "CYRILLIC SMALL LETTER ABKHASIAN DZE"),
+    ('\u{4e2}', "CYRILLIC CAPITAL LETTER I WITH MACRON"),
+    ('\u{4e3}', "CYRILLIC SMALL LETTER I WITH MACRON"),
+    ('\u{4e4}', "CYRILLIC CAPITAL LETTER I WITH DIAERESIS"),
+    ('\u{4e5}', "CYRILLIC SMALL LETTER I WITH DIAERESIS"),
+    ('\u{4e6}', "CYRILLIC CAPITAL LETTER O WITH DIAERESIS"),
+    ('\u{4e7}', "CYRILLIC SMALL LETTER O WITH DIAERESIS"),
+    ('\u{4e8}', "CYRILLIC CAPITAL LETTER BARRED O"),
+    ('\u{4e9}', "CYRILLIC SMALL LETTER BARRED O"),
+    ('\u{4ea}', "CYRILLIC CAPITAL LETTER BARRED O WITH DIAERESIS"),
+    ('\u{4eb}', "CYRILLIC SMALL LETTER BARRED O WITH DIAERESIS"),
+    ('\u{4ec}', "CYRILLIC CAPITAL LETTER E WITH DIAERESIS"),
+    ('\u{4ed}', "CYRILLIC SMALL LETTER E WITH DIAERESIS"),
+    ('\u{4ee}', "CYRILLIC CAPITAL LETTER U WITH MACRON"),
+    ('\u{4ef}', "CYRILLIC SMALL LETTER U WITH MACRON"),
+    ('\u{4f0}', "CYRILLIC CAPITAL LETTER U WITH DIAERESIS"),
+    ('\u{4f1}', "CYRILLIC SMALL LETTER U WITH DIAERESIS"),
+    ('\u{4f2}', "CYRILLIC CAPITAL LETTER U WITH DOUBLE ACUTE"),
+    ('\u{4f3}', "CYRILLIC SMALL LETTER U WITH DOUBLE ACUTE"),
+    ('\u{4f4}', "CYRILLIC CAPITAL LETTER CHE WITH DIAERESIS"),
+    ('\u{4f5}', "CYRILLIC SMALL LETTER CHE WITH DIAERESIS"),
+    ('\u{4f6}', "CYRILLIC CAPITAL LETTER GHE WITH DESCENDER"),
+    ('\u{4f7}', "CYRILLIC SMALL LETTER GHE WITH DESCENDER"),
+    ('\u{4f8}', "CYRILLIC CAPITAL LETTER YERU WITH DIAERESIS"),
+    ('\u{4f9}', "CYRILLIC SMALL LETTER YERU WITH DIAERESIS"),
+    (
+        '\u{4fa}',
+        "CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK",
+    ),
+    ('\u{4fb}', "CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK"),
+    ('\u{4fc}', "CYRILLIC CAPITAL LETTER HA WITH HOOK"),
+    ('\u{4fd}', "CYRILLIC SMALL LETTER HA WITH HOOK"),
+    ('\u{4fe}', "CYRILLIC CAPITAL LETTER HA WITH STROKE"),
+    ('\u{4ff}', "CYRILLIC SMALL LETTER HA WITH STROKE"),
+    ('\u{900}', "DEVANAGARI SIGN INVERTED CANDRABINDU"),
+    ('\u{901}', "DEVANAGARI SIGN CANDRABINDU"),
+    ('\u{902}', "DEVANAGARI SIGN ANUSVARA"),
+    ('\u{903}', "DEVANAGARI SIGN VISARGA"),
+    ('\u{904}', "DEVANAGARI LETTER SHORT A"),
+    ('\u{905}', "DEVANAGARI LETTER A"),
+    ('\u{906}', "DEVANAGARI LETTER AA"),
+    ('\u{907}', "DEVANAGARI LETTER I"),
+    ('\u{908}', "DEVANAGARI LETTER II"),
+    ('\u{909}', "DEVANAGARI LETTER U"),
+    ('\u{90a}', "DEVANAGARI LETTER UU"),
+    ('\u{90b}', "DEVANAGARI LETTER VOCALIC R"),
+    ('\u{90c}', "DEVANAGARI LETTER VOCALIC L"),
+    ('\u{90d}', "DEVANAGARI LETTER CANDRA E"),
+    ('\u{90e}', "DEVANAGARI LETTER SHORT E"),
+    ('\u{90f}', "DEVANAGARI LETTER E"),
+    ('\u{910}', "DEVANAGARI LETTER AI"),
+    ('\u{911}', "DEVANAGARI LETTER CANDRA O"),
+    ('\u{912}', "DEVANAGARI LETTER SHORT O"),
+    ('\u{913}', "DEVANAGARI LETTER O"),
+    ('\u{914}', "DEVANAGARI LETTER AU"),
+    ('\u{915}', "DEVANAGARI LETTER KA"),
+    ('\u{916}', "DEVANAGARI LETTER KHA"),
+    ('\u{917}', "DEVANAGARI LETTER GA"),
+    ('\u{918}', "DEVANAGARI LETTER GHA"),
+    ('\u{919}', "DEVANAGARI LETTER NGA"),
+    ('\u{91a}', "DEVANAGARI LETTER CA"),
+    ('\u{91b}', "DEVANAGARI LETTER CHA"),
+    ('\u{91c}', "DEVANAGARI LETTER JA"),
+    ('\u{91d}', "DEVANAGARI LETTER JHA"),
+    ('\u{91e}', "DEVANAGARI LETTER NYA"),
+    ('\u{91f}', "DEVANAGARI LETTER TTA"),
+    ('\u{920}', "DEVANAGARI LETTER TTHA"),
+    ('\u{921}', "DEVANAGARI LETTER DDA"),
+    ('\u{922}', "DEVANAGARI LETTER DDHA"),
+    ('\u{923}', "DEVANAGARI LETTER NNA"),
+    ('\u{924}', "DEVANAGARI LETTER TA"),
+    ('\u{925}', "DEVANAGARI LETTER THA"),
+    ('\u{926}', "DEVANAGARI LETTER DA"),
+    ('\u{927}', "DEVANAGARI LETTER DHA"),
+    ('\u{928}', "DEVANAGARI LETTER NA"),
+    ('\u{929}', "DEVANAGARI LETTER NNNA"),
+    ('\u{92a}', "DEVANAGARI LETTER PA"),
+    ('\u{92b}', "DEVANAGARI LETTER PHA"),
+    ('\u{92c}', "DEVANAGARI LETTER BA"),
+    ('\u{92d}', "DEVANAGARI LETTER BHA"),
+    ('\u{92e}', "DEVANAGARI LETTER MA"),
+    ('\u{92f}', "DEVANAGARI LETTER YA"),
+    ('\u{930}', "DEVANAGARI LETTER RA"),
+    ('\u{931}', "DEVANAGARI LETTER RRA"),
+    ('\u{932}', "DEVANAGARI LETTER LA"),
+    ('\u{933}', "DEVANAGARI LETTER LLA"),
+    ('\u{934}', "DEVANAGARI LETTER LLLA"),
+    ('\u{935}', "DEVANAGARI LETTER VA"),
+    ('\u{936}', "DEVANAGARI LETTER SHA"),
+    ('\u{937}', "DEVANAGARI LETTER SSA"),
+    ('\u{938}', "DEVANAGARI LETTER SA"),
+    ('\u{939}', "DEVANAGARI LETTER HA"),
+    ('\u{93a}', "DEVANAGARI VOWEL SIGN OE"),
+    ('\u{93b}', "DEVANAGARI VOWEL SIGN OOE"),
+    ('\u{93c}', "DEVANAGARI SIGN NUKTA"),
+    ('\u{93d}', "DEVANAGARI SIGN AVAGRAHA"),
+    ('\u{93e}', "DEVANAGARI VOWEL SIGN AA"),
+    ('\u{93f}', "DEVANAGARI VOWEL SIGN I"),
+    ('\u{940}', "DEVANAGARI VOWEL SIGN II"),
+    ('\u{941}', "DEVANAGARI VOWEL SIGN U"),
+    ('\u{942}', "DEVANAGARI VOWEL SIGN UU"),
+    ('\u{943}', "DEVANAGARI VOWEL SIGN VOCALIC R"),
+    ('\u{944}', "DEVANAGARI VOWEL SIGN VOCALIC RR"),
+    ('\u{945}', "DEVANAGARI VOWEL SIGN CANDRA E"),
+    ('\u{946}', "DEVANAGARI VOWEL SIGN SHORT E"),
+    ('\u{947}', "DEVANAGARI VOWEL SIGN E"),
+    ('\u{948}', "DEVANAGARI VOWEL SIGN AI"),
+    ('\u{949}', "DEVANAGARI VOWEL SIGN CANDRA O"),
+    ('\u{94a}', "DEVANAGARI VOWEL SIGN SHORT O"),
+    ('\u{94b}', "DEVANAGARI VOWEL SIGN O"),
+    ('\u{94c}', "DEVANAGARI VOWEL SIGN AU"),
+    ('\u{94d}', "DEVANAGARI SIGN VIRAMA"),
+    ('\u{94e}', "DEVANAGARI VOWEL SIGN PRISHTHAMATRA E"),
+    ('\u{94f}', "DEVANAGARI VOWEL SIGN AW"),
+    ('\u{950}', "DEVANAGARI OM"),
+    ('\u{951}', "DEVANAGARI STRESS SIGN UDATTA"),
+    ('\u{952}', "DEVANAGARI STRESS SIGN ANUDATTA"),
+    ('\u{953}', "DEVANAGARI GRAVE ACCENT"),
+    ('\u{954}', "DEVANAGARI ACUTE ACCENT"),
+    ('\u{955}', "DEVANAGARI VOWEL SIGN CANDRA LONG E"),
+    ('\u{956}', "DEVANAGARI VOWEL SIGN UE"),
+    ('\u{957}', "DEVANAGARI VOWEL SIGN UUE"),
+    ('\u{958}', "DEVANAGARI LETTER QA"),
+    ('\u{959}', "DEVANAGARI LETTER KHHA"),
+    ('\u{95a}', "DEVANAGARI LETTER GHHA"),
+    ('\u{95b}', "DEVANAGARI LETTER ZA"),
+    ('\u{95c}', "DEVANAGARI LETTER DDDHA"),
+    ('\u{95d}', "DEVANAGARI LETTER RHA"),
+    ('\u{95e}', "DEVANAGARI LETTER FA"),
+    ('\u{95f}', "DEVANAGARI LETTER YYA"),
+    ('\u{960}', "DEVANAGARI LETTER VOCALIC RR"),
+    ('\u{961}', "DEVANAGARI LETTER VOCALIC LL"),
+    ('\u{962}', "DEVANAGARI VOWEL SIGN VOCALIC L"),
+    ('\u{963}', "DEVANAGARI VOWEL SIGN VOCALIC LL"),
+    ('\u{964}', "DEVANAGARI DANDA"),
+    ('\u{965}', "DEVANAGARI DOUBLE DANDA"),
+    ('\u{966}', "DEVANAGARI DIGIT ZERO"),
+    ('\u{967}', "DEVANAGARI DIGIT ONE"),
+    ('\u{968}', "DEVANAGARI DIGIT TWO"),
+    ('\u{969}', "DEVANAGARI DIGIT THREE"),
+    ('\u{96a}', "DEVANAGARI DIGIT FOUR"),
+    ('\u{96b}', "DEVANAGARI DIGIT FIVE"),
+    ('\u{96c}', "DEVANAGARI DIGIT SIX"),
+    ('\u{96d}', "DEVANAGARI DIGIT SEVEN"),
+    ('\u{96e}', "DEVANAGARI DIGIT EIGHT"),
+    ('\u{96f}', "DEVANAGARI DIGIT NINE"),
+    ('\u{970}', "DEVANAGARI ABBREVIATION SIGN"),
+    ('\u{971}', "DEVANAGARI SIGN HIGH SPACING DOT"),
+    ('\u{972}', "DEVANAGARI LETTER CANDRA A"),
+    ('\u{973}', "DEVANAGARI LETTER OE"),
+    ('\u{974}', "DEVANAGARI LETTER OOE"),
+    ('\u{975}', "DEVANAGARI LETTER AW"),
+    ('\u{976}', "DEVANAGARI LETTER UE"),
+    ('\u{977}', "DEVANAGARI LETTER UUE"),
+    ('\u{978}', "DEVANAGARI LETTER MARWARI DDA"),
+    ('\u{979}', "DEVANAGARI LETTER ZHA"),
+    ('\u{97a}', "DEVANAGARI LETTER HEAVY YA"),
+    ('\u{97b}', "DEVANAGARI LETTER GGA"),
+    ('\u{97c}', "DEVANAGARI LETTER JJA"),
+    ('\u{97d}', "DEVANAGARI LETTER GLOTTAL STOP"),
+    ('\u{97e}', "DEVANAGARI LETTER DDDA"),
+    ('\u{97f}', "DEVANAGARI LETTER BBA"),
+    ('\u{2000}', "EN QUAD"),
+    ('\u{2001}', "EM QUAD"),
+    ('\u{2002}', "EN SPACE"),
+    ('\u{2003}', "EM SPACE"),
+    ('\u{2004}', "THREE-PER-EM SPACE"),
+    ('\u{2005}', "FOUR-PER-EM SPACE"),
+    ('\u{2006}', "SIX-PER-EM SPACE"),
+    ('\u{2007}', "FIGURE SPACE"),
+    ('\u{2008}', "PUNCTUATION SPACE"),
+    ('\u{2009}', "THIN SPACE"),
+    ('\u{200a}', "HAIR SPACE"),
+    ('\u{200b}', "ZERO WIDTH SPACE"),
+    ('\u{200c}', "ZERO WIDTH NON-JOINER"),
+    ('\u{200d}', "ZERO WIDTH JOINER"),
+    ('\u{200e}', "LEFT-TO-RIGHT MARK"),
+    ('\u{200f}', "RIGHT-TO-LEFT MARK"),
+    ('\u{2010}', "HYPHEN"),
+    ('\u{2011}', "NON-BREAKING HYPHEN"),
+    ('\u{2012}', "FIGURE DASH"),
+    ('\u{2013}', "EN DASH"),
+    ('\u{2014}', "EM DASH"),
+    ('\u{2015}', "HORIZONTAL BAR"),
+    ('\u{2016}', "DOUBLE VERTICAL LINE"),
+    ('\u{2017}', "DOUBLE LOW LINE"),
+    ('\u{2018}', "LEFT SINGLE QUOTATION MARK"),
+    ('\u{2019}', "RIGHT SINGLE QUOTATION MARK"),
+    ('\u{201a}', "SINGLE LOW-9 QUOTATION MARK"),
+    ('\u{201b}', "SINGLE HIGH-REVERSED-9 QUOTATION MARK"),
+    ('\u{201c}', "LEFT DOUBLE QUOTATION MARK"),
+    ('\u{201d}', "RIGHT DOUBLE QUOTATION MARK"),
+    ('\u{201e}', "DOUBLE LOW-9 QUOTATION MARK"),
+    ('\u{201f}', "DOUBLE HIGH-REVERSED-9 QUOTATION MARK"),
+    ('\u{2020}', "DAGGER"),
+    ('\u{2021}', "DOUBLE DAGGER"),
+    ('\u{2022}', "BULLET"),
+    ('\u{2023}', "TRIANGULAR BULLET"),
+    ('\u{2024}', "ONE DOT LEADER"),
+    ('\u{2025}', "TWO DOT LEADER"),
+    ('\u{2026}', "HORIZONTAL ELLIPSIS"),
+    ('\u{2027}', "HYPHENATION POINT"),
+    ('\u{2028}', "LINE SEPARATOR"),
+    ('\u{2029}', "PARAGRAPH SEPARATOR"),
+    ('\u{202a}', "LEFT-TO-RIGHT EMBEDDING"),
+    ('\u{202b}', "RIGHT-TO-LEFT EMBEDDING"),
+    ('\u{202c}', "POP DIRECTIONAL FORMATTING"),
+    ('\u{202d}', "LEFT-TO-RIGHT OVERRIDE"),
+    ('\u{202e}', "RIGHT-TO-LEFT OVERRIDE"),
+    ('\u{202f}', "NARROW NO-BREAK SPACE"),
+    ('\u{2030}', "PER MILLE SIGN"),
+    ('\u{2031}', "PER TEN THOUSAND SIGN"),
+    ('\u{2032}', "PRIME"),
+    ('\u{2033}', "DOUBLE PRIME"),
+    ('\u{2034}', "TRIPLE PRIME"),
+    ('\u{2035}', "REVERSED PRIME"),
+    ('\u{2036}', "REVERSED DOUBLE PRIME"),
+    ('\u{2037}', "REVERSED TRIPLE PRIME"),
+    ('\u{2038}', "CARET"),
+    ('\u{2039}', "SINGLE LEFT-POINTING ANGLE QUOTATION MARK"),
+    ('\u{203a}', "SINGLE RIGHT-POINTING ANGLE QUOTATION MARK"),
+    ('\u{203b}', "REFERENCE MARK"),
+    ('\u{203c}', "DOUBLE EXCLAMATION MARK"),
+    ('\u{203d}', "INTERROBANG"),
+    ('\u{203e}', "OVERLINE"),
+    ('\u{203f}', "UNDERTIE"),
+    ('\u{2040}', "CHARACTER TIE"),
+    ('\u{2041}', "CARET INSERTION POINT"),
+    ('\u{2042}', "ASTERISM"),
+    ('\u{2043}', "HYPHEN BULLET"),
+    ('\u{2044}', "FRACTION SLASH"),
+    ('\u{2045}', "LEFT SQUARE BRACKET WITH QUILL"),
+    ('\u{2046}', "RIGHT SQUARE BRACKET WITH QUILL"),
+    ('\u{2047}', "DOUBLE QUESTION MARK"),
+    ('\u{2048}', "QUESTION EXCLAMATION MARK"),
+    ('\u{2049}', "EXCLAMATION QUESTION MARK"),
+    ('\u{204a}', "TIRONIAN SIGN ET"),
+    ('\u{204b}', "REVERSED PILCROW SIGN"),
+    ('\u{204c}', "BLACK LEFTWARDS BULLET"),
+    ('\u{204d}', "BLACK RIGHTWARDS BULLET"),
+    ('\u{204e}', "LOW ASTERISK"),
+    ('\u{204f}', "REVERSED SEMICOLON"),
+    ('\u{2050}', "CLOSE UP"),
+    ('\u{2051}', "TWO ASTERISKS ALIGNED VERTICALLY"),
+    ('\u{2052}', "COMMERCIAL MINUS SIGN"),
+    ('\u{2053}', "SWUNG DASH"),
+    ('\u{2054}', "INVERTED UNDERTIE"),
+    ('\u{2055}', "FLOWER PUNCTUATION MARK"),
+    ('\u{2056}', "THREE DOT PUNCTUATION"),
+    ('\u{2057}', "QUADRUPLE PRIME"),
+    ('\u{2058}', "FOUR DOT PUNCTUATION"),
+    ('\u{2059}', "FIVE DOT PUNCTUATION"),
+    ('\u{205a}', "TWO DOT PUNCTUATION"),
+    ('\u{205b}', "FOUR DOT MARK"),
+    ('\u{205c}', "DOTTED CROSS"),
+    ('\u{205d}', "TRICOLON"),
+    ('\u{205e}', "VERTICAL FOUR DOTS"),
+    ('\u{205f}', "MEDIUM MATHEMATICAL SPACE"),
+    ('\u{2060}', "WORD JOINER"),
+    ('\u{2061}', "FUNCTION APPLICATION"),
+    ('\u{2062}', "INVISIBLE TIMES"),
+    ('\u{2063}', "INVISIBLE SEPARATOR"),
+    ('\u{2064}', "INVISIBLE PLUS"),
+    ('\u{2066}', "LEFT-TO-RIGHT ISOLATE"),
+    ('\u{2067}', "RIGHT-TO-LEFT ISOLATE"),
+    ('\u{2068}', "FIRST STRONG ISOLATE"),
+    ('\u{2069}', "POP DIRECTIONAL ISOLATE"),
+    ('\u{206a}', "INHIBIT SYMMETRIC SWAPPING"),
+    ('\u{206b}', "ACTIVATE SYMMETRIC SWAPPING"),
+    ('\u{206c}', "INHIBIT ARABIC FORM SHAPING"),
+    ('\u{206d}', "ACTIVATE ARABIC FORM SHAPING"),
+    ('\u{206e}', "NATIONAL DIGIT SHAPES"),
+    ('\u{206f}', "NOMINAL DIGIT SHAPES"),
+    ('\u{20ac}', "EURO SIGN"),
+    ('\u{1f1e6}', "REGIONAL INDICATOR SYMBOL LETTER A"),
+    ('\u{1f1e7}', "REGIONAL INDICATOR SYMBOL LETTER B"),
+    ('\u{1f1e8}', "REGIONAL INDICATOR SYMBOL LETTER C"),
+    ('\u{1f1e9}', "REGIONAL INDICATOR SYMBOL LETTER D"),
+    ('\u{1f1ea}', "REGIONAL INDICATOR SYMBOL LETTER E"),
+    ('\u{1f1eb}', "REGIONAL INDICATOR SYMBOL LETTER F"),
+    ('\u{1f1ec}', "REGIONAL INDICATOR SYMBOL LETTER G"),
+    ('\u{1f1ed}', "REGIONAL INDICATOR SYMBOL LETTER H"),
+    ('\u{1f1ee}', "REGIONAL INDICATOR SYMBOL LETTER I"),
+    ('\u{1f1ef}', "REGIONAL INDICATOR SYMBOL LETTER J"),
+    ('\u{1f1f0}', "REGIONAL INDICATOR SYMBOL LETTER K"),
+    ('\u{1f1f1}', "REGIONAL INDICATOR SYMBOL LETTER L"),
+    ('\u{1f1f2}', "REGIONAL INDICATOR SYMBOL LETTER M"),
+    ('\u{1f1f3}', "REGIONAL INDICATOR SYMBOL LETTER N"),
+    ('\u{1f1f4}', "REGIONAL INDICATOR SYMBOL LETTER O"),
+    ('\u{1f1f5}', "REGIONAL INDICATOR SYMBOL LETTER P"),
+    ('\u{1f1f6}', "REGIONAL INDICATOR SYMBOL LETTER Q"),
+    ('\u{1f1f7}', "REGIONAL INDICATOR SYMBOL LETTER R"),
+    ('\u{1f1f8}', "REGIONAL INDICATOR SYMBOL LETTER S"),
+    ('\u{1f1f9}', "REGIONAL INDICATOR SYMBOL LETTER T"),
+    ('\u{1f1fa}', "REGIONAL INDICATOR SYMBOL LETTER U"),
+    ('\u{1f1fb}', "REGIONAL INDICATOR SYMBOL LETTER V"),
+    ('\u{1f1fc}', "REGIONAL INDICATOR SYMBOL LETTER W"),
+    ('\u{1f1fd}', "REGIONAL INDICATOR SYMBOL LETTER X"),
+    ('\u{1f1fe}', "REGIONAL INDICATOR SYMBOL LETTER Y"),
+    ('\u{1f1ff}', "REGIONAL INDICATOR SYMBOL LETTER Z"),
+];
@@ -0,0 +1,424 @@
+#![allow(dead_code)]
+use super::wrap::display_width;
+use std::fmt;
+use std::ops::Range;
+
+// rustc-style error reports for our own parsers, so instead of "Please enter valid temperature value." we can say
+// *which* part of the input was wrong:
+//
+// error: invalid temperature value
+//  --> input:1:1
+//   |
+// 1 | 36,6
+//   | ^^^^ expected a number like 36.6
+//   |
+//   = help: use a '.' for the decimal point
+//
+// Spans are byte ranges into the source, like everything else in text::*. Line and column numbers are 1-based and
+// columns count chars (like rustc), while the underlines are lined up by display width so they still sit under
+// the right text for Cyrillic, Devanagari or CJK input.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+            Level::Help => "help",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: Range<usize>,
+    pub message: String,
+    pub primary: bool, // primary labels are underlined with ^^^, secondary ones with ---
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub level: Level,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub helps: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(level: Level, message: &str) -> Self {
+        Self {
+            level,
+            message: message.to_string(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+        }
+    }
+
+    pub fn error(message: &str) -> Self {
+        Self::new(Level::Error, message)
+    }
+
+    pub fn warning(message: &str) -> Self {
+        Self::new(Level::Warning, message)
+    }
+
+    pub fn with_label(mut self, span: Range<usize>, message: &str) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.to_string(),
+            primary: true,
+        });
+        self
+    }
+
+    pub fn with_secondary_label(mut self, span: Range<usize>, message: &str) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.to_string(),
+            primary: false,
+        });
+        self
+    }
+
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    pub fn with_help(mut self, help: &str) -> Self {
+        self.helps.push(help.to_string());
+        self
+    }
+
+    // `name` is what shows up after the arrow, a file name or something like "input"
+    pub fn render(&self, name: &str, source: &str) -> String {
+        let lines = line_starts(source);
+        let mut out = format!("{}: {}\n", self.level, self.message);
+
+        // Labels are clamped to the source and, if they span several lines, to their first line
+        let mut labels: Vec<(usize, Range<usize>, &Label)> = self
+            .labels
+            .iter()
+            .map(|label| {
+                let start = clamp_to_boundary(source, label.span.start);
+                let line = line_of(&lines, start);
+                let line_end = line_content_end(source, &lines, line);
+                let end = clamp_to_boundary(source, label.span.end.max(start)).min(line_end);
+                (line, start..end, label)
+            })
+            .collect();
+        labels.sort_by_key(|(line, span, _)| (*line, span.start));
+
+        let max_line = labels
+            .iter()
+            .map(|(line, _, _)| line + 1)
+            .max()
+            .unwrap_or(1);
+        let gutter = " ".repeat(max_line.to_string().len());
+
+        let location = labels
+            .iter()
+            .find(|(_, _, label)| label.primary)
+            .or(labels.first());
+        match location {
+            Some((line, span, _)) => {
+                let column = source[lines[*line]..span.start].chars().count() + 1;
+                out.push_str(&format!("{gutter}--> {name}:{}:{column}\n", line + 1));
+            }
+            None => out.push_str(&format!("{gutter}--> {name}\n")),
+        }
+
+        if !labels.is_empty() {
+            out.push_str(&format!("{gutter} |\n"));
+        }
+        let mut previous_line: Option<usize> = None;
+        let mut i = 0;
+        while i < labels.len() {
+            let line = labels[i].0;
+            let same_line: Vec<&(usize, Range<usize>, &Label)> = labels[i..]
+                .iter()
+                .take_while(|(l, _, _)| *l == line)
+                .collect();
+            i += same_line.len();
+
+            if previous_line.is_some_and(|prev| line > prev + 1) {
+                out.push_str("...\n");
+            }
+            previous_line = Some(line);
+
+            let text = &source[lines[line]..line_content_end(source, &lines, line)];
+            out.push_str(&format!(
+                "{:>width$} | {}\n",
+                line + 1,
+                expand_tabs(text),
+                width = gutter.len()
+            ));
+            for row in underline_rows(source, lines[line], &same_line) {
+                out.push_str(&format!("{gutter} | {}\n", row.trim_end()));
+            }
+        }
+
+        if !self.notes.is_empty() || !self.helps.is_empty() {
+            if !labels.is_empty() {
+                out.push_str(&format!("{gutter} |\n"));
+            }
+            for note in &self.notes {
+                out.push_str(&format!("{gutter} = note: {note}\n"));
+            }
+            for help in &self.helps {
+                out.push_str(&format!("{gutter} = help: {help}\n"));
+            }
+        }
+        out
+    }
+}
+
+// The first row has every underline plus the message of the rightmost label. Every other label gets a "|" hanging
+// down from its start and its message on a row of its own, right to left, like rustc does:
+//
+// 1 | let x = foo + bar;
+//   |         ^^^   --- second
+//   |         |
+//   |         first
+fn underline_rows(
+    source: &str,
+    line_start: usize,
+    labels: &[&(usize, Range<usize>, &Label)],
+) -> Vec<String> {
+    // (column where the underline starts, its width, the label)
+    let marks: Vec<(usize, usize, &Label)> = labels
+        .iter()
+        .map(|(_, span, label)| {
+            let column = text_width(&source[line_start..span.start]);
+            let width = text_width(&source[span.clone()]).max(1);
+            (column, width, *label)
+        })
+        .collect();
+
+    let mut first = String::new();
+    for &(column, width, label) in &marks {
+        pad_to(&mut first, column);
+        let c = if label.primary { '^' } else { '-' };
+        for _ in 0..width {
+            first.push(c);
+        }
+    }
+    let (_, _, last) = marks[marks.len() - 1];
+    if !last.message.is_empty() {
+        first.push(' ');
+        first.push_str(&last.message);
+    }
+
+    let mut rows = vec![first];
+    let hanging: Vec<&(usize, usize, &Label)> = marks[..marks.len() - 1]
+        .iter()
+        .filter(|(_, _, label)| !label.message.is_empty())
+        .collect();
+    for (k, &&(column, _, label)) in hanging.iter().enumerate().rev() {
+        let mut connectors = String::new();
+        for &&(other, _, _) in &hanging[..=k] {
+            pad_to(&mut connectors, other);
+            connectors.push('|');
+        }
+        rows.push(connectors);
+
+        let mut message = String::new();
+        for &&(other, _, _) in &hanging[..k] {
+            pad_to(&mut message, other);
+            message.push('|');
+        }
+        pad_to(&mut message, column);
+        message.push_str(&label.message);
+        rows.push(message);
+    }
+    rows
+}
+
+// Pads with spaces up to `column`, counted in display width
+fn pad_to(row: &mut String, column: usize) {
+    let width = text_width(row);
+    if width < column {
+        row.push_str(&" ".repeat(column - width));
+    }
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', "    ")
+}
+
+fn text_width(text: &str) -> usize {
+    display_width(&expand_tabs(text))
+}
+
+// Byte offset where each line starts
+fn line_starts(source: &str) -> Vec<usize> {
+    let mut starts = vec![0];
+    for (idx, b) in source.bytes().enumerate() {
+        if b == b'\n' {
+            starts.push(idx + 1);
+        }
+    }
+    starts
+}
+
+fn line_of(lines: &[usize], offset: usize) -> usize {
+    match lines.binary_search(&offset) {
+        Ok(line) => line,
+        Err(next) => next - 1,
+    }
+}
+
+// End of the line's text, without the "\n" or "\r\n"
+fn line_content_end(source: &str, lines: &[usize], line: usize) -> usize {
+    let end = lines.get(line + 1).map_or(source.len(), |&next| next - 1);
+    if end > lines[line] && source.as_bytes()[end - 1] == b'\r' {
+        end - 1
+    } else {
+        end
+    }
+}
+
+fn clamp_to_boundary(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+// Where `part` (a slice borrowed from `whole`, e.g. from trim() or split_whitespace()) sits inside `whole`.
+// Passing any other string is a bug, caught in debug builds; release builds still return a span that's in range
+// and on char boundaries, just not a meaningful one.
+pub fn span_of(whole: &str, part: &str) -> Range<usize> {
+    let (outer, inner) = (
+        whole.as_bytes().as_ptr_range(),
+        part.as_bytes().as_ptr_range(),
+    );
+    debug_assert!(
+        outer.start <= inner.start && inner.end <= outer.end,
+        "span_of: {part:?} isn't a slice of {whole:?}"
+    );
+    let start = (inner.start as usize).saturating_sub(outer.start as usize);
+    let start = clamp_to_boundary(whole, start);
+    start..clamp_to_boundary(whole, start + part.len())
+}
+
+pub fn start() {
+    let input = "36,6";
+    let diagnostic = Diagnostic::error("invalid temperature value")
+        .with_label(0..input.len(), "expected a number like 36.6")
+        .with_help("use a '.' for the decimal point");
+    println!("{}", diagnostic.render("input", input));
+
+    let source = "let x = foo + bar;\nlet y = x * 2;\n\nlet привет = y +;";
+    let diagnostic = Diagnostic::error("mismatched types")
+        .with_label(8..11, "expected `i32`")
+        .with_secondary_label(14..17, "found `String`")
+        .with_secondary_label(4..5, "")
+        .with_label(56..57, "expected an expression after `+`")
+        .with_note("`+` needs a right-hand side")
+        .with_help("remove the `+` or add a value after it");
+    println!("{}", diagnostic.render("src/example.rs", source));
+
+    let command = "Add Sally";
+    let name = command.split_whitespace().last().unwrap();
+    let diagnostic = Diagnostic::error("no valid department or name found in the command")
+        .with_label(
+            command.len()..command.len(),
+            "expected `to <department>` here",
+        )
+        .with_secondary_label(span_of(command, name), "this is the only name")
+        .with_help("try `Add Sally to Engineering`");
+    println!("{}", diagnostic.render("command", command));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_of_finds_borrowed_slices() {
+        let line = "  привет мир ";
+        assert_eq!(span_of(line, line.trim()), 2..21);
+        let words: Vec<Range<usize>> = line.split_whitespace().map(|w| span_of(line, w)).collect();
+        assert_eq!(words, [2..14, 15..21]);
+        assert_eq!(span_of(line, &line[line.len()..]), line.len()..line.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "isn't a slice of")]
+    fn span_of_rejects_other_strings() {
+        let other = String::from("мир");
+        span_of("привет мир", &other);
+    }
+
+    #[test]
+    fn single_line_label() {
+        let input = "36,6";
+        let diagnostic = Diagnostic::error("invalid temperature value")
+            .with_label(2..3, "expected a `.`")
+            .with_help("use a '.' for the decimal point");
+        assert_eq!(
+            diagnostic.render("input", input),
+            "error: invalid temperature value\n\
+             \x20--> input:1:3\n\
+             \x20 |\n\
+             1 | 36,6\n\
+             \x20 |   ^ expected a `.`\n\
+             \x20 |\n\
+             \x20 = help: use a '.' for the decimal point\n"
+        );
+    }
+
+    // Underlines line up with what the terminal draws: "名前" is 4 columns, the Cyrillic letters 1 each
+    #[test]
+    fn wide_and_multibyte_spans() {
+        let source = "let 名前 = \"привет\";";
+        let name = 4..10;
+        let value = 13..27;
+        assert_eq!(
+            (&source[name.clone()], &source[value.clone()]),
+            ("名前", "\"привет\"")
+        );
+        let diagnostic = Diagnostic::warning("unused variable")
+            .with_label(name, "never read")
+            .with_secondary_label(value, "assigned here");
+        assert_eq!(
+            diagnostic.render("src/main.rs", source),
+            "warning: unused variable\n\
+             \x20--> src/main.rs:1:5\n\
+             \x20 |\n\
+             1 | let 名前 = \"привет\";\n\
+             \x20 |     ^^^^   -------- assigned here\n\
+             \x20 |     |\n\
+             \x20 |     never read\n"
+        );
+    }
+
+    // An empty span at the very end still gets one caret, just past the last char
+    #[test]
+    fn end_of_input_span() {
+        let source = "let y = x +";
+        let diagnostic =
+            Diagnostic::error("expected expression").with_label(source.len()..source.len(), "here");
+        assert_eq!(
+            diagnostic.render("src/lib.rs", source),
+            "error: expected expression\n\
+             \x20--> src/lib.rs:1:12\n\
+             \x20 |\n\
+             1 | let y = x +\n\
+             \x20 |            ^ here\n"
+        );
+    }
+}
@@ -0,0 +1,284 @@
+#![allow(dead_code)]
+use super::grapheme_tables::{GRAPHEME_CAT_TABLE, INCB_EXTEND_TABLE, INCB_LINKER, UNICODE_VERSION};
+use std::cmp::Ordering;
+use std::ops::Range;
+
+// chap_18_string ends with "Getting grapheme clusters isn't supported by Rust out of the box". This is our own
+// implementation of the extended grapheme cluster rules from UAX #29 (https://www.unicode.org/reports/tr29/),
+// so that "नमस्ते" comes out as ["न", "म", "स्ते"] (see the WARN in start()) and "🇮🇳" or "👨‍👩‍👧" stay in one piece.
+// The property tables live in grapheme_tables.rs, generated from the Unicode character database by
+// scripts/grapheme_tables.py.
+
+#[allow(clippy::upper_case_acronyms)] // names follow the Unicode property values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphemeCat {
+    Other,
+    CR,
+    LF,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+    InCbConsonant, // Grapheme_Cluster_Break=Other but Indic_Conjunct_Break=Consonant
+}
+
+pub fn grapheme_cat(c: char) -> GraphemeCat {
+    let found = GRAPHEME_CAT_TABLE.binary_search_by(|&(lo, hi, _)| {
+        if hi < c {
+            Ordering::Less
+        } else if lo > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+    match found {
+        Ok(idx) => GRAPHEME_CAT_TABLE[idx].2,
+        Err(_) => GraphemeCat::Other,
+    }
+}
+
+fn is_incb_linker(c: char) -> bool {
+    INCB_LINKER.contains(&c)
+}
+
+fn is_incb_extend(c: char) -> bool {
+    INCB_EXTEND_TABLE
+        .binary_search_by(|&(lo, hi)| {
+            if hi < c {
+                Ordering::Less
+            } else if lo > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// What we remember about the chars already seen, for the rules that look further back than one char.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conjunct {
+    None,
+    Consonant,       // InCB=Consonant followed by InCB=Extend only
+    ConsonantLinker, // ... and at least one InCB=Linker (virama) so far
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Emoji {
+    None,
+    Pictographic,    // Extended_Pictographic Extend*
+    PictographicZwj, // Extended_Pictographic Extend* ZWJ
+}
+
+struct BreakState {
+    prev: Option<GraphemeCat>,
+    conjunct: Conjunct,
+    emoji: Emoji,
+    regional_indicators: usize, // consecutive RIs right before the current position
+}
+
+impl BreakState {
+    fn new() -> Self {
+        Self {
+            prev: None,
+            conjunct: Conjunct::None,
+            emoji: Emoji::None,
+            regional_indicators: 0,
+        }
+    }
+
+    // Is there a boundary between everything seen so far and `c`? Also records `c` for the next call.
+    fn push(&mut self, c: char) -> bool {
+        let cat = grapheme_cat(c);
+        let is_break = match self.prev {
+            None => true, // GB1: break at the start of text
+            Some(prev) => self.is_break(prev, cat),
+        };
+        self.update(c, cat);
+        is_break
+    }
+
+    fn is_break(&self, prev: GraphemeCat, cat: GraphemeCat) -> bool {
+        use GraphemeCat::*;
+        match (prev, cat) {
+            // GB3: never split \r\n
+            (CR, LF) => false,
+            // GB4, GB5: controls and line breaks always stand alone
+            (CR | LF | Control, _) => true,
+            (_, CR | LF | Control) => true,
+            // GB6-GB8: Hangul syllable sequences
+            (L, L | V | LV | LVT) => false,
+            (LV | V, V | T) => false,
+            (LVT | T, T) => false,
+            // GB9, GB9a: combining marks, ZWJ and spacing marks attach to what comes before
+            (_, Extend | Zwj) => false,
+            (_, SpacingMark) => false,
+            // GB9b: prepended concatenation marks attach to what comes after
+            (Prepend, _) => false,
+            // GB9c: consonant + virama + consonant is one conjunct (क्ष, स्त)
+            (_, InCbConsonant) if self.conjunct == Conjunct::ConsonantLinker => false,
+            // GB11: ZWJ emoji sequences (👨‍👩‍👧)
+            (Zwj, ExtendedPictographic) if self.emoji == Emoji::PictographicZwj => false,
+            // GB12, GB13: regional indicators pair up into flags (🇮🇳)
+            (RegionalIndicator, RegionalIndicator) => self.regional_indicators.is_multiple_of(2),
+            // GB999: break everywhere else
+            _ => true,
+        }
+    }
+
+    fn update(&mut self, c: char, cat: GraphemeCat) {
+        self.conjunct = match (cat, self.conjunct) {
+            (GraphemeCat::InCbConsonant, _) => Conjunct::Consonant,
+            (_, Conjunct::None) => Conjunct::None,
+            _ if is_incb_linker(c) => Conjunct::ConsonantLinker,
+            (_, state) if is_incb_extend(c) => state,
+            _ => Conjunct::None,
+        };
+
+        self.emoji = match (cat, self.emoji) {
+            (GraphemeCat::ExtendedPictographic, _) => Emoji::Pictographic,
+            (GraphemeCat::Extend, Emoji::Pictographic) => Emoji::Pictographic,
+            (GraphemeCat::Zwj, Emoji::Pictographic) => Emoji::PictographicZwj,
+            _ => Emoji::None,
+        };
+
+        if cat == GraphemeCat::RegionalIndicator {
+            self.regional_indicators += 1;
+        } else {
+            self.regional_indicators = 0;
+        }
+
+        self.prev = Some(cat);
+    }
+}
+
+// Iterator over the byte ranges of the grapheme clusters in a string.
+pub struct GraphemeSpans<'a> {
+    source: &'a str,
+    pos: usize,
+    state: BreakState,
+}
+
+impl<'a> Iterator for GraphemeSpans<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        if self.pos >= self.source.len() {
+            return None;
+        }
+        let start = self.pos;
+        let mut end = self.source.len();
+        for (idx, c) in self.source[start..].char_indices() {
+            if idx == 0 && start > 0 {
+                continue; // already pushed by the previous call, when it turned out to start a new cluster
+            }
+            // the first char of a cluster always "breaks", we're looking for the next break after it
+            if self.state.push(c) && idx > 0 {
+                end = start + idx;
+                // `c` has already been recorded; it becomes the first char of the next cluster
+                break;
+            }
+        }
+        self.pos = end;
+        Some(start..end)
+    }
+}
+
+pub fn grapheme_spans(s: &str) -> GraphemeSpans<'_> {
+    GraphemeSpans {
+        source: s,
+        pos: 0,
+        state: BreakState::new(),
+    }
+}
+
+pub fn graphemes(s: &str) -> impl Iterator<Item = &str> {
+    grapheme_spans(s).map(move |span| &s[span])
+}
+
+// All the byte offsets where a cluster boundary is, including 0 and s.len()
+pub fn boundaries(s: &str) -> Vec<usize> {
+    let mut result: Vec<usize> = grapheme_spans(s).map(|span| span.start).collect();
+    result.push(s.len());
+    result
+}
+
+pub fn start() {
+    let hello = "नमस्ते";
+    println!("chars: {:?}", hello.chars().collect::<Vec<char>>()); // ['न', 'म', 'स', '्', 'त', 'े']
+    println!("graphemes: {:?}", graphemes(hello).collect::<Vec<&str>>()); // ["न", "म", "स्ते"]
+    // WARN: chap_18_string lists ["न", "म", "स्", "ते"], which is what Unicode gave before 15.1. Since 15.1 rule GB9c
+    // keeps a consonant + virama + consonant conjunct together, so "स्ते" is one cluster.
+
+    // Conjuncts: क्ष is क + virama + ष, one cluster since Unicode 15.1 (GB9c)
+    assert_eq!(
+        graphemes("क्षत्रिय").collect::<Vec<&str>>(),
+        ["क्ष", "त्रि", "य"]
+    );
+
+    // Combining marks, flags and ZWJ emoji sequences
+    assert_eq!(graphemes("e\u{301}a").count(), 2);
+    assert_eq!(graphemes("🇮🇳🇷🇺🇯").collect::<Vec<&str>>(), ["🇮🇳", "🇷🇺", "🇯"]);
+    assert_eq!(graphemes("👨‍👩‍👧👍🏽").collect::<Vec<&str>>(), ["👨‍👩‍👧", "👍🏽"]);
+    assert_eq!(graphemes("\r\n").count(), 1);
+
+    let (major, minor, patch) = UNICODE_VERSION;
+    println!(
+        "Unicode {major}.{minor}.{patch} tables, `cargo test` checks them against GraphemeBreakTest.txt"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every case from the vendored GraphemeBreakTest.txt, which has to match the version the tables were made from
+    #[test]
+    fn grapheme_break_test() {
+        let data = include_str!("../../assets/GraphemeBreakTest.txt");
+        let mut failures = Vec::new();
+
+        for line in data.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut text = String::new();
+            let mut expected: Vec<usize> = Vec::new();
+            for token in line.split_whitespace() {
+                match token {
+                    "÷" => expected.push(text.len()),
+                    "×" => {}
+                    hex => {
+                        let c = u32::from_str_radix(hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .expect("GraphemeBreakTest.txt should only contain valid code points");
+                        text.push(c);
+                    }
+                }
+            }
+
+            if boundaries(&text) != expected {
+                failures.push(line.to_string());
+            }
+        }
+        let (major, minor, patch) = UNICODE_VERSION;
+        assert!(
+            failures.is_empty(),
+            "GraphemeBreakTest {major}.{minor}.{patch}: {} failures, first: {}",
+            failures.len(),
+            failures[0]
+        );
+    }
+}
@@ -0,0 +1,1870 @@
+// Generated by scripts/grapheme_tables.py from the Unicode 16.0.0 character database, do not edit by hand:
+// - Grapheme_Cluster_Break from auxiliary/GraphemeBreakProperty.txt
+// - Extended_Pictographic from emoji/emoji-data.txt
+// - Indic_Conjunct_Break (InCB) from DerivedCoreProperties.txt
+// Ranges are sorted and non-overlapping so they can be binary searched. Anything not listed is `Other`.
+use super::grapheme::GraphemeCat::{self, *};
+
+pub const UNICODE_VERSION: (u8, u8, u8) = (16, 0, 0);
+
+pub const GRAPHEME_CAT_TABLE: &[(char, char, GraphemeCat)] = &[
+    ('\u{0}', '\u{9}', Control),
+    ('\u{a}', '\u{a}', LF),
+    ('\u{b}', '\u{c}', Control),
+    ('\u{d}', '\u{d}', CR),
+    ('\u{e}', '\u{1f}', Control),
+    ('\u{7f}', '\u{9f}', Control),
+    ('\u{a9}', '\u{a9}', ExtendedPictographic),
+    ('\u{ad}', '\u{ad}', Control),
+    ('\u{ae}', '\u{ae}', ExtendedPictographic),
+    ('\u{300}', '\u{36f}', Extend),
+    ('\u{483}', '\u{489}', Extend),
+    ('\u{591}', '\u{5bd}', Extend),
+    ('\u{5bf}', '\u{5bf}', Extend),
+    ('\u{5c1}', '\u{5c2}', Extend),
+    ('\u{5c4}', '\u{5c5}', Extend),
+    ('\u{5c7}', '\u{5c7}', Extend),
+    ('\u{600}', '\u{605}', Prepend),
+    ('\u{610}', '\u{61a}', Extend),
+    ('\u{61c}', '\u{61c}', Control),
+    ('\u{64b}', '\u{65f}', Extend),
+    ('\u{670}', '\u{670}', Extend),
+    ('\u{6d6}', '\u{6dc}', Extend),
+    ('\u{6dd}', '\u{6dd}', Prepend),
+    ('\u{6df}', '\u{6e4}', Extend),
+    ('\u{6e7}', '\u{6e8}', Extend),
+    ('\u{6ea}', '\u{6ed}', Extend),
+    ('\u{70f}', '\u{70f}', Prepend),
+    ('\u{711}', '\u{711}', Extend),
+    ('\u{730}', '\u{74a}', Extend),
+    ('\u{7a6}', '\u{7b0}', Extend),
+    ('\u{7eb}', '\u{7f3}', Extend),
+    ('\u{7fd}', '\u{7fd}', Extend),
+    ('\u{816}', '\u{819}', Extend),
+    ('\u{81b}', '\u{823}', Extend),
+    ('\u{825}', '\u{827}', Extend),
+    ('\u{829}', '\u{82d}', Extend),
+    ('\u{859}', '\u{85b}', Extend),
+    ('\u{890}', '\u{891}', Prepend),
+    ('\u{897}', '\u{89f}', Extend),
+    ('\u{8ca}', '\u{8e1}', Extend),
+    ('\u{8e2}', '\u{8e2}', Prepend),
+    ('\u{8e3}', '\u{902}', Extend),
+    ('\u{903}', '\u{903}', SpacingMark),
+    ('\u{915}', '\u{939}', InCbConsonant),
+    ('\u{93a}', '\u{93a}', Extend),
+    ('\u{93b}', '\u{93b}', SpacingMark),
+    ('\u{93c}', '\u{93c}', Extend),
+    ('\u{93e}', '\u{940}', SpacingMark),
+    ('\u{941}', '\u{948}', Extend),
+    ('\u{949}', '\u{94c}', SpacingMark),
+    ('\u{94d}', '\u{94d}', Extend),
+    ('\u{94e}', '\u{94f}', SpacingMark),
+    ('\u{951}', '\u{957}', Extend),
+    ('\u{958}', '\u{95f}', InCbConsonant),
+    ('\u{962}', '\u{963}', Extend),
+    ('\u{978}', '\u{97f}', InCbConsonant),
+    ('\u{981}', '\u{981}', Extend),
+    ('\u{982}', '\u{983}', SpacingMark),
+    ('\u{995}', '\u{9a8}', InCbConsonant),
+    ('\u{9aa}', '\u{9b0}', InCbConsonant),
+    ('\u{9b2}', '\u{9b2}', InCbConsonant),
+    ('\u{9b6}', '\u{9b9}', InCbConsonant),
+    ('\u{9bc}', '\u{9bc}', Extend),
+    ('\u{9be}', '\u{9be}', Extend),
+    ('\u{9bf}', '\u{9c0}', SpacingMark),
+    ('\u{9c1}', '\u{9c4}', Extend),
+    ('\u{9c7}', '\u{9c8}', SpacingMark),
+    ('\u{9cb}', '\u{9cc}', SpacingMark),
+    ('\u{9cd}', '\u{9cd}', Extend),
+    ('\u{9d7}', '\u{9d7}', Extend),
+    ('\u{9dc}', '\u{9dd}', InCbConsonant),
+    ('\u{9df}', '\u{9df}', InCbConsonant),
+    ('\u{9e2}', '\u{9e3}', Extend),
+    ('\u{9f0}', '\u{9f1}', InCbConsonant),
+    ('\u{9fe}', '\u{9fe}', Extend),
+    ('\u{a01}', '\u{a02}', Extend),
+    ('\u{a03}', '\u{a03}', SpacingMark),
+    ('\u{a3c}', '\u{a3c}', Extend),
+    ('\u{a3e}', '\u{a40}', SpacingMark),
+    ('\u{a41}', '\u{a42}', Extend),
+    ('\u{a47}', '\u{a48}', Extend),
+    ('\u{a4b}', '\u{a4d}', Extend),
+    ('\u{a51}', '\u{a51}', Extend),
+    ('\u{a70}', '\u{a71}', Extend),
+    ('\u{a75}', '\u{a75}', Extend),
+    ('\u{a81}', '\u{a82}', Extend),
+    ('\u{a83}', '\u{a83}', SpacingMark),
+    ('\u{a95}', '\u{aa8}', InCbConsonant),
+    ('\u{aaa}', '\u{ab0}', InCbConsonant),
+    ('\u{ab2}', '\u{ab3}', InCbConsonant),
+    ('\u{ab5}', '\u{ab9}', InCbConsonant),
+    ('\u{abc}', '\u{abc}', Extend),
+    ('\u{abe}', '\u{ac0}', SpacingMark),
+    ('\u{ac1}', '\u{ac5}', Extend),
+    ('\u{ac7}', '\u{ac8}', Extend),
+    ('\u{ac9}', '\u{ac9}', SpacingMark),
+    ('\u{acb}', '\u{acc}', SpacingMark),
+    ('\u{acd}', '\u{acd}', Extend),
+    ('\u{ae2}', '\u{ae3}', Extend),
+    ('\u{af9}', '\u{af9}', InCbConsonant),
+    ('\u{afa}', '\u{aff}', Extend),
+    ('\u{b01}', '\u{b01}', Extend),
+    ('\u{b02}', '\u{b03}', SpacingMark),
+    ('\u{b15}', '\u{b28}', InCbConsonant),
+    ('\u{b2a}', '\u{b30}', InCbConsonant),
+    ('\u{b32}', '\u{b33}', InCbConsonant),
+    ('\u{b35}', '\u{b39}', InCbConsonant),
+    ('\u{b3c}', '\u{b3c}', Extend),
+    ('\u{b3e}', '\u{b3f}', Extend),
+    ('\u{b40}', '\u{b40}', SpacingMark),
+    ('\u{b41}', '\u{b44}', Extend),
+    ('\u{b47}', '\u{b48}', SpacingMark),
+    ('\u{b4b}', '\u{b4c}', SpacingMark),
+    ('\u{b4d}', '\u{b4d}', Extend),
+    ('\u{b55}', '\u{b57}', Extend),
+    ('\u{b5c}', '\u{b5d}', InCbConsonant),
+    ('\u{b5f}', '\u{b5f}', InCbConsonant),
+    ('\u{b62}', '\u{b63}', Extend),
+    ('\u{b71}', '\u{b71}', InCbConsonant),
+    ('\u{b82}', '\u{b82}', Extend),
+    ('\u{bbe}', '\u{bbe}', Extend),
+    ('\u{bbf}', '\u{bbf}', SpacingMark),
+    ('\u{bc0}', '\u{bc0}', Extend),
+    ('\u{bc1}', '\u{bc2}', SpacingMark),
+    ('\u{bc6}', '\u{bc8}', SpacingMark),
+    ('\u{bca}', '\u{bcc}', SpacingMark),
+    ('\u{bcd}', '\u{bcd}', Extend),
+    ('\u{bd7}', '\u{bd7}', Extend),
+    ('\u{c00}', '\u{c00}', Extend),
+    ('\u{c01}', '\u{c03}', SpacingMark),
+    ('\u{c04}', '\u{c04}', Extend),
+    ('\u{c15}', '\u{c28}', InCbConsonant),
+    ('\u{c2a}', '\u{c39}', InCbConsonant),
+    ('\u{c3c}', '\u{c3c}', Extend),
+    ('\u{c3e}', '\u{c40}', Extend),
+    ('\u{c41}', '\u{c44}', SpacingMark),
+    ('\u{c46}', '\u{c48}', Extend),
+    ('\u{c4a}', '\u{c4d}', Extend),
+    ('\u{c55}', '\u{c56}', Extend),
+    ('\u{c58}', '\u{c5a}', InCbConsonant),
+    ('\u{c62}', '\u{c63}', Extend),
+    ('\u{c81}', '\u{c81}', Extend),
+    ('\u{c82}', '\u{c83}', SpacingMark),
+    ('\u{cbc}', '\u{cbc}', Extend),
+    ('\u{cbe}', '\u{cbe}', SpacingMark),
+    ('\u{cbf}', '\u{cc0}', Extend),
+    ('\u{cc1}', '\u{cc1}', SpacingMark),
+    ('\u{cc2}', '\u{cc2}', Extend),
+    ('\u{cc3}', '\u{cc4}', SpacingMark),
+    ('\u{cc6}', '\u{cc8}', Extend),
+    ('\u{cca}', '\u{ccd}', Extend),
+    ('\u{cd5}', '\u{cd6}', Extend),
+    ('\u{ce2}', '\u{ce3}', Extend),
+    ('\u{cf3}', '\u{cf3}', SpacingMark),
+    ('\u{d00}', '\u{d01}', Extend),
+    ('\u{d02}', '\u{d03}', SpacingMark),
+    ('\u{d15}', '\u{d3a}', InCbConsonant),
+    ('\u{d3b}', '\u{d3c}', Extend),
+    ('\u{d3e}', '\u{d3e}', Extend),
+    ('\u{d3f}', '\u{d40}', SpacingMark),
+    ('\u{d41}', '\u{d44}', Extend),
+    ('\u{d46}', '\u{d48}', SpacingMark),
+    ('\u{d4a}', '\u{d4c}', SpacingMark),
+    ('\u{d4d}', '\u{d4d}', Extend),
+    ('\u{d4e}', '\u{d4e}', Prepend),
+    ('\u{d57}', '\u{d57}', Extend),
+    ('\u{d62}', '\u{d63}', Extend),
+    ('\u{d81}', '\u{d81}', Extend),
+    ('\u{d82}', '\u{d83}', SpacingMark),
+    ('\u{dca}', '\u{dca}', Extend),
+    ('\u{dcf}', '\u{dcf}', Extend),
+    ('\u{dd0}', '\u{dd1}', SpacingMark),
+    ('\u{dd2}', '\u{dd4}', Extend),
+    ('\u{dd6}', '\u{dd6}', Extend),
+    ('\u{dd8}', '\u{dde}', SpacingMark),
+    ('\u{ddf}', '\u{ddf}', Extend),
+    ('\u{df2}', '\u{df3}', SpacingMark),
+    ('\u{e31}', '\u{e31}', Extend),
+    ('\u{e33}', '\u{e33}', SpacingMark),
+    ('\u{e34}', '\u{e3a}', Extend),
+    ('\u{e47}', '\u{e4e}', Extend),
+    ('\u{eb1}', '\u{eb1}', Extend),
+    ('\u{eb3}', '\u{eb3}', SpacingMark),
+    ('\u{eb4}', '\u{ebc}', Extend),
+    ('\u{ec8}', '\u{ece}', Extend),
+    ('\u{f18}', '\u{f19}', Extend),
+    ('\u{f35}', '\u{f35}', Extend),
+    ('\u{f37}', '\u{f37}', Extend),
+    ('\u{f39}', '\u{f39}', Extend),
+    ('\u{f3e}', '\u{f3f}', SpacingMark),
+    ('\u{f71}', '\u{f7e}', Extend),
+    ('\u{f7f}', '\u{f7f}', SpacingMark),
+    ('\u{f80}', '\u{f84}', Extend),
+    ('\u{f86}', '\u{f87}', Extend),
+    ('\u{f8d}', '\u{f97}', Extend),
+    ('\u{f99}', '\u{fbc}', Extend),
+    ('\u{fc6}', '\u{fc6}', Extend),
+    ('\u{102d}', '\u{1030}', Extend),
+    ('\u{1031}', '\u{1031}', SpacingMark),
+    ('\u{1032}', '\u{1037}', Extend),
+    ('\u{1039}', '\u{103a}', Extend),
+    ('\u{103b}', '\u{103c}', SpacingMark),
+    ('\u{103d}', '\u{103e}', Extend),
+    ('\u{1056}', '\u{1057}', SpacingMark),
+    ('\u{1058}', '\u{1059}', Extend),
+    ('\u{105e}', '\u{1060}', Extend),
+    ('\u{1071}', '\u{1074}', Extend),
+    ('\u{1082}', '\u{1082}', Extend),
+    ('\u{1084}', '\u{1084}', SpacingMark),
+    ('\u{1085}', '\u{1086}', Extend),
+    ('\u{108d}', '\u{108d}', Extend),
+    ('\u{109d}', '\u{109d}', Extend),
+    ('\u{1100}', '\u{115f}', L),
+    ('\u{1160}', '\u{11a7}', V),
+    ('\u{11a8}', '\u{11ff}', T),
+    ('\u{135d}', '\u{135f}', Extend),
+    ('\u{1712}', '\u{1715}', Extend),
+    ('\u{1732}', '\u{1734}', Extend),
+    ('\u{1752}', '\u{1753}', Extend),
+    ('\u{1772}', '\u{1773}', Extend),
+    ('\u{17b4}', '\u{17b5}', Extend),
+    ('\u{17b6}', '\u{17b6}', SpacingMark),
+    ('\u{17b7}', '\u{17bd}', Extend),
+    ('\u{17be}', '\u{17c5}', SpacingMark),
+    ('\u{17c6}', '\u{17c6}', Extend),
+    ('\u{17c7}', '\u{17c8}', SpacingMark),
+    ('\u{17c9}', '\u{17d3}', Extend),
+    ('\u{17dd}', '\u{17dd}', Extend),
+    ('\u{180b}', '\u{180d}', Extend),
+    ('\u{180e}', '\u{180e}', Control),
+    ('\u{180f}', '\u{180f}', Extend),
+    ('\u{1885}', '\u{1886}', Extend),
+    ('\u{18a9}', '\u{18a9}', Extend),
+    ('\u{1920}', '\u{1922}', Extend),
+    ('\u{1923}', '\u{1926}', SpacingMark),
+    ('\u{1927}', '\u{1928}', Extend),
+    ('\u{1929}', '\u{192b}', SpacingMark),
+    ('\u{1930}', '\u{1931}', SpacingMark),
+    ('\u{1932}', '\u{1932}', Extend),
+    ('\u{1933}', '\u{1938}', SpacingMark),
+    ('\u{1939}', '\u{193b}', Extend),
+    ('\u{1a17}', '\u{1a18}', Extend),
+    ('\u{1a19}', '\u{1a1a}', SpacingMark),
+    ('\u{1a1b}', '\u{1a1b}', Extend),
+    ('\u{1a55}', '\u{1a55}', SpacingMark),
+    ('\u{1a56}', '\u{1a56}', Extend),
+    ('\u{1a57}', '\u{1a57}', SpacingMark),
+    ('\u{1a58}', '\u{1a5e}', Extend),
+    ('\u{1a60}', '\u{1a60}', Extend),
+    ('\u{1a62}', '\u{1a62}', Extend),
+    ('\u{1a65}', '\u{1a6c}', Extend),
+    ('\u{1a6d}', '\u{1a72}', SpacingMark),
+    ('\u{1a73}', '\u{1a7c}', Extend),
+    ('\u{1a7f}', '\u{1a7f}', Extend),
+    ('\u{1ab0}', '\u{1ace}', Extend),
+    ('\u{1b00}', '\u{1b03}', Extend),
+    ('\u{1b04}', '\u{1b04}', SpacingMark),
+    ('\u{1b34}', '\u{1b3d}', Extend),
+    ('\u{1b3e}', '\u{1b41}', SpacingMark),
+    ('\u{1b42}', '\u{1b44}', Extend),
+    ('\u{1b6b}', '\u{1b73}', Extend),
+    ('\u{1b80}', '\u{1b81}', Extend),
+    ('\u{1b82}', '\u{1b82}', SpacingMark),
+    ('\u{1ba1}', '\u{1ba1}', SpacingMark),
+    ('\u{1ba2}', '\u{1ba5}', Extend),
+    ('\u{1ba6}', '\u{1ba7}', SpacingMark),
+    ('\u{1ba8}', '\u{1bad}', Extend),
+    ('\u{1be6}', '\u{1be6}', Extend),
+    ('\u{1be7}', '\u{1be7}', SpacingMark),
+    ('\u{1be8}', '\u{1be9}', Extend),
+    ('\u{1bea}', '\u{1bec}', SpacingMark),
+    ('\u{1bed}', '\u{1bed}', Extend),
+    ('\u{1bee}', '\u{1bee}', SpacingMark),
+    ('\u{1bef}', '\u{1bf3}', Extend),
+    ('\u{1c24}', '\u{1c2b}', SpacingMark),
+    ('\u{1c2c}', '\u{1c33}', Extend),
+    ('\u{1c34}', '\u{1c35}', SpacingMark),
+    ('\u{1c36}', '\u{1c37}', Extend),
+    ('\u{1cd0}', '\u{1cd2}', Extend),
+    ('\u{1cd4}', '\u{1ce0}', Extend),
+    ('\u{1ce1}', '\u{1ce1}', SpacingMark),
+    ('\u{1ce2}', '\u{1ce8}', Extend),
+    ('\u{1ced}', '\u{1ced}', Extend),
+    ('\u{1cf4}', '\u{1cf4}', Extend),
+    ('\u{1cf7}', '\u{1cf7}', SpacingMark),
+    ('\u{1cf8}', '\u{1cf9}', Extend),
+    ('\u{1dc0}', '\u{1dff}', Extend),
+    ('\u{200b}', '\u{200b}', Control),
+    ('\u{200c}', '\u{200c}', Extend),
+    ('\u{200d}', '\u{200d}', Zwj),
+    ('\u{200e}', '\u{200f}', Control),
+    ('\u{2028}', '\u{202e}', Control),
+    ('\u{203c}', '\u{203c}', ExtendedPictographic),
+    ('\u{2049}', '\u{2049}', ExtendedPictographic),
+    ('\u{2060}', '\u{206f}', Control),
+    ('\u{20d0}', '\u{20f0}', Extend),
+    ('\u{2122}', '\u{2122}', ExtendedPictographic),
+    ('\u{2139}', '\u{2139}', ExtendedPictographic),
+    ('\u{2194}', '\u{2199}', ExtendedPictographic),
+    ('\u{21a9}', '\u{21aa}', ExtendedPictographic),
+    ('\u{231a}', '\u{231b}', ExtendedPictographic),
+    ('\u{2328}', '\u{2328}', ExtendedPictographic),
+    ('\u{2388}', '\u{2388}', ExtendedPictographic),
+    ('\u{23cf}', '\u{23cf}', ExtendedPictographic),
+    ('\u{23e9}', '\u{23f3}', ExtendedPictographic),
+    ('\u{23f8}', '\u{23fa}', ExtendedPictographic),
+    ('\u{24c2}', '\u{24c2}', ExtendedPictographic),
+    ('\u{25aa}', '\u{25ab}', ExtendedPictographic),
+    ('\u{25b6}', '\u{25b6}', ExtendedPictographic),
+    ('\u{25c0}', '\u{25c0}', ExtendedPictographic),
+    ('\u{25fb}', '\u{25fe}', ExtendedPictographic),
+    ('\u{2600}', '\u{2605}', ExtendedPictographic),
+    ('\u{2607}', '\u{2612}', ExtendedPictographic),
+    ('\u{2614}', '\u{2685}', ExtendedPictographic),
+    ('\u{2690}', '\u{2705}', ExtendedPictographic),
+    ('\u{2708}', '\u{2712}', ExtendedPictographic),
+    ('\u{2714}', '\u{2714}', ExtendedPictographic),
+    ('\u{2716}', '\u{2716}', ExtendedPictographic),
+    ('\u{271d}', '\u{271d}', ExtendedPictographic),
+    ('\u{2721}', '\u{2721}', ExtendedPictographic),
+    ('\u{2728}', '\u{2728}', ExtendedPictographic),
+    ('\u{2733}', '\u{2734}', ExtendedPictographic),
+    ('\u{2744}', '\u{2744}', ExtendedPictographic),
+    ('\u{2747}', '\u{2747}', ExtendedPictographic),
+    ('\u{274c}', '\u{274c}', ExtendedPictographic),
+    ('\u{274e}', '\u{274e}', ExtendedPictographic),
+    ('\u{2753}', '\u{2755}', ExtendedPictographic),
+    ('\u{2757}', '\u{2757}', ExtendedPictographic),
+    ('\u{2763}', '\u{2767}', ExtendedPictographic),
+    ('\u{2795}', '\u{2797}', ExtendedPictographic),
+    ('\u{27a1}', '\u{27a1}', ExtendedPictographic),
+    ('\u{27b0}', '\u{27b0}', ExtendedPictographic),
+    ('\u{27bf}', '\u{27bf}', ExtendedPictographic),
+    ('\u{2934}', '\u{2935}', ExtendedPictographic),
+    ('\u{2b05}', '\u{2b07}', ExtendedPictographic),
+    ('\u{2b1b}', '\u{2b1c}', ExtendedPictographic),
+    ('\u{2b50}', '\u{2b50}', ExtendedPictographic),
+    ('\u{2b55}', '\u{2b55}', ExtendedPictographic),
+    ('\u{2cef}', '\u{2cf1}', Extend),
+    ('\u{2d7f}', '\u{2d7f}', Extend),
+    ('\u{2de0}', '\u{2dff}', Extend),
+    ('\u{302a}', '\u{302f}', Extend),
+    ('\u{3030}', '\u{3030}', ExtendedPictographic),
+    ('\u{303d}', '\u{303d}', ExtendedPictographic),
+    ('\u{3099}', '\u{309a}', Extend),
+    ('\u{3297}', '\u{3297}', ExtendedPictographic),
+    ('\u{3299}', '\u{3299}', ExtendedPictographic),
+    ('\u{a66f}', '\u{a672}', Extend),
+    ('\u{a674}', '\u{a67d}', Extend),
+    ('\u{a69e}', '\u{a69f}', Extend),
+    ('\u{a6f0}', '\u{a6f1}', Extend),
+    ('\u{a802}', '\u{a802}', Extend),
+    ('\u{a806}', '\u{a806}', Extend),
+    ('\u{a80b}', '\u{a80b}', Extend),
+    ('\u{a823}', '\u{a824}', SpacingMark),
+    ('\u{a825}', '\u{a826}', Extend),
+    ('\u{a827}', '\u{a827}', SpacingMark),
+    ('\u{a82c}', '\u{a82c}', Extend),
+    ('\u{a880}', '\u{a881}', SpacingMark),
+    ('\u{a8b4}', '\u{a8c3}', SpacingMark),
+    ('\u{a8c4}', '\u{a8c5}', Extend),
+    ('\u{a8e0}', '\u{a8f1}', Extend),
+    ('\u{a8ff}', '\u{a8ff}', Extend),
+    ('\u{a926}', '\u{a92d}', Extend),
+    ('\u{a947}', '\u{a951}', Extend),
+    ('\u{a952}', '\u{a952}', SpacingMark),
+    ('\u{a953}', '\u{a953}', Extend),
+    ('\u{a960}', '\u{a97c}', L),
+    ('\u{a980}', '\u{a982}', Extend),
+    ('\u{a983}', '\u{a983}', SpacingMark),
+    ('\u{a9b3}', '\u{a9b3}', Extend),
+    ('\u{a9b4}', '\u{a9b5}', SpacingMark),
+    ('\u{a9b6}', '\u{a9b9}', Extend),
+    ('\u{a9ba}', '\u{a9bb}', SpacingMark),
+    ('\u{a9bc}', '\u{a9bd}', Extend),
+    ('\u{a9be}', '\u{a9bf}', SpacingMark),
+    ('\u{a9c0}', '\u{a9c0}', Extend),
+    ('\u{a9e5}', '\u{a9e5}', Extend),
+    ('\u{aa29}', '\u{aa2e}', Extend),
+    ('\u{aa2f}', '\u{aa30}', SpacingMark),
+    ('\u{aa31}', '\u{aa32}', Extend),
+    ('\u{aa33}', '\u{aa34}', SpacingMark),
+    ('\u{aa35}', '\u{aa36}', Extend),
+    ('\u{aa43}', '\u{aa43}', Extend),
+    ('\u{aa4c}', '\u{aa4c}', Extend),
+    ('\u{aa4d}', '\u{aa4d}', SpacingMark),
+    ('\u{aa7c}', '\u{aa7c}', Extend),
+    ('\u{aab0}', '\u{aab0}', Extend),
+    ('\u{aab2}', '\u{aab4}', Extend),
+    ('\u{aab7}', '\u{aab8}', Extend),
+    ('\u{aabe}', '\u{aabf}', Extend),
+    ('\u{aac1}', '\u{aac1}', Extend),
+    ('\u{aaeb}', '\u{aaeb}', SpacingMark),
+    ('\u{aaec}', '\u{aaed}', Extend),
+    ('\u{aaee}', '\u{aaef}', SpacingMark),
+    ('\u{aaf5}', '\u{aaf5}', SpacingMark),
+    ('\u{aaf6}', '\u{aaf6}', Extend),
+    ('\u{abe3}', '\u{abe4}', SpacingMark),
+    ('\u{abe5}', '\u{abe5}', Extend),
+    ('\u{abe6}', '\u{abe7}', SpacingMark),
+    ('\u{abe8}', '\u{abe8}', Extend),
+    ('\u{abe9}', '\u{abea}', SpacingMark),
+    ('\u{abec}', '\u{abec}', SpacingMark),
+    ('\u{abed}', '\u{abed}', Extend),
+    ('\u{ac00}', '\u{ac00}', LV),
+    ('\u{ac01}', '\u{ac1b}', LVT),
+    ('\u{ac1c}', '\u{ac1c}', LV),
+    ('\u{ac1d}', '\u{ac37}', LVT),
+    ('\u{ac38}', '\u{ac38}', LV),
+    ('\u{ac39}', '\u{ac53}', LVT),
+    ('\u{ac54}', '\u{ac54}', LV),
+    ('\u{ac55}', '\u{ac6f}', LVT),
+    ('\u{ac70}', '\u{ac70}', LV),
+    ('\u{ac71}', '\u{ac8b}', LVT),
+    ('\u{ac8c}', '\u{ac8c}', LV),
+    ('\u{ac8d}', '\u{aca7}', LVT),
+    ('\u{aca8}', '\u{aca8}', LV),
+    ('\u{aca9}', '\u{acc3}', LVT),
+    ('\u{acc4}', '\u{acc4}', LV),
+    ('\u{acc5}', '\u{acdf}', LVT),
+    ('\u{ace0}', '\u{ace0}', LV),
+    ('\u{ace1}', '\u{acfb}', LVT),
+    ('\u{acfc}', '\u{acfc}', LV),
+    ('\u{acfd}', '\u{ad17}', LVT),
+    ('\u{ad18}', '\u{ad18}', LV),
+    ('\u{ad19}', '\u{ad33}', LVT),
+    ('\u{ad34}', '\u{ad34}', LV),
+    ('\u{ad35}', '\u{ad4f}', LVT),
+    ('\u{ad50}', '\u{ad50}', LV),
+    ('\u{ad51}', '\u{ad6b}', LVT),
+    ('\u{ad6c}', '\u{ad6c}', LV),
+    ('\u{ad6d}', '\u{ad87}', LVT),
+    ('\u{ad88}', '\u{ad88}', LV),
+    ('\u{ad89}', '\u{ada3}', LVT),
+    ('\u{ada4}', '\u{ada4}', LV),
+    ('\u{ada5}', '\u{adbf}', LVT),
+    ('\u{adc0}', '\u{adc0}', LV),
+    ('\u{adc1}', '\u{addb}', LVT),
+    ('\u{addc}', '\u{addc}', LV),
+    ('\u{addd}', '\u{adf7}', LVT),
+    ('\u{adf8}', '\u{adf8}', LV),
+    ('\u{adf9}', '\u{ae13}', LVT),
+    ('\u{ae14}', '\u{ae14}', LV),
+    ('\u{ae15}', '\u{ae2f}', LVT),
+    ('\u{ae30}', '\u{ae30}', LV),
+    ('\u{ae31}', '\u{ae4b}', LVT),
+    ('\u{ae4c}', '\u{ae4c}', LV),
+    ('\u{ae4d}', '\u{ae67}', LVT),
+    ('\u{ae68}', '\u{ae68}', LV),
+    ('\u{ae69}', '\u{ae83}', LVT),
+    ('\u{ae84}', '\u{ae84}', LV),
+    ('\u{ae85}', '\u{ae9f}', LVT),
+    ('\u{aea0}', '\u{aea0}', LV),
+    ('\u{aea1}', '\u{aebb}', LVT),
+    ('\u{aebc}', '\u{aebc}', LV),
+    ('\u{aebd}', '\u{aed7}', LVT),
+    ('\u{aed8}', '\u{aed8}', LV),
+    ('\u{aed9}', '\u{aef3}', LVT),
+    ('\u{aef4}', '\u{aef4}', LV),
+    ('\u{aef5}', '\u{af0f}', LVT),
+    ('\u{af10}', '\u{af10}', LV),
+    ('\u{af11}', '\u{af2b}', LVT),
+    ('\u{af2c}', '\u{af2c}', LV),
+    ('\u{af2d}', '\u{af47}', LVT),
+    ('\u{af48}', '\u{af48}', LV),
+    ('\u{af49}', '\u{af63}', LVT),
+    ('\u{af64}', '\u{af64}', LV),
+    ('\u{af65}', '\u{af7f}', LVT),
+    ('\u{af80}', '\u{af80}', LV),
+    ('\u{af81}', '\u{af9b}', LVT),
+    ('\u{af9c}', '\u{af9c}', LV),
+    ('\u{af9d}', '\u{afb7}', LVT),
+    ('\u{afb8}', '\u{afb8}', LV),
+    ('\u{afb9}', '\u{afd3}', LVT),
+    ('\u{afd4}', '\u{afd4}', LV),
+    ('\u{afd5}', '\u{afef}', LVT),
+    ('\u{aff0}', '\u{aff0}', LV),
+    ('\u{aff1}', '\u{b00b}', LVT),
+    ('\u{b00c}', '\u{b00c}', LV),
+    ('\u{b00d}', '\u{b027}', LVT),
+    ('\u{b028}', '\u{b028}', LV),
+    ('\u{b029}', '\u{b043}', LVT),
+    ('\u{b044}', '\u{b044}', LV),
+    ('\u{b045}', '\u{b05f}', LVT),
+    ('\u{b060}', '\u{b060}', LV),
+    ('\u{b061}', '\u{b07b}', LVT),
+    ('\u{b07c}', '\u{b07c}', LV),
+    ('\u{b07d}', '\u{b097}', LVT),
+    ('\u{b098}', '\u{b098}', LV),
+    ('\u{b099}', '\u{b0b3}', LVT),
+    ('\u{b0b4}', '\u{b0b4}', LV),
+    ('\u{b0b5}', '\u{b0cf}', LVT),
+    ('\u{b0d0}', '\u{b0d0}', LV),
+    ('\u{b0d1}', '\u{b0eb}', LVT),
+    ('\u{b0ec}', '\u{b0ec}', LV),
+    ('\u{b0ed}', '\u{b107}', LVT),
+    ('\u{b108}', '\u{b108}', LV),
+    ('\u{b109}', '\u{b123}', LVT),
+    ('\u{b124}', '\u{b124}', LV),
+    ('\u{b125}', '\u{b13f}', LVT),
+    ('\u{b140}', '\u{b140}', LV),
+    ('\u{b141}', '\u{b15b}', LVT),
+    ('\u{b15c}', '\u{b15c}', LV),
+    ('\u{b15d}', '\u{b177}', LVT),
+    ('\u{b178}', '\u{b178}', LV),
+    ('\u{b179}', '\u{b193}', LVT),
+    ('\u{b194}', '\u{b194}', LV),
+    ('\u{b195}', '\u{b1af}', LVT),
+    ('\u{b1b0}', '\u{b1b0}', LV),
+    ('\u{b1b1}', '\u{b1cb}', LVT),
+    ('\u{b1cc}', '\u{b1cc}', LV),
+    ('\u{b1cd}', '\u{b1e7}', LVT),
+    ('\u{b1e8}', '\u{b1e8}', LV),
+    ('\u{b1e9}', '\u{b203}', LVT),
+    ('\u{b204}', '\u{b204}', LV),
+    ('\u{b205}', '\u{b21f}', LVT),
+    ('\u{b220}', '\u{b220}', LV),
+    ('\u{b221}', '\u{b23b}', LVT),
+    ('\u{b23c}', '\u{b23c}', LV),
+    ('\u{b23d}', '\u{b257}', LVT),
+    ('\u{b258}', '\u{b258}', LV),
+    ('\u{b259}', '\u{b273}', LVT),
+    ('\u{b274}', '\u{b274}', LV),
+    ('\u{b275}', '\u{b28f}', LVT),
+    ('\u{b290}', '\u{b290}', LV),
+    ('\u{b291}', '\u{b2ab}', LVT),
+    ('\u{b2ac}', '\u{b2ac}', LV),
+    ('\u{b2ad}', '\u{b2c7}', LVT),
+    ('\u{b2c8}', '\u{b2c8}', LV),
+    ('\u{b2c9}', '\u{b2e3}', LVT),
+    ('\u{b2e4}', '\u{b2e4}', LV),
+    ('\u{b2e5}', '\u{b2ff}', LVT),
+    ('\u{b300}', '\u{b300}', LV),
+    ('\u{b301}', '\u{b31b}', LVT),
+    ('\u{b31c}', '\u{b31c}', LV),
+    ('\u{b31d}', '\u{b337}', LVT),
+    ('\u{b338}', '\u{b338}', LV),
+    ('\u{b339}', '\u{b353}', LVT),
+    ('\u{b354}', '\u{b354}', LV),
+    ('\u{b355}', '\u{b36f}', LVT),
+    ('\u{b370}', '\u{b370}', LV),
+    ('\u{b371}', '\u{b38b}', LVT),
+    ('\u{b38c}', '\u{b38c}', LV),
+    ('\u{b38d}', '\u{b3a7}', LVT),
+    ('\u{b3a8}', '\u{b3a8}', LV),
+    ('\u{b3a9}', '\u{b3c3}', LVT),
+    ('\u{b3c4}', '\u{b3c4}', LV),
+    ('\u{b3c5}', '\u{b3df}', LVT),
+    ('\u{b3e0}', '\u{b3e0}', LV),
+    ('\u{b3e1}', '\u{b3fb}', LVT),
+    ('\u{b3fc}', '\u{b3fc}', LV),
+    ('\u{b3fd}', '\u{b417}', LVT),
+    ('\u{b418}', '\u{b418}', LV),
+    ('\u{b419}', '\u{b433}', LVT),
+    ('\u{b434}', '\u{b434}', LV),
+    ('\u{b435}', '\u{b44f}', LVT),
+    ('\u{b450}', '\u{b450}', LV),
+    ('\u{b451}', '\u{b46b}', LVT),
+    ('\u{b46c}', '\u{b46c}', LV),
+    ('\u{b46d}', '\u{b487}', LVT),
+    ('\u{b488}', '\u{b488}', LV),
+    ('\u{b489}', '\u{b4a3}', LVT),
+    ('\u{b4a4}', '\u{b4a4}', LV),
+    ('\u{b4a5}', '\u{b4bf}', LVT),
+    ('\u{b4c0}', '\u{b4c0}', LV),
+    ('\u{b4c1}', '\u{b4db}', LVT),
+    ('\u{b4dc}', '\u{b4dc}', LV),
+    ('\u{b4dd}', '\u{b4f7}', LVT),
+    ('\u{b4f8}', '\u{b4f8}', LV),
+    ('\u{b4f9}', '\u{b513}', LVT),
+    ('\u{b514}', '\u{b514}', LV),
+    ('\u{b515}', '\u{b52f}', LVT),
+    ('\u{b530}', '\u{b530}', LV),
+    ('\u{b531}', '\u{b54b}', LVT),
+    ('\u{b54c}', '\u{b54c}', LV),
+    ('\u{b54d}', '\u{b567}', LVT),
+    ('\u{b568}', '\u{b568}', LV),
+    ('\u{b569}', '\u{b583}', LVT),
+    ('\u{b584}', '\u{b584}', LV),
+    ('\u{b585}', '\u{b59f}', LVT),
+    ('\u{b5a0}', '\u{b5a0}', LV),
+    ('\u{b5a1}', '\u{b5bb}', LVT),
+    ('\u{b5bc}', '\u{b5bc}', LV),
+    ('\u{b5bd}', '\u{b5d7}', LVT),
+    ('\u{b5d8}', '\u{b5d8}', LV),
+    ('\u{b5d9}', '\u{b5f3}', LVT),
+    ('\u{b5f4}', '\u{b5f4}', LV),
+    ('\u{b5f5}', '\u{b60f}', LVT),
+    ('\u{b610}', '\u{b610}', LV),
+    ('\u{b611}', '\u{b62b}', LVT),
+    ('\u{b62c}', '\u{b62c}', LV),
+    ('\u{b62d}', '\u{b647}', LVT),
+    ('\u{b648}', '\u{b648}', LV),
+    ('\u{b649}', '\u{b663}', LVT),
+    ('\u{b664}', '\u{b664}', LV),
+    ('\u{b665}', '\u{b67f}', LVT),
+    ('\u{b680}', '\u{b680}', LV),
+    ('\u{b681}', '\u{b69b}', LVT),
+    ('\u{b69c}', '\u{b69c}', LV),
+    ('\u{b69d}', '\u{b6b7}', LVT),
+    ('\u{b6b8}', '\u{b6b8}', LV),
+    ('\u{b6b9}', '\u{b6d3}', LVT),
+    ('\u{b6d4}', '\u{b6d4}', LV),
+    ('\u{b6d5}', '\u{b6ef}', LVT),
+    ('\u{b6f0}', '\u{b6f0}', LV),
+    ('\u{b6f1}', '\u{b70b}', LVT),
+    ('\u{b70c}', '\u{b70c}', LV),
+    ('\u{b70d}', '\u{b727}', LVT),
+    ('\u{b728}', '\u{b728}', LV),
+    ('\u{b729}', '\u{b743}', LVT),
+    ('\u{b744}', '\u{b744}', LV),
+    ('\u{b745}', '\u{b75f}', LVT),
+    ('\u{b760}', '\u{b760}', LV),
+    ('\u{b761}', '\u{b77b}', LVT),
+    ('\u{b77c}', '\u{b77c}', LV),
+    ('\u{b77d}', '\u{b797}', LVT),
+    ('\u{b798}', '\u{b798}', LV),
+    ('\u{b799}', '\u{b7b3}', LVT),
+    ('\u{b7b4}', '\u{b7b4}', LV),
+    ('\u{b7b5}', '\u{b7cf}', LVT),
+    ('\u{b7d0}', '\u{b7d0}', LV),
+    ('\u{b7d1}', '\u{b7eb}', LVT),
+    ('\u{b7ec}', '\u{b7ec}', LV),
+    ('\u{b7ed}', '\u{b807}', LVT),
+    ('\u{b808}', '\u{b808}', LV),
+    ('\u{b809}', '\u{b823}', LVT),
+    ('\u{b824}', '\u{b824}', LV),
+    ('\u{b825}', '\u{b83f}', LVT),
+    ('\u{b840}', '\u{b840}', LV),
+    ('\u{b841}', '\u{b85b}', LVT),
+    ('\u{b85c}', '\u{b85c}', LV),
+    ('\u{b85d}', '\u{b877}', LVT),
+    ('\u{b878}', '\u{b878}', LV),
+    ('\u{b879}', '\u{b893}', LVT),
+    ('\u{b894}', '\u{b894}', LV),
+    ('\u{b895}', '\u{b8af}', LVT),
+    ('\u{b8b0}', '\u{b8b0}', LV),
+    ('\u{b8b1}', '\u{b8cb}', LVT),
+    ('\u{b8cc}', '\u{b8cc}', LV),
+    ('\u{b8cd}', '\u{b8e7}', LVT),
+    ('\u{b8e8}', '\u{b8e8}', LV),
+    ('\u{b8e9}', '\u{b903}', LVT),
+    ('\u{b904}', '\u{b904}', LV),
+    ('\u{b905}', '\u{b91f}', LVT),
+    ('\u{b920}', '\u{b920}', LV),
+    ('\u{b921}', '\u{b93b}', LVT),
+    ('\u{b93c}', '\u{b93c}', LV),
+    ('\u{b93d}', '\u{b957}', LVT),
+    ('\u{b958}', '\u{b958}', LV),
+    ('\u{b959}', '\u{b973}', LVT),
+    ('\u{b974}', '\u{b974}', LV),
+    ('\u{b975}', '\u{b98f}', LVT),
+    ('\u{b990}', '\u{b990}', LV),
+    ('\u{b991}', '\u{b9ab}', LVT),
+    ('\u{b9ac}', '\u{b9ac}', LV),
+    ('\u{b9ad}', '\u{b9c7}', LVT),
+    ('\u{b9c8}', '\u{b9c8}', LV),
+    ('\u{b9c9}', '\u{b9e3}', LVT),
+    ('\u{b9e4}', '\u{b9e4}', LV),
+    ('\u{b9e5}', '\u{b9ff}', LVT),
+    ('\u{ba00}', '\u{ba00}', LV),
+    ('\u{ba01}', '\u{ba1b}', LVT),
+    ('\u{ba1c}', '\u{ba1c}', LV),
+    ('\u{ba1d}', '\u{ba37}', LVT),
+    ('\u{ba38}', '\u{ba38}', LV),
+    ('\u{ba39}', '\u{ba53}', LVT),
+    ('\u{ba54}', '\u{ba54}', LV),
+    ('\u{ba55}', '\u{ba6f}', LVT),
+    ('\u{ba70}', '\u{ba70}', LV),
+    ('\u{ba71}', '\u{ba8b}', LVT),
+    ('\u{ba8c}', '\u{ba8c}', LV),
+    ('\u{ba8d}', '\u{baa7}', LVT),
+    ('\u{baa8}', '\u{baa8}', LV),
+    ('\u{baa9}', '\u{bac3}', LVT),
+    ('\u{bac4}', '\u{bac4}', LV),
+    ('\u{bac5}', '\u{badf}', LVT),
+    ('\u{bae0}', '\u{bae0}', LV),
+    ('\u{bae1}', '\u{bafb}', LVT),
+    ('\u{bafc}', '\u{bafc}', LV),
+    ('\u{bafd}', '\u{bb17}', LVT),
+    ('\u{bb18}', '\u{bb18}', LV),
+    ('\u{bb19}', '\u{bb33}', LVT),
+    ('\u{bb34}', '\u{bb34}', LV),
+    ('\u{bb35}', '\u{bb4f}', LVT),
+    ('\u{bb50}', '\u{bb50}', LV),
+    ('\u{bb51}', '\u{bb6b}', LVT),
+    ('\u{bb6c}', '\u{bb6c}', LV),
+    ('\u{bb6d}', '\u{bb87}', LVT),
+    ('\u{bb88}', '\u{bb88}', LV),
+    ('\u{bb89}', '\u{bba3}', LVT),
+    ('\u{bba4}', '\u{bba4}', LV),
+    ('\u{bba5}', '\u{bbbf}', LVT),
+    ('\u{bbc0}', '\u{bbc0}', LV),
+    ('\u{bbc1}', '\u{bbdb}', LVT),
+    ('\u{bbdc}', '\u{bbdc}', LV),
+    ('\u{bbdd}', '\u{bbf7}', LVT),
+    ('\u{bbf8}', '\u{bbf8}', LV),
+    ('\u{bbf9}', '\u{bc13}', LVT),
+    ('\u{bc14}', '\u{bc14}', LV),
+    ('\u{bc15}', '\u{bc2f}', LVT),
+    ('\u{bc30}', '\u{bc30}', LV),
+    ('\u{bc31}', '\u{bc4b}', LVT),
+    ('\u{bc4c}', '\u{bc4c}', LV),
+    ('\u{bc4d}', '\u{bc67}', LVT),
+    ('\u{bc68}', '\u{bc68}', LV),
+    ('\u{bc69}', '\u{bc83}', LVT),
+    ('\u{bc84}', '\u{bc84}', LV),
+    ('\u{bc85}', '\u{bc9f}', LVT),
+    ('\u{bca0}', '\u{bca0}', LV),
+    ('\u{bca1}', '\u{bcbb}', LVT),
+    ('\u{bcbc}', '\u{bcbc}', LV),
+    ('\u{bcbd}', '\u{bcd7}', LVT),
+    ('\u{bcd8}', '\u{bcd8}', LV),
+    ('\u{bcd9}', '\u{bcf3}', LVT),
+    ('\u{bcf4}', '\u{bcf4}', LV),
+    ('\u{bcf5}', '\u{bd0f}', LVT),
+    ('\u{bd10}', '\u{bd10}', LV),
+    ('\u{bd11}', '\u{bd2b}', LVT),
+    ('\u{bd2c}', '\u{bd2c}', LV),
+    ('\u{bd2d}', '\u{bd47}', LVT),
+    ('\u{bd48}', '\u{bd48}', LV),
+    ('\u{bd49}', '\u{bd63}', LVT),
+    ('\u{bd64}', '\u{bd64}', LV),
+    ('\u{bd65}', '\u{bd7f}', LVT),
+    ('\u{bd80}', '\u{bd80}', LV),
+    ('\u{bd81}', '\u{bd9b}', LVT),
+    ('\u{bd9c}', '\u{bd9c}', LV),
+    ('\u{bd9d}', '\u{bdb7}', LVT),
+    ('\u{bdb8}', '\u{bdb8}', LV),
+    ('\u{bdb9}', '\u{bdd3}', LVT),
+    ('\u{bdd4}', '\u{bdd4}', LV),
+    ('\u{bdd5}', '\u{bdef}', LVT),
+    ('\u{bdf0}', '\u{bdf0}', LV),
+    ('\u{bdf1}', '\u{be0b}', LVT),
+    ('\u{be0c}', '\u{be0c}', LV),
+    ('\u{be0d}', '\u{be27}', LVT),
+    ('\u{be28}', '\u{be28}', LV),
+    ('\u{be29}', '\u{be43}', LVT),
+    ('\u{be44}', '\u{be44}', LV),
+    ('\u{be45}', '\u{be5f}', LVT),
+    ('\u{be60}', '\u{be60}', LV),
+    ('\u{be61}', '\u{be7b}', LVT),
+    ('\u{be7c}', '\u{be7c}', LV),
+    ('\u{be7d}', '\u{be97}', LVT),
+    ('\u{be98}', '\u{be98}', LV),
+    ('\u{be99}', '\u{beb3}', LVT),
+    ('\u{beb4}', '\u{beb4}', LV),
+    ('\u{beb5}', '\u{becf}', LVT),
+    ('\u{bed0}', '\u{bed0}', LV),
+    ('\u{bed1}', '\u{beeb}', LVT),
+    ('\u{beec}', '\u{beec}', LV),
+    ('\u{beed}', '\u{bf07}', LVT),
+    ('\u{bf08}', '\u{bf08}', LV),
+    ('\u{bf09}', '\u{bf23}', LVT),
+    ('\u{bf24}', '\u{bf24}', LV),
+    ('\u{bf25}', '\u{bf3f}', LVT),
+    ('\u{bf40}', '\u{bf40}', LV),
+    ('\u{bf41}', '\u{bf5b}', LVT),
+    ('\u{bf5c}', '\u{bf5c}', LV),
+    ('\u{bf5d}', '\u{bf77}', LVT),
+    ('\u{bf78}', '\u{bf78}', LV),
+    ('\u{bf79}', '\u{bf93}', LVT),
+    ('\u{bf94}', '\u{bf94}', LV),
+    ('\u{bf95}', '\u{bfaf}', LVT),
+    ('\u{bfb0}', '\u{bfb0}', LV),
+    ('\u{bfb1}', '\u{bfcb}', LVT),
+    ('\u{bfcc}', '\u{bfcc}', LV),
+    ('\u{bfcd}', '\u{bfe7}', LVT),
+    ('\u{bfe8}', '\u{bfe8}', LV),
+    ('\u{bfe9}', '\u{c003}', LVT),
+    ('\u{c004}', '\u{c004}', LV),
+    ('\u{c005}', '\u{c01f}', LVT),
+    ('\u{c020}', '\u{c020}', LV),
+    ('\u{c021}', '\u{c03b}', LVT),
+    ('\u{c03c}', '\u{c03c}', LV),
+    ('\u{c03d}', '\u{c057}', LVT),
+    ('\u{c058}', '\u{c058}', LV),
+    ('\u{c059}', '\u{c073}', LVT),
+    ('\u{c074}', '\u{c074}', LV),
+    ('\u{c075}', '\u{c08f}', LVT),
+    ('\u{c090}', '\u{c090}', LV),
+    ('\u{c091}', '\u{c0ab}', LVT),
+    ('\u{c0ac}', '\u{c0ac}', LV),
+    ('\u{c0ad}', '\u{c0c7}', LVT),
+    ('\u{c0c8}', '\u{c0c8}', LV),
+    ('\u{c0c9}', '\u{c0e3}', LVT),
+    ('\u{c0e4}', '\u{c0e4}', LV),
+    ('\u{c0e5}', '\u{c0ff}', LVT),
+    ('\u{c100}', '\u{c100}', LV),
+    ('\u{c101}', '\u{c11b}', LVT),
+    ('\u{c11c}', '\u{c11c}', LV),
+    ('\u{c11d}', '\u{c137}', LVT),
+    ('\u{c138}', '\u{c138}', LV),
+    ('\u{c139}', '\u{c153}', LVT),
+    ('\u{c154}', '\u{c154}', LV),
+    ('\u{c155}', '\u{c16f}', LVT),
+    ('\u{c170}', '\u{c170}', LV),
+    ('\u{c171}', '\u{c18b}', LVT),
+    ('\u{c18c}', '\u{c18c}', LV),
+    ('\u{c18d}', '\u{c1a7}', LVT),
+    ('\u{c1a8}', '\u{c1a8}', LV),
+    ('\u{c1a9}', '\u{c1c3}', LVT),
+    ('\u{c1c4}', '\u{c1c4}', LV),
+    ('\u{c1c5}', '\u{c1df}', LVT),
+    ('\u{c1e0}', '\u{c1e0}', LV),
+    ('\u{c1e1}', '\u{c1fb}', LVT),
+    ('\u{c1fc}', '\u{c1fc}', LV),
+    ('\u{c1fd}', '\u{c217}', LVT),
+    ('\u{c218}', '\u{c218}', LV),
+    ('\u{c219}', '\u{c233}', LVT),
+    ('\u{c234}', '\u{c234}', LV),
+    ('\u{c235}', '\u{c24f}', LVT),
+    ('\u{c250}', '\u{c250}', LV),
+    ('\u{c251}', '\u{c26b}', LVT),
+    ('\u{c26c}', '\u{c26c}', LV),
+    ('\u{c26d}', '\u{c287}', LVT),
+    ('\u{c288}', '\u{c288}', LV),
+    ('\u{c289}', '\u{c2a3}', LVT),
+    ('\u{c2a4}', '\u{c2a4}', LV),
+    ('\u{c2a5}', '\u{c2bf}', LVT),
+    ('\u{c2c0}', '\u{c2c0}', LV),
+    ('\u{c2c1}', '\u{c2db}', LVT),
+    ('\u{c2dc}', '\u{c2dc}', LV),
+    ('\u{c2dd}', '\u{c2f7}', LVT),
+    ('\u{c2f8}', '\u{c2f8}', LV),
+    ('\u{c2f9}', '\u{c313}', LVT),
+    ('\u{c314}', '\u{c314}', LV),
+    ('\u{c315}', '\u{c32f}', LVT),
+    ('\u{c330}', '\u{c330}', LV),
+    ('\u{c331}', '\u{c34b}', LVT),
+    ('\u{c34c}', '\u{c34c}', LV),
+    ('\u{c34d}', '\u{c367}', LVT),
+    ('\u{c368}', '\u{c368}', LV),
+    ('\u{c369}', '\u{c383}', LVT),
+    ('\u{c384}', '\u{c384}', LV),
+    ('\u{c385}', '\u{c39f}', LVT),
+    ('\u{c3a0}', '\u{c3a0}', LV),
+    ('\u{c3a1}', '\u{c3bb}', LVT),
+    ('\u{c3bc}', '\u{c3bc}', LV),
+    ('\u{c3bd}', '\u{c3d7}', LVT),
+    ('\u{c3d8}', '\u{c3d8}', LV),
+    ('\u{c3d9}', '\u{c3f3}', LVT),
+    ('\u{c3f4}', '\u{c3f4}', LV),
+    ('\u{c3f5}', '\u{c40f}', LVT),
+    ('\u{c410}', '\u{c410}', LV),
+    ('\u{c411}', '\u{c42b}', LVT),
+    ('\u{c42c}', '\u{c42c}', LV),
+    ('\u{c42d}', '\u{c447}', LVT),
+    ('\u{c448}', '\u{c448}', LV),
+    ('\u{c449}', '\u{c463}', LVT),
+    ('\u{c464}', '\u{c464}', LV),
+    ('\u{c465}', '\u{c47f}', LVT),
+    ('\u{c480}', '\u{c480}', LV),
+    ('\u{c481}', '\u{c49b}', LVT),
+    ('\u{c49c}', '\u{c49c}', LV),
+    ('\u{c49d}', '\u{c4b7}', LVT),
+    ('\u{c4b8}', '\u{c4b8}', LV),
+    ('\u{c4b9}', '\u{c4d3}', LVT),
+    ('\u{c4d4}', '\u{c4d4}', LV),
+    ('\u{c4d5}', '\u{c4ef}', LVT),
+    ('\u{c4f0}', '\u{c4f0}', LV),
+    ('\u{c4f1}', '\u{c50b}', LVT),
+    ('\u{c50c}', '\u{c50c}', LV),
+    ('\u{c50d}', '\u{c527}', LVT),
+    ('\u{c528}', '\u{c528}', LV),
+    ('\u{c529}', '\u{c543}', LVT),
+    ('\u{c544}', '\u{c544}', LV),
+    ('\u{c545}', '\u{c55f}', LVT),
+    ('\u{c560}', '\u{c560}', LV),
+    ('\u{c561}', '\u{c57b}', LVT),
+    ('\u{c57c}', '\u{c57c}', LV),
+    ('\u{c57d}', '\u{c597}', LVT),
+    ('\u{c598}', '\u{c598}', LV),
+    ('\u{c599}', '\u{c5b3}', LVT),
+    ('\u{c5b4}', '\u{c5b4}', LV),
+    ('\u{c5b5}', '\u{c5cf}', LVT),
+    ('\u{c5d0}', '\u{c5d0}', LV),
+    ('\u{c5d1}', '\u{c5eb}', LVT),
+    ('\u{c5ec}', '\u{c5ec}', LV),
+    ('\u{c5ed}', '\u{c607}', LVT),
+    ('\u{c608}', '\u{c608}', LV),
+    ('\u{c609}', '\u{c623}', LVT),
+    ('\u{c624}', '\u{c624}', LV),
+    ('\u{c625}', '\u{c63f}', LVT),
+    ('\u{c640}', '\u{c640}', LV),
+    ('\u{c641}', '\u{c65b}', LVT),
+    ('\u{c65c}', '\u{c65c}', LV),
+    ('\u{c65d}', '\u{c677}', LVT),
+    ('\u{c678}', '\u{c678}', LV),
+    ('\u{c679}', '\u{c693}', LVT),
+    ('\u{c694}', '\u{c694}', LV),
+    ('\u{c695}', '\u{c6af}', LVT),
+    ('\u{c6b0}', '\u{c6b0}', LV),
+    ('\u{c6b1}', '\u{c6cb}', LVT),
+    ('\u{c6cc}', '\u{c6cc}', LV),
+    ('\u{c6cd}', '\u{c6e7}', LVT),
+    ('\u{c6e8}', '\u{c6e8}', LV),
+    ('\u{c6e9}', '\u{c703}', LVT),
+    ('\u{c704}', '\u{c704}', LV),
+    ('\u{c705}', '\u{c71f}', LVT),
+    ('\u{c720}', '\u{c720}', LV),
+    ('\u{c721}', '\u{c73b}', LVT),
+    ('\u{c73c}', '\u{c73c}', LV),
+    ('\u{c73d}', '\u{c757}', LVT),
+    ('\u{c758}', '\u{c758}', LV),
+    ('\u{c759}', '\u{c773}', LVT),
+    ('\u{c774}', '\u{c774}', LV),
+    ('\u{c775}', '\u{c78f}', LVT),
+    ('\u{c790}', '\u{c790}', LV),
+    ('\u{c791}', '\u{c7ab}', LVT),
+    ('\u{c7ac}', '\u{c7ac}', LV),
+    ('\u{c7ad}', '\u{c7c7}', LVT),
+    ('\u{c7c8}', '\u{c7c8}', LV),
+    ('\u{c7c9}', '\u{c7e3}', LVT),
+    ('\u{c7e4}', '\u{c7e4}', LV),
+    ('\u{c7e5}', '\u{c7ff}', LVT),
+    ('\u{c800}', '\u{c800}', LV),
+    ('\u{c801}', '\u{c81b}', LVT),
+    ('\u{c81c}', '\u{c81c}', LV),
+    ('\u{c81d}', '\u{c837}', LVT),
+    ('\u{c838}', '\u{c838}', LV),
+    ('\u{c839}', '\u{c853}', LVT),
+    ('\u{c854}', '\u{c854}', LV),
+    ('\u{c855}', '\u{c86f}', LVT),
+    ('\u{c870}', '\u{c870}', LV),
+    ('\u{c871}', '\u{c88b}', LVT),
+    ('\u{c88c}', '\u{c88c}', LV),
+    ('\u{c88d}', '\u{c8a7}', LVT),
+    ('\u{c8a8}', '\u{c8a8}', LV),
+    ('\u{c8a9}', '\u{c8c3}', LVT),
+    ('\u{c8c4}', '\u{c8c4}', LV),
+    ('\u{c8c5}', '\u{c8df}', LVT),
+    ('\u{c8e0}', '\u{c8e0}', LV),
+    ('\u{c8e1}', '\u{c8fb}', LVT),
+    ('\u{c8fc}', '\u{c8fc}', LV),
+    ('\u{c8fd}', '\u{c917}', LVT),
+    ('\u{c918}', '\u{c918}', LV),
+    ('\u{c919}', '\u{c933}', LVT),
+    ('\u{c934}', '\u{c934}', LV),
+    ('\u{c935}', '\u{c94f}', LVT),
+    ('\u{c950}', '\u{c950}', LV),
+    ('\u{c951}', '\u{c96b}', LVT),
+    ('\u{c96c}', '\u{c96c}', LV),
+    ('\u{c96d}', '\u{c987}', LVT),
+    ('\u{c988}', '\u{c988}', LV),
+    ('\u{c989}', '\u{c9a3}', LVT),
+    ('\u{c9a4}', '\u{c9a4}', LV),
+    ('\u{c9a5}', '\u{c9bf}', LVT),
+    ('\u{c9c0}', '\u{c9c0}', LV),
+    ('\u{c9c1}', '\u{c9db}', LVT),
+    ('\u{c9dc}', '\u{c9dc}', LV),
+    ('\u{c9dd}', '\u{c9f7}', LVT),
+    ('\u{c9f8}', '\u{c9f8}', LV),
+    ('\u{c9f9}', '\u{ca13}', LVT),
+    ('\u{ca14}', '\u{ca14}', LV),
+    ('\u{ca15}', '\u{ca2f}', LVT),
+    ('\u{ca30}', '\u{ca30}', LV),
+    ('\u{ca31}', '\u{ca4b}', LVT),
+    ('\u{ca4c}', '\u{ca4c}', LV),
+    ('\u{ca4d}', '\u{ca67}', LVT),
+    ('\u{ca68}', '\u{ca68}', LV),
+    ('\u{ca69}', '\u{ca83}', LVT),
+    ('\u{ca84}', '\u{ca84}', LV),
+    ('\u{ca85}', '\u{ca9f}', LVT),
+    ('\u{caa0}', '\u{caa0}', LV),
+    ('\u{caa1}', '\u{cabb}', LVT),
+    ('\u{cabc}', '\u{cabc}', LV),
+    ('\u{cabd}', '\u{cad7}', LVT),
+    ('\u{cad8}', '\u{cad8}', LV),
+    ('\u{cad9}', '\u{caf3}', LVT),
+    ('\u{caf4}', '\u{caf4}', LV),
+    ('\u{caf5}', '\u{cb0f}', LVT),
+    ('\u{cb10}', '\u{cb10}', LV),
+    ('\u{cb11}', '\u{cb2b}', LVT),
+    ('\u{cb2c}', '\u{cb2c}', LV),
+    ('\u{cb2d}', '\u{cb47}', LVT),
+    ('\u{cb48}', '\u{cb48}', LV),
+    ('\u{cb49}', '\u{cb63}', LVT),
+    ('\u{cb64}', '\u{cb64}', LV),
+    ('\u{cb65}', '\u{cb7f}', LVT),
+    ('\u{cb80}', '\u{cb80}', LV),
+    ('\u{cb81}', '\u{cb9b}', LVT),
+    ('\u{cb9c}', '\u{cb9c}', LV),
+    ('\u{cb9d}', '\u{cbb7}', LVT),
+    ('\u{cbb8}', '\u{cbb8}', LV),
+    ('\u{cbb9}', '\u{cbd3}', LVT),
+    ('\u{cbd4}', '\u{cbd4}', LV),
+    ('\u{cbd5}', '\u{cbef}', LVT),
+    ('\u{cbf0}', '\u{cbf0}', LV),
+    ('\u{cbf1}', '\u{cc0b}', LVT),
+    ('\u{cc0c}', '\u{cc0c}', LV),
+    ('\u{cc0d}', '\u{cc27}', LVT),
+    ('\u{cc28}', '\u{cc28}', LV),
+    ('\u{cc29}', '\u{cc43}', LVT),
+    ('\u{cc44}', '\u{cc44}', LV),
+    ('\u{cc45}', '\u{cc5f}', LVT),
+    ('\u{cc60}', '\u{cc60}', LV),
+    ('\u{cc61}', '\u{cc7b}', LVT),
+    ('\u{cc7c}', '\u{cc7c}', LV),
+    ('\u{cc7d}', '\u{cc97}', LVT),
+    ('\u{cc98}', '\u{cc98}', LV),
+    ('\u{cc99}', '\u{ccb3}', LVT),
+    ('\u{ccb4}', '\u{ccb4}', LV),
+    ('\u{ccb5}', '\u{cccf}', LVT),
+    ('\u{ccd0}', '\u{ccd0}', LV),
+    ('\u{ccd1}', '\u{cceb}', LVT),
+    ('\u{ccec}', '\u{ccec}', LV),
+    ('\u{cced}', '\u{cd07}', LVT),
+    ('\u{cd08}', '\u{cd08}', LV),
+    ('\u{cd09}', '\u{cd23}', LVT),
+    ('\u{cd24}', '\u{cd24}', LV),
+    ('\u{cd25}', '\u{cd3f}', LVT),
+    ('\u{cd40}', '\u{cd40}', LV),
+    ('\u{cd41}', '\u{cd5b}', LVT),
+    ('\u{cd5c}', '\u{cd5c}', LV),
+    ('\u{cd5d}', '\u{cd77}', LVT),
+    ('\u{cd78}', '\u{cd78}', LV),
+    ('\u{cd79}', '\u{cd93}', LVT),
+    ('\u{cd94}', '\u{cd94}', LV),
+    ('\u{cd95}', '\u{cdaf}', LVT),
+    ('\u{cdb0}', '\u{cdb0}', LV),
+    ('\u{cdb1}', '\u{cdcb}', LVT),
+    ('\u{cdcc}', '\u{cdcc}', LV),
+    ('\u{cdcd}', '\u{cde7}', LVT),
+    ('\u{cde8}', '\u{cde8}', LV),
+    ('\u{cde9}', '\u{ce03}', LVT),
+    ('\u{ce04}', '\u{ce04}', LV),
+    ('\u{ce05}', '\u{ce1f}', LVT),
+    ('\u{ce20}', '\u{ce20}', LV),
+    ('\u{ce21}', '\u{ce3b}', LVT),
+    ('\u{ce3c}', '\u{ce3c}', LV),
+    ('\u{ce3d}', '\u{ce57}', LVT),
+    ('\u{ce58}', '\u{ce58}', LV),
+    ('\u{ce59}', '\u{ce73}', LVT),
+    ('\u{ce74}', '\u{ce74}', LV),
+    ('\u{ce75}', '\u{ce8f}', LVT),
+    ('\u{ce90}', '\u{ce90}', LV),
+    ('\u{ce91}', '\u{ceab}', LVT),
+    ('\u{ceac}', '\u{ceac}', LV),
+    ('\u{cead}', '\u{cec7}', LVT),
+    ('\u{cec8}', '\u{cec8}', LV),
+    ('\u{cec9}', '\u{cee3}', LVT),
+    ('\u{cee4}', '\u{cee4}', LV),
+    ('\u{cee5}', '\u{ceff}', LVT),
+    ('\u{cf00}', '\u{cf00}', LV),
+    ('\u{cf01}', '\u{cf1b}', LVT),
+    ('\u{cf1c}', '\u{cf1c}', LV),
+    ('\u{cf1d}', '\u{cf37}', LVT),
+    ('\u{cf38}', '\u{cf38}', LV),
+    ('\u{cf39}', '\u{cf53}', LVT),
+    ('\u{cf54}', '\u{cf54}', LV),
+    ('\u{cf55}', '\u{cf6f}', LVT),
+    ('\u{cf70}', '\u{cf70}', LV),
+    ('\u{cf71}', '\u{cf8b}', LVT),
+    ('\u{cf8c}', '\u{cf8c}', LV),
+    ('\u{cf8d}', '\u{cfa7}', LVT),
+    ('\u{cfa8}', '\u{cfa8}', LV),
+    ('\u{cfa9}', '\u{cfc3}', LVT),
+    ('\u{cfc4}', '\u{cfc4}', LV),
+    ('\u{cfc5}', '\u{cfdf}', LVT),
+    ('\u{cfe0}', '\u{cfe0}', LV),
+    ('\u{cfe1}', '\u{cffb}', LVT),
+    ('\u{cffc}', '\u{cffc}', LV),
+    ('\u{cffd}', '\u{d017}', LVT),
+    ('\u{d018}', '\u{d018}', LV),
+    ('\u{d019}', '\u{d033}', LVT),
+    ('\u{d034}', '\u{d034}', LV),
+    ('\u{d035}', '\u{d04f}', LVT),
+    ('\u{d050}', '\u{d050}', LV),
+    ('\u{d051}', '\u{d06b}', LVT),
+    ('\u{d06c}', '\u{d06c}', LV),
+    ('\u{d06d}', '\u{d087}', LVT),
+    ('\u{d088}', '\u{d088}', LV),
+    ('\u{d089}', '\u{d0a3}', LVT),
+    ('\u{d0a4}', '\u{d0a4}', LV),
+    ('\u{d0a5}', '\u{d0bf}', LVT),
+    ('\u{d0c0}', '\u{d0c0}', LV),
+    ('\u{d0c1}', '\u{d0db}', LVT),
+    ('\u{d0dc}', '\u{d0dc}', LV),
+    ('\u{d0dd}', '\u{d0f7}', LVT),
+    ('\u{d0f8}', '\u{d0f8}', LV),
+    ('\u{d0f9}', '\u{d113}', LVT),
+    ('\u{d114}', '\u{d114}', LV),
+    ('\u{d115}', '\u{d12f}', LVT),
+    ('\u{d130}', '\u{d130}', LV),
+    ('\u{d131}', '\u{d14b}', LVT),
+    ('\u{d14c}', '\u{d14c}', LV),
+    ('\u{d14d}', '\u{d167}', LVT),
+    ('\u{d168}', '\u{d168}', LV),
+    ('\u{d169}', '\u{d183}', LVT),
+    ('\u{d184}', '\u{d184}', LV),
+    ('\u{d185}', '\u{d19f}', LVT),
+    ('\u{d1a0}', '\u{d1a0}', LV),
+    ('\u{d1a1}', '\u{d1bb}', LVT),
+    ('\u{d1bc}', '\u{d1bc}', LV),
+    ('\u{d1bd}', '\u{d1d7}', LVT),
+    ('\u{d1d8}', '\u{d1d8}', LV),
+    ('\u{d1d9}', '\u{d1f3}', LVT),
+    ('\u{d1f4}', '\u{d1f4}', LV),
+    ('\u{d1f5}', '\u{d20f}', LVT),
+    ('\u{d210}', '\u{d210}', LV),
+    ('\u{d211}', '\u{d22b}', LVT),
+    ('\u{d22c}', '\u{d22c}', LV),
+    ('\u{d22d}', '\u{d247}', LVT),
+    ('\u{d248}', '\u{d248}', LV),
+    ('\u{d249}', '\u{d263}', LVT),
+    ('\u{d264}', '\u{d264}', LV),
+    ('\u{d265}', '\u{d27f}', LVT),
+    ('\u{d280}', '\u{d280}', LV),
+    ('\u{d281}', '\u{d29b}', LVT),
+    ('\u{d29c}', '\u{d29c}', LV),
+    ('\u{d29d}', '\u{d2b7}', LVT),
+    ('\u{d2b8}', '\u{d2b8}', LV),
+    ('\u{d2b9}', '\u{d2d3}', LVT),
+    ('\u{d2d4}', '\u{d2d4}', LV),
+    ('\u{d2d5}', '\u{d2ef}', LVT),
+    ('\u{d2f0}', '\u{d2f0}', LV),
+    ('\u{d2f1}', '\u{d30b}', LVT),
+    ('\u{d30c}', '\u{d30c}', LV),
+    ('\u{d30d}', '\u{d327}', LVT),
+    ('\u{d328}', '\u{d328}', LV),
+    ('\u{d329}', '\u{d343}', LVT),
+    ('\u{d344}', '\u{d344}', LV),
+    ('\u{d345}', '\u{d35f}', LVT),
+    ('\u{d360}', '\u{d360}', LV),
+    ('\u{d361}', '\u{d37b}', LVT),
+    ('\u{d37c}', '\u{d37c}', LV),
+    ('\u{d37d}', '\u{d397}', LVT),
+    ('\u{d398}', '\u{d398}', LV),
+    ('\u{d399}', '\u{d3b3}', LVT),
+    ('\u{d3b4}', '\u{d3b4}', LV),
+    ('\u{d3b5}', '\u{d3cf}', LVT),
+    ('\u{d3d0}', '\u{d3d0}', LV),
+    ('\u{d3d1}', '\u{d3eb}', LVT),
+    ('\u{d3ec}', '\u{d3ec}', LV),
+    ('\u{d3ed}', '\u{d407}', LVT),
+    ('\u{d408}', '\u{d408}', LV),
+    ('\u{d409}', '\u{d423}', LVT),
+    ('\u{d424}', '\u{d424}', LV),
+    ('\u{d425}', '\u{d43f}', LVT),
+    ('\u{d440}', '\u{d440}', LV),
+    ('\u{d441}', '\u{d45b}', LVT),
+    ('\u{d45c}', '\u{d45c}', LV),
+    ('\u{d45d}', '\u{d477}', LVT),
+    ('\u{d478}', '\u{d478}', LV),
+    ('\u{d479}', '\u{d493}', LVT),
+    ('\u{d494}', '\u{d494}', LV),
+    ('\u{d495}', '\u{d4af}', LVT),
+    ('\u{d4b0}', '\u{d4b0}', LV),
+    ('\u{d4b1}', '\u{d4cb}', LVT),
+    ('\u{d4cc}', '\u{d4cc}', LV),
+    ('\u{d4cd}', '\u{d4e7}', LVT),
+    ('\u{d4e8}', '\u{d4e8}', LV),
+    ('\u{d4e9}', '\u{d503}', LVT),
+    ('\u{d504}', '\u{d504}', LV),
+    ('\u{d505}', '\u{d51f}', LVT),
+    ('\u{d520}', '\u{d520}', LV),
+    ('\u{d521}', '\u{d53b}', LVT),
+    ('\u{d53c}', '\u{d53c}', LV),
+    ('\u{d53d}', '\u{d557}', LVT),
+    ('\u{d558}', '\u{d558}', LV),
+    ('\u{d559}', '\u{d573}', LVT),
+    ('\u{d574}', '\u{d574}', LV),
+    ('\u{d575}', '\u{d58f}', LVT),
+    ('\u{d590}', '\u{d590}', LV),
+    ('\u{d591}', '\u{d5ab}', LVT),
+    ('\u{d5ac}', '\u{d5ac}', LV),
+    ('\u{d5ad}', '\u{d5c7}', LVT),
+    ('\u{d5c8}', '\u{d5c8}', LV),
+    ('\u{d5c9}', '\u{d5e3}', LVT),
+    ('\u{d5e4}', '\u{d5e4}', LV),
+    ('\u{d5e5}', '\u{d5ff}', LVT),
+    ('\u{d600}', '\u{d600}', LV),
+    ('\u{d601}', '\u{d61b}', LVT),
+    ('\u{d61c}', '\u{d61c}', LV),
+    ('\u{d61d}', '\u{d637}', LVT),
+    ('\u{d638}', '\u{d638}', LV),
+    ('\u{d639}', '\u{d653}', LVT),
+    ('\u{d654}', '\u{d654}', LV),
+    ('\u{d655}', '\u{d66f}', LVT),
+    ('\u{d670}', '\u{d670}', LV),
+    ('\u{d671}', '\u{d68b}', LVT),
+    ('\u{d68c}', '\u{d68c}', LV),
+    ('\u{d68d}', '\u{d6a7}', LVT),
+    ('\u{d6a8}', '\u{d6a8}', LV),
+    ('\u{d6a9}', '\u{d6c3}', LVT),
+    ('\u{d6c4}', '\u{d6c4}', LV),
+    ('\u{d6c5}', '\u{d6df}', LVT),
+    ('\u{d6e0}', '\u{d6e0}', LV),
+    ('\u{d6e1}', '\u{d6fb}', LVT),
+    ('\u{d6fc}', '\u{d6fc}', LV),
+    ('\u{d6fd}', '\u{d717}', LVT),
+    ('\u{d718}', '\u{d718}', LV),
+    ('\u{d719}', '\u{d733}', LVT),
+    ('\u{d734}', '\u{d734}', LV),
+    ('\u{d735}', '\u{d74f}', LVT),
+    ('\u{d750}', '\u{d750}', LV),
+    ('\u{d751}', '\u{d76b}', LVT),
+    ('\u{d76c}', '\u{d76c}', LV),
+    ('\u{d76d}', '\u{d787}', LVT),
+    ('\u{d788}', '\u{d788}', LV),
+    ('\u{d789}', '\u{d7a3}', LVT),
+    ('\u{d7b0}', '\u{d7c6}', V),
+    ('\u{d7cb}', '\u{d7fb}', T),
+    ('\u{fb1e}', '\u{fb1e}', Extend),
+    ('\u{fe00}', '\u{fe0f}', Extend),
+    ('\u{fe20}', '\u{fe2f}', Extend),
+    ('\u{feff}', '\u{feff}', Control),
+    ('\u{ff9e}', '\u{ff9f}', Extend),
+    ('\u{fff0}', '\u{fffb}', Control),
+    ('\u{101fd}', '\u{101fd}', Extend),
+    ('\u{102e0}', '\u{102e0}', Extend),
+    ('\u{10376}', '\u{1037a}', Extend),
+    ('\u{10a01}', '\u{10a03}', Extend),
+    ('\u{10a05}', '\u{10a06}', Extend),
+    ('\u{10a0c}', '\u{10a0f}', Extend),
+    ('\u{10a38}', '\u{10a3a}', Extend),
+    ('\u{10a3f}', '\u{10a3f}', Extend),
+    ('\u{10ae5}', '\u{10ae6}', Extend),
+    ('\u{10d24}', '\u{10d27}', Extend),
+    ('\u{10d69}', '\u{10d6d}', Extend),
+    ('\u{10eab}', '\u{10eac}', Extend),
+    ('\u{10efc}', '\u{10eff}', Extend),
+    ('\u{10f46}', '\u{10f50}', Extend),
+    ('\u{10f82}', '\u{10f85}', Extend),
+    ('\u{11000}', '\u{11000}', SpacingMark),
+    ('\u{11001}', '\u{11001}', Extend),
+    ('\u{11002}', '\u{11002}', SpacingMark),
+    ('\u{11038}', '\u{11046}', Extend),
+    ('\u{11070}', '\u{11070}', Extend),
+    ('\u{11073}', '\u{11074}', Extend),
+    ('\u{1107f}', '\u{11081}', Extend),
+    ('\u{11082}', '\u{11082}', SpacingMark),
+    ('\u{110b0}', '\u{110b2}', SpacingMark),
+    ('\u{110b3}', '\u{110b6}', Extend),
+    ('\u{110b7}', '\u{110b8}', SpacingMark),
+    ('\u{110b9}', '\u{110ba}', Extend),
+    ('\u{110bd}', '\u{110bd}', Prepend),
+    ('\u{110c2}', '\u{110c2}', Extend),
+    ('\u{110cd}', '\u{110cd}', Prepend),
+    ('\u{11100}', '\u{11102}', Extend),
+    ('\u{11127}', '\u{1112b}', Extend),
+    ('\u{1112c}', '\u{1112c}', SpacingMark),
+    ('\u{1112d}', '\u{11134}', Extend),
+    ('\u{11145}', '\u{11146}', SpacingMark),
+    ('\u{11173}', '\u{11173}', Extend),
+    ('\u{11180}', '\u{11181}', Extend),
+    ('\u{11182}', '\u{11182}', SpacingMark),
+    ('\u{111b3}', '\u{111b5}', SpacingMark),
+    ('\u{111b6}', '\u{111be}', Extend),
+    ('\u{111bf}', '\u{111bf}', SpacingMark),
+    ('\u{111c0}', '\u{111c0}', Extend),
+    ('\u{111c2}', '\u{111c3}', Prepend),
+    ('\u{111c9}', '\u{111cc}', Extend),
+    ('\u{111ce}', '\u{111ce}', SpacingMark),
+    ('\u{111cf}', '\u{111cf}', Extend),
+    ('\u{1122c}', '\u{1122e}', SpacingMark),
+    ('\u{1122f}', '\u{11231}', Extend),
+    ('\u{11232}', '\u{11233}', SpacingMark),
+    ('\u{11234}', '\u{11237}', Extend),
+    ('\u{1123e}', '\u{1123e}', Extend),
+    ('\u{11241}', '\u{11241}', Extend),
+    ('\u{112df}', '\u{112df}', Extend),
+    ('\u{112e0}', '\u{112e2}', SpacingMark),
+    ('\u{112e3}', '\u{112ea}', Extend),
+    ('\u{11300}', '\u{11301}', Extend),
+    ('\u{11302}', '\u{11303}', SpacingMark),
+    ('\u{1133b}', '\u{1133c}', Extend),
+    ('\u{1133e}', '\u{1133e}', Extend),
+    ('\u{1133f}', '\u{1133f}', SpacingMark),
+    ('\u{11340}', '\u{11340}', Extend),
+    ('\u{11341}', '\u{11344}', SpacingMark),
+    ('\u{11347}', '\u{11348}', SpacingMark),
+    ('\u{1134b}', '\u{1134c}', SpacingMark),
+    ('\u{1134d}', '\u{1134d}', Extend),
+    ('\u{11357}', '\u{11357}', Extend),
+    ('\u{11362}', '\u{11363}', SpacingMark),
+    ('\u{11366}', '\u{1136c}', Extend),
+    ('\u{11370}', '\u{11374}', Extend),
+    ('\u{113b8}', '\u{113b8}', Extend),
+    ('\u{113b9}', '\u{113ba}', SpacingMark),
+    ('\u{113bb}', '\u{113c0}', Extend),
+    ('\u{113c2}', '\u{113c2}', Extend),
+    ('\u{113c5}', '\u{113c5}', Extend),
+    ('\u{113c7}', '\u{113c9}', Extend),
+    ('\u{113ca}', '\u{113ca}', SpacingMark),
+    ('\u{113cc}', '\u{113cd}', SpacingMark),
+    ('\u{113ce}', '\u{113d0}', Extend),
+    ('\u{113d1}', '\u{113d1}', Prepend),
+    ('\u{113d2}', '\u{113d2}', Extend),
+    ('\u{113e1}', '\u{113e2}', Extend),
+    ('\u{11435}', '\u{11437}', SpacingMark),
+    ('\u{11438}', '\u{1143f}', Extend),
+    ('\u{11440}', '\u{11441}', SpacingMark),
+    ('\u{11442}', '\u{11444}', Extend),
+    ('\u{11445}', '\u{11445}', SpacingMark),
+    ('\u{11446}', '\u{11446}', Extend),
+    ('\u{1145e}', '\u{1145e}', Extend),
+    ('\u{114b0}', '\u{114b0}', Extend),
+    ('\u{114b1}', '\u{114b2}', SpacingMark),
+    ('\u{114b3}', '\u{114b8}', Extend),
+    ('\u{114b9}', '\u{114b9}', SpacingMark),
+    ('\u{114ba}', '\u{114ba}', Extend),
+    ('\u{114bb}', '\u{114bc}', SpacingMark),
+    ('\u{114bd}', '\u{114bd}', Extend),
+    ('\u{114be}', '\u{114be}', SpacingMark),
+    ('\u{114bf}', '\u{114c0}', Extend),
+    ('\u{114c1}', '\u{114c1}', SpacingMark),
+    ('\u{114c2}', '\u{114c3}', Extend),
+    ('\u{115af}', '\u{115af}', Extend),
+    ('\u{115b0}', '\u{115b1}', SpacingMark),
+    ('\u{115b2}', '\u{115b5}', Extend),
+    ('\u{115b8}', '\u{115bb}', SpacingMark),
+    ('\u{115bc}', '\u{115bd}', Extend),
+    ('\u{115be}', '\u{115be}', SpacingMark),
+    ('\u{115bf}', '\u{115c0}', Extend),
+    ('\u{115dc}', '\u{115dd}', Extend),
+    ('\u{11630}', '\u{11632}', SpacingMark),
+    ('\u{11633}', '\u{1163a}', Extend),
+    ('\u{1163b}', '\u{1163c}', SpacingMark),
+    ('\u{1163d}', '\u{1163d}', Extend),
+    ('\u{1163e}', '\u{1163e}', SpacingMark),
+    ('\u{1163f}', '\u{11640}', Extend),
+    ('\u{116ab}', '\u{116ab}', Extend),
+    ('\u{116ac}', '\u{116ac}', SpacingMark),
+    ('\u{116ad}', '\u{116ad}', Extend),
+    ('\u{116ae}', '\u{116af}', SpacingMark),
+    ('\u{116b0}', '\u{116b7}', Extend),
+    ('\u{1171d}', '\u{1171d}', Extend),
+    ('\u{1171e}', '\u{1171e}', SpacingMark),
+    ('\u{1171f}', '\u{1171f}', Extend),
+    ('\u{11722}', '\u{11725}', Extend),
+    ('\u{11726}', '\u{11726}', SpacingMark),
+    ('\u{11727}', '\u{1172b}', Extend),
+    ('\u{1182c}', '\u{1182e}', SpacingMark),
+    ('\u{1182f}', '\u{11837}', Extend),
+    ('\u{11838}', '\u{11838}', SpacingMark),
+    ('\u{11839}', '\u{1183a}', Extend),
+    ('\u{11930}', '\u{11930}', Extend),
+    ('\u{11931}', '\u{11935}', SpacingMark),
+    ('\u{11937}', '\u{11938}', SpacingMark),
+    ('\u{1193b}', '\u{1193e}', Extend),
+    ('\u{1193f}', '\u{1193f}', Prepend),
+    ('\u{11940}', '\u{11940}', SpacingMark),
+    ('\u{11941}', '\u{11941}', Prepend),
+    ('\u{11942}', '\u{11942}', SpacingMark),
+    ('\u{11943}', '\u{11943}', Extend),
+    ('\u{119d1}', '\u{119d3}', SpacingMark),
+    ('\u{119d4}', '\u{119d7}', Extend),
+    ('\u{119da}', '\u{119db}', Extend),
+    ('\u{119dc}', '\u{119df}', SpacingMark),
+    ('\u{119e0}', '\u{119e0}', Extend),
+    ('\u{119e4}', '\u{119e4}', SpacingMark),
+    ('\u{11a01}', '\u{11a0a}', Extend),
+    ('\u{11a33}', '\u{11a38}', Extend),
+    ('\u{11a39}', '\u{11a39}', SpacingMark),
+    ('\u{11a3a}', '\u{11a3a}', Prepend),
+    ('\u{11a3b}', '\u{11a3e}', Extend),
+    ('\u{11a47}', '\u{11a47}', Extend),
+    ('\u{11a51}', '\u{11a56}', Extend),
+    ('\u{11a57}', '\u{11a58}', SpacingMark),
+    ('\u{11a59}', '\u{11a5b}', Extend),
+    ('\u{11a84}', '\u{11a89}', Prepend),
+    ('\u{11a8a}', '\u{11a96}', Extend),
+    ('\u{11a97}', '\u{11a97}', SpacingMark),
+    ('\u{11a98}', '\u{11a99}', Extend),
+    ('\u{11c2f}', '\u{11c2f}', SpacingMark),
+    ('\u{11c30}', '\u{11c36}', Extend),
+    ('\u{11c38}', '\u{11c3d}', Extend),
+    ('\u{11c3e}', '\u{11c3e}', SpacingMark),
+    ('\u{11c3f}', '\u{11c3f}', Extend),
+    ('\u{11c92}', '\u{11ca7}', Extend),
+    ('\u{11ca9}', '\u{11ca9}', SpacingMark),
+    ('\u{11caa}', '\u{11cb0}', Extend),
+    ('\u{11cb1}', '\u{11cb1}', SpacingMark),
+    ('\u{11cb2}', '\u{11cb3}', Extend),
+    ('\u{11cb4}', '\u{11cb4}', SpacingMark),
+    ('\u{11cb5}', '\u{11cb6}', Extend),
+    ('\u{11d31}', '\u{11d36}', Extend),
+    ('\u{11d3a}', '\u{11d3a}', Extend),
+    ('\u{11d3c}', '\u{11d3d}', Extend),
+    ('\u{11d3f}', '\u{11d45}', Extend),
+    ('\u{11d46}', '\u{11d46}', Prepend),
+    ('\u{11d47}', '\u{11d47}', Extend),
+    ('\u{11d8a}', '\u{11d8e}', SpacingMark),
+    ('\u{11d90}', '\u{11d91}', Extend),
+    ('\u{11d93}', '\u{11d94}', SpacingMark),
+    ('\u{11d95}', '\u{11d95}', Extend),
+    ('\u{11d96}', '\u{11d96}', SpacingMark),
+    ('\u{11d97}', '\u{11d97}', Extend),
+    ('\u{11ef3}', '\u{11ef4}', Extend),
+    ('\u{11ef5}', '\u{11ef6}', SpacingMark),
+    ('\u{11f00}', '\u{11f01}', Extend),
+    ('\u{11f02}', '\u{11f02}', Prepend),
+    ('\u{11f03}', '\u{11f03}', SpacingMark),
+    ('\u{11f34}', '\u{11f35}', SpacingMark),
+    ('\u{11f36}', '\u{11f3a}', Extend),
+    ('\u{11f3e}', '\u{11f3f}', SpacingMark),
+    ('\u{11f40}', '\u{11f42}', Extend),
+    ('\u{11f5a}', '\u{11f5a}', Extend),
+    ('\u{13430}', '\u{1343f}', Control),
+    ('\u{13440}', '\u{13440}', Extend),
+    ('\u{13447}', '\u{13455}', Extend),
+    ('\u{1611e}', '\u{16129}', Extend),
+    ('\u{1612a}', '\u{1612c}', SpacingMark),
+    ('\u{1612d}', '\u{1612f}', Extend),
+    ('\u{16af0}', '\u{16af4}', Extend),
+    ('\u{16b30}', '\u{16b36}', Extend),
+    ('\u{16d63}', '\u{16d63}', V),
+    ('\u{16d67}', '\u{16d6a}', V),
+    ('\u{16f4f}', '\u{16f4f}', Extend),
+    ('\u{16f51}', '\u{16f87}', SpacingMark),
+    ('\u{16f8f}', '\u{16f92}', Extend),
+    ('\u{16fe4}', '\u{16fe4}', Extend),
+    ('\u{16ff0}', '\u{16ff1}', Extend),
+    ('\u{1bc9d}', '\u{1bc9e}', Extend),
+    ('\u{1bca0}', '\u{1bca3}', Control),
+    ('\u{1cf00}', '\u{1cf2d}', Extend),
+    ('\u{1cf30}', '\u{1cf46}', Extend),
+    ('\u{1d165}', '\u{1d169}', Extend),
+    ('\u{1d16d}', '\u{1d172}', Extend),
+    ('\u{1d173}', '\u{1d17a}', Control),
+    ('\u{1d17b}', '\u{1d182}', Extend),
+    ('\u{1d185}', '\u{1d18b}', Extend),
+    ('\u{1d1aa}', '\u{1d1ad}', Extend),
+    ('\u{1d242}', '\u{1d244}', Extend),
+    ('\u{1da00}', '\u{1da36}', Extend),
+    ('\u{1da3b}', '\u{1da6c}', Extend),
+    ('\u{1da75}', '\u{1da75}', Extend),
+    ('\u{1da84}', '\u{1da84}', Extend),
+    ('\u{1da9b}', '\u{1da9f}', Extend),
+    ('\u{1daa1}', '\u{1daaf}', Extend),
+    ('\u{1e000}', '\u{1e006}', Extend),
+    ('\u{1e008}', '\u{1e018}', Extend),
+    ('\u{1e01b}', '\u{1e021}', Extend),
+    ('\u{1e023}', '\u{1e024}', Extend),
+    ('\u{1e026}', '\u{1e02a}', Extend),
+    ('\u{1e08f}', '\u{1e08f}', Extend),
+    ('\u{1e130}', '\u{1e136}', Extend),
+    ('\u{1e2ae}', '\u{1e2ae}', Extend),
+    ('\u{1e2ec}', '\u{1e2ef}', Extend),
+    ('\u{1e4ec}', '\u{1e4ef}', Extend),
+    ('\u{1e5ee}', '\u{1e5ef}', Extend),
+    ('\u{1e8d0}', '\u{1e8d6}', Extend),
+    ('\u{1e944}', '\u{1e94a}', Extend),
+    ('\u{1f000}', '\u{1f0ff}', ExtendedPictographic),
+    ('\u{1f10d}', '\u{1f10f}', ExtendedPictographic),
+    ('\u{1f12f}', '\u{1f12f}', ExtendedPictographic),
+    ('\u{1f16c}', '\u{1f171}', ExtendedPictographic),
+    ('\u{1f17e}', '\u{1f17f}', ExtendedPictographic),
+    ('\u{1f18e}', '\u{1f18e}', ExtendedPictographic),
+    ('\u{1f191}', '\u{1f19a}', ExtendedPictographic),
+    ('\u{1f1ad}', '\u{1f1e5}', ExtendedPictographic),
+    ('\u{1f1e6}', '\u{1f1ff}', RegionalIndicator),
+    ('\u{1f201}', '\u{1f20f}', ExtendedPictographic),
+    ('\u{1f21a}', '\u{1f21a}', ExtendedPictographic),
+    ('\u{1f22f}', '\u{1f22f}', ExtendedPictographic),
+    ('\u{1f232}', '\u{1f23a}', ExtendedPictographic),
+    ('\u{1f23c}', '\u{1f23f}', ExtendedPictographic),
+    ('\u{1f249}', '\u{1f3fa}', ExtendedPictographic),
+    ('\u{1f3fb}', '\u{1f3ff}', Extend),
+    ('\u{1f400}', '\u{1f53d}', ExtendedPictographic),
+    ('\u{1f546}', '\u{1f64f}', ExtendedPictographic),
+    ('\u{1f680}', '\u{1f6ff}', ExtendedPictographic),
+    ('\u{1f774}', '\u{1f77f}', ExtendedPictographic),
+    ('\u{1f7d5}', '\u{1f7ff}', ExtendedPictographic),
+    ('\u{1f80c}', '\u{1f80f}', ExtendedPictographic),
+    ('\u{1f848}', '\u{1f84f}', ExtendedPictographic),
+    ('\u{1f85a}', '\u{1f85f}', ExtendedPictographic),
+    ('\u{1f888}', '\u{1f88f}', ExtendedPictographic),
+    ('\u{1f8ae}', '\u{1f8ff}', ExtendedPictographic),
+    ('\u{1f90c}', '\u{1f93a}', ExtendedPictographic),
+    ('\u{1f93c}', '\u{1f945}', ExtendedPictographic),
+    ('\u{1f947}', '\u{1faff}', ExtendedPictographic),
+    ('\u{1fc00}', '\u{1fffd}', ExtendedPictographic),
+    ('\u{e0000}', '\u{e001f}', Control),
+    ('\u{e0020}', '\u{e007f}', Extend),
+    ('\u{e0080}', '\u{e00ff}', Control),
+    ('\u{e0100}', '\u{e01ef}', Extend),
+    ('\u{e01f0}', '\u{e0fff}', Control),
+];
+
+// InCB=Linker: the viramas that glue two consonants into a conjunct (GB9c)
+pub const INCB_LINKER: &[char] = &[
+    '\u{94d}', '\u{9cd}', '\u{acd}', '\u{b4d}', '\u{c4d}', '\u{d4d}',
+];
+
+pub const INCB_EXTEND_TABLE: &[(char, char)] = &[
+    ('\u{300}', '\u{36f}'),
+    ('\u{483}', '\u{489}'),
+    ('\u{591}', '\u{5bd}'),
+    ('\u{5bf}', '\u{5bf}'),
+    ('\u{5c1}', '\u{5c2}'),
+    ('\u{5c4}', '\u{5c5}'),
+    ('\u{5c7}', '\u{5c7}'),
+    ('\u{610}', '\u{61a}'),
+    ('\u{64b}', '\u{65f}'),
+    ('\u{670}', '\u{670}'),
+    ('\u{6d6}', '\u{6dc}'),
+    ('\u{6df}', '\u{6e4}'),
+    ('\u{6e7}', '\u{6e8}'),
+    ('\u{6ea}', '\u{6ed}'),
+    ('\u{711}', '\u{711}'),
+    ('\u{730}', '\u{74a}'),
+    ('\u{7a6}', '\u{7b0}'),
+    ('\u{7eb}', '\u{7f3}'),
+    ('\u{7fd}', '\u{7fd}'),
+    ('\u{816}', '\u{819}'),
+    ('\u{81b}', '\u{823}'),
+    ('\u{825}', '\u{827}'),
+    ('\u{829}', '\u{82d}'),
+    ('\u{859}', '\u{85b}'),
+    ('\u{897}', '\u{89f}'),
+    ('\u{8ca}', '\u{8e1}'),
+    ('\u{8e3}', '\u{902}'),
+    ('\u{93a}', '\u{93a}'),
+    ('\u{93c}', '\u{93c}'),
+    ('\u{941}', '\u{948}'),
+    ('\u{951}', '\u{957}'),
+    ('\u{962}', '\u{963}'),
+    ('\u{981}', '\u{981}'),
+    ('\u{9bc}', '\u{9bc}'),
+    ('\u{9be}', '\u{9be}'),
+    ('\u{9c1}', '\u{9c4}'),
+    ('\u{9d7}', '\u{9d7}'),
+    ('\u{9e2}', '\u{9e3}'),
+    ('\u{9fe}', '\u{9fe}'),
+    ('\u{a01}', '\u{a02}'),
+    ('\u{a3c}', '\u{a3c}'),
+    ('\u{a41}', '\u{a42}'),
+    ('\u{a47}', '\u{a48}'),
+    ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'),
+    ('\u{a70}', '\u{a71}'),
+    ('\u{a75}', '\u{a75}'),
+    ('\u{a81}', '\u{a82}'),
+    ('\u{abc}', '\u{abc}'),
+    ('\u{ac1}', '\u{ac5}'),
+    ('\u{ac7}', '\u{ac8}'),
+    ('\u{ae2}', '\u{ae3}'),
+    ('\u{afa}', '\u{aff}'),
+    ('\u{b01}', '\u{b01}'),
+    ('\u{b3c}', '\u{b3c}'),
+    ('\u{b3e}', '\u{b3f}'),
+    ('\u{b41}', '\u{b44}'),
+    ('\u{b55}', '\u{b57}'),
+    ('\u{b62}', '\u{b63}'),
+    ('\u{b82}', '\u{b82}'),
+    ('\u{bbe}', '\u{bbe}'),
+    ('\u{bc0}', '\u{bc0}'),
+    ('\u{bcd}', '\u{bcd}'),
+    ('\u{bd7}', '\u{bd7}'),
+    ('\u{c00}', '\u{c00}'),
+    ('\u{c04}', '\u{c04}'),
+    ('\u{c3c}', '\u{c3c}'),
+    ('\u{c3e}', '\u{c40}'),
+    ('\u{c46}', '\u{c48}'),
+    ('\u{c4a}', '\u{c4c}'),
+    ('\u{c55}', '\u{c56}'),
+    ('\u{c62}', '\u{c63}'),
+    ('\u{c81}', '\u{c81}'),
+    ('\u{cbc}', '\u{cbc}'),
+    ('\u{cbf}', '\u{cc0}'),
+    ('\u{cc2}', '\u{cc2}'),
+    ('\u{cc6}', '\u{cc8}'),
+    ('\u{cca}', '\u{ccd}'),
+    ('\u{cd5}', '\u{cd6}'),
+    ('\u{ce2}', '\u{ce3}'),
+    ('\u{d00}', '\u{d01}'),
+    ('\u{d3b}', '\u{d3c}'),
+    ('\u{d3e}', '\u{d3e}'),
+    ('\u{d41}', '\u{d44}'),
+    ('\u{d57}', '\u{d57}'),
+    ('\u{d62}', '\u{d63}'),
+    ('\u{d81}', '\u{d81}'),
+    ('\u{dca}', '\u{dca}'),
+    ('\u{dcf}', '\u{dcf}'),
+    ('\u{dd2}', '\u{dd4}'),
+    ('\u{dd6}', '\u{dd6}'),
+    ('\u{ddf}', '\u{ddf}'),
+    ('\u{e31}', '\u{e31}'),
+    ('\u{e34}', '\u{e3a}'),
+    ('\u{e47}', '\u{e4e}'),
+    ('\u{eb1}', '\u{eb1}'),
+    ('\u{eb4}', '\u{ebc}'),
+    ('\u{ec8}', '\u{ece}'),
+    ('\u{f18}', '\u{f19}'),
+    ('\u{f35}', '\u{f35}'),
+    ('\u{f37}', '\u{f37}'),
+    ('\u{f39}', '\u{f39}'),
+    ('\u{f71}', '\u{f7e}'),
+    ('\u{f80}', '\u{f84}'),
+    ('\u{f86}', '\u{f87}'),
+    ('\u{f8d}', '\u{f97}'),
+    ('\u{f99}', '\u{fbc}'),
+    ('\u{fc6}', '\u{fc6}'),
+    ('\u{102d}', '\u{1030}'),
+    ('\u{1032}', '\u{1037}'),
+    ('\u{1039}', '\u{103a}'),
+    ('\u{103d}', '\u{103e}'),
+    ('\u{1058}', '\u{1059}'),
+    ('\u{105e}', '\u{1060}'),
+    ('\u{1071}', '\u{1074}'),
+    ('\u{1082}', '\u{1082}'),
+    ('\u{1085}', '\u{1086}'),
+    ('\u{108d}', '\u{108d}'),
+    ('\u{109d}', '\u{109d}'),
+    ('\u{135d}', '\u{135f}'),
+    ('\u{1712}', '\u{1715}'),
+    ('\u{1732}', '\u{1734}'),
+    ('\u{1752}', '\u{1753}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{17b4}', '\u{17b5}'),
+    ('\u{17b7}', '\u{17bd}'),
+    ('\u{17c6}', '\u{17c6}'),
+    ('\u{17c9}', '\u{17d3}'),
+    ('\u{17dd}', '\u{17dd}'),
+    ('\u{180b}', '\u{180d}'),
+    ('\u{180f}', '\u{180f}'),
+    ('\u{1885}', '\u{1886}'),
+    ('\u{18a9}', '\u{18a9}'),
+    ('\u{1920}', '\u{1922}'),
+    ('\u{1927}', '\u{1928}'),
+    ('\u{1932}', '\u{1932}'),
+    ('\u{1939}', '\u{193b}'),
+    ('\u{1a17}', '\u{1a18}'),
+    ('\u{1a1b}', '\u{1a1b}'),
+    ('\u{1a56}', '\u{1a56}'),
+    ('\u{1a58}', '\u{1a5e}'),
+    ('\u{1a60}', '\u{1a60}'),
+    ('\u{1a62}', '\u{1a62}'),
+    ('\u{1a65}', '\u{1a6c}'),
+    ('\u{1a73}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a7f}'),
+    ('\u{1ab0}', '\u{1ace}'),
+    ('\u{1b00}', '\u{1b03}'),
+    ('\u{1b34}', '\u{1b3d}'),
+    ('\u{1b42}', '\u{1b44}'),
+    ('\u{1b6b}', '\u{1b73}'),
+    ('\u{1b80}', '\u{1b81}'),
+    ('\u{1ba2}', '\u{1ba5}'),
+    ('\u{1ba8}', '\u{1bad}'),
+    ('\u{1be6}', '\u{1be6}'),
+    ('\u{1be8}', '\u{1be9}'),
+    ('\u{1bed}', '\u{1bed}'),
+    ('\u{1bef}', '\u{1bf3}'),
+    ('\u{1c2c}', '\u{1c33}'),
+    ('\u{1c36}', '\u{1c37}'),
+    ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1ce0}'),
+    ('\u{1ce2}', '\u{1ce8}'),
+    ('\u{1ced}', '\u{1ced}'),
+    ('\u{1cf4}', '\u{1cf4}'),
+    ('\u{1cf8}', '\u{1cf9}'),
+    ('\u{1dc0}', '\u{1dff}'),
+    ('\u{200d}', '\u{200d}'),
+    ('\u{20d0}', '\u{20f0}'),
+    ('\u{2cef}', '\u{2cf1}'),
+    ('\u{2d7f}', '\u{2d7f}'),
+    ('\u{2de0}', '\u{2dff}'),
+    ('\u{302a}', '\u{302f}'),
+    ('\u{3099}', '\u{309a}'),
+    ('\u{a66f}', '\u{a672}'),
+    ('\u{a674}', '\u{a67d}'),
+    ('\u{a69e}', '\u{a69f}'),
+    ('\u{a6f0}', '\u{a6f1}'),
+    ('\u{a802}', '\u{a802}'),
+    ('\u{a806}', '\u{a806}'),
+    ('\u{a80b}', '\u{a80b}'),
+    ('\u{a825}', '\u{a826}'),
+    ('\u{a82c}', '\u{a82c}'),
+    ('\u{a8c4}', '\u{a8c5}'),
+    ('\u{a8e0}', '\u{a8f1}'),
+    ('\u{a8ff}', '\u{a8ff}'),
+    ('\u{a926}', '\u{a92d}'),
+    ('\u{a947}', '\u{a951}'),
+    ('\u{a953}', '\u{a953}'),
+    ('\u{a980}', '\u{a982}'),
+    ('\u{a9b3}', '\u{a9b3}'),
+    ('\u{a9b6}', '\u{a9b9}'),
+    ('\u{a9bc}', '\u{a9bd}'),
+    ('\u{a9c0}', '\u{a9c0}'),
+    ('\u{a9e5}', '\u{a9e5}'),
+    ('\u{aa29}', '\u{aa2e}'),
+    ('\u{aa31}', '\u{aa32}'),
+    ('\u{aa35}', '\u{aa36}'),
+    ('\u{aa43}', '\u{aa43}'),
+    ('\u{aa4c}', '\u{aa4c}'),
+    ('\u{aa7c}', '\u{aa7c}'),
+    ('\u{aab0}', '\u{aab0}'),
+    ('\u{aab2}', '\u{aab4}'),
+    ('\u{aab7}', '\u{aab8}'),
+    ('\u{aabe}', '\u{aabf}'),
+    ('\u{aac1}', '\u{aac1}'),
+    ('\u{aaec}', '\u{aaed}'),
+    ('\u{aaf6}', '\u{aaf6}'),
+    ('\u{abe5}', '\u{abe5}'),
+    ('\u{abe8}', '\u{abe8}'),
+    ('\u{abed}', '\u{abed}'),
+    ('\u{fb1e}', '\u{fb1e}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'),
+    ('\u{ff9e}', '\u{ff9f}'),
+    ('\u{101fd}', '\u{101fd}'),
+    ('\u{102e0}', '\u{102e0}'),
+    ('\u{10376}', '\u{1037a}'),
+    ('\u{10a01}', '\u{10a03}'),
+    ('\u{10a05}', '\u{10a06}'),
+    ('\u{10a0c}', '\u{10a0f}'),
+    ('\u{10a38}', '\u{10a3a}'),
+    ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10ae5}', '\u{10ae6}'),
+    ('\u{10d24}', '\u{10d27}'),
+    ('\u{10d69}', '\u{10d6d}'),
+    ('\u{10eab}', '\u{10eac}'),
+    ('\u{10efc}', '\u{10eff}'),
+    ('\u{10f46}', '\u{10f50}'),
+    ('\u{10f82}', '\u{10f85}'),
+    ('\u{11001}', '\u{11001}'),
+    ('\u{11038}', '\u{11046}'),
+    ('\u{11070}', '\u{11070}'),
+    ('\u{11073}', '\u{11074}'),
+    ('\u{1107f}', '\u{11081}'),
+    ('\u{110b3}', '\u{110b6}'),
+    ('\u{110b9}', '\u{110ba}'),
+    ('\u{110c2}', '\u{110c2}'),
+    ('\u{11100}', '\u{11102}'),
+    ('\u{11127}', '\u{1112b}'),
+    ('\u{1112d}', '\u{11134}'),
+    ('\u{11173}', '\u{11173}'),
+    ('\u{11180}', '\u{11181}'),
+    ('\u{111b6}', '\u{111be}'),
+    ('\u{111c0}', '\u{111c0}'),
+    ('\u{111c9}', '\u{111cc}'),
+    ('\u{111cf}', '\u{111cf}'),
+    ('\u{1122f}', '\u{11231}'),
+    ('\u{11234}', '\u{11237}'),
+    ('\u{1123e}', '\u{1123e}'),
+    ('\u{11241}', '\u{11241}'),
+    ('\u{112df}', '\u{112df}'),
+    ('\u{112e3}', '\u{112ea}'),
+    ('\u{11300}', '\u{11301}'),
+    ('\u{1133b}', '\u{1133c}'),
+    ('\u{1133e}', '\u{1133e}'),
+    ('\u{11340}', '\u{11340}'),
+    ('\u{1134d}', '\u{1134d}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{11366}', '\u{1136c}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{113b8}', '\u{113b8}'),
+    ('\u{113bb}', '\u{113c0}'),
+    ('\u{113c2}', '\u{113c2}'),
+    ('\u{113c5}', '\u{113c5}'),
+    ('\u{113c7}', '\u{113c9}'),
+    ('\u{113ce}', '\u{113d0}'),
+    ('\u{113d2}', '\u{113d2}'),
+    ('\u{113e1}', '\u{113e2}'),
+    ('\u{11438}', '\u{1143f}'),
+    ('\u{11442}', '\u{11444}'),
+    ('\u{11446}', '\u{11446}'),
+    ('\u{1145e}', '\u{1145e}'),
+    ('\u{114b0}', '\u{114b0}'),
+    ('\u{114b3}', '\u{114b8}'),
+    ('\u{114ba}', '\u{114ba}'),
+    ('\u{114bd}', '\u{114bd}'),
+    ('\u{114bf}', '\u{114c0}'),
+    ('\u{114c2}', '\u{114c3}'),
+    ('\u{115af}', '\u{115af}'),
+    ('\u{115b2}', '\u{115b5}'),
+    ('\u{115bc}', '\u{115bd}'),
+    ('\u{115bf}', '\u{115c0}'),
+    ('\u{115dc}', '\u{115dd}'),
+    ('\u{11633}', '\u{1163a}'),
+    ('\u{1163d}', '\u{1163d}'),
+    ('\u{1163f}', '\u{11640}'),
+    ('\u{116ab}', '\u{116ab}'),
+    ('\u{116ad}', '\u{116ad}'),
+    ('\u{116b0}', '\u{116b7}'),
+    ('\u{1171d}', '\u{1171d}'),
+    ('\u{1171f}', '\u{1171f}'),
+    ('\u{11722}', '\u{11725}'),
+    ('\u{11727}', '\u{1172b}'),
+    ('\u{1182f}', '\u{11837}'),
+    ('\u{11839}', '\u{1183a}'),
+    ('\u{11930}', '\u{11930}'),
+    ('\u{1193b}', '\u{1193e}'),
+    ('\u{11943}', '\u{11943}'),
+    ('\u{119d4}', '\u{119d7}'),
+    ('\u{119da}', '\u{119db}'),
+    ('\u{119e0}', '\u{119e0}'),
+    ('\u{11a01}', '\u{11a0a}'),
+    ('\u{11a33}', '\u{11a38}'),
+    ('\u{11a3b}', '\u{11a3e}'),
+    ('\u{11a47}', '\u{11a47}'),
+    ('\u{11a51}', '\u{11a56}'),
+    ('\u{11a59}', '\u{11a5b}'),
+    ('\u{11a8a}', '\u{11a96}'),
+    ('\u{11a98}', '\u{11a99}'),
+    ('\u{11c30}', '\u{11c36}'),
+    ('\u{11c38}', '\u{11c3d}'),
+    ('\u{11c3f}', '\u{11c3f}'),
+    ('\u{11c92}', '\u{11ca7}'),
+    ('\u{11caa}', '\u{11cb0}'),
+    ('\u{11cb2}', '\u{11cb3}'),
+    ('\u{11cb5}', '\u{11cb6}'),
+    ('\u{11d31}', '\u{11d36}'),
+    ('\u{11d3a}', '\u{11d3a}'),
+    ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d45}'),
+    ('\u{11d47}', '\u{11d47}'),
+    ('\u{11d90}', '\u{11d91}'),
+    ('\u{11d95}', '\u{11d95}'),
+    ('\u{11d97}', '\u{11d97}'),
+    ('\u{11ef3}', '\u{11ef4}'),
+    ('\u{11f00}', '\u{11f01}'),
+    ('\u{11f36}', '\u{11f3a}'),
+    ('\u{11f40}', '\u{11f42}'),
+    ('\u{11f5a}', '\u{11f5a}'),
+    ('\u{13440}', '\u{13440}'),
+    ('\u{13447}', '\u{13455}'),
+    ('\u{1611e}', '\u{16129}'),
+    ('\u{1612d}', '\u{1612f}'),
+    ('\u{16af0}', '\u{16af4}'),
+    ('\u{16b30}', '\u{16b36}'),
+    ('\u{16f4f}', '\u{16f4f}'),
+    ('\u{16f8f}', '\u{16f92}'),
+    ('\u{16fe4}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'),
+    ('\u{1bc9d}', '\u{1bc9e}'),
+    ('\u{1cf00}', '\u{1cf2d}'),
+    ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d165}', '\u{1d169}'),
+    ('\u{1d16d}', '\u{1d172}'),
+    ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{1d242}', '\u{1d244}'),
+    ('\u{1da00}', '\u{1da36}'),
+    ('\u{1da3b}', '\u{1da6c}'),
+    ('\u{1da75}', '\u{1da75}'),
+    ('\u{1da84}', '\u{1da84}'),
+    ('\u{1da9b}', '\u{1da9f}'),
+    ('\u{1daa1}', '\u{1daaf}'),
+    ('\u{1e000}', '\u{1e006}'),
+    ('\u{1e008}', '\u{1e018}'),
+    ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'),
+    ('\u{1e026}', '\u{1e02a}'),
+    ('\u{1e08f}', '\u{1e08f}'),
+    ('\u{1e130}', '\u{1e136}'),
+    ('\u{1e2ae}', '\u{1e2ae}'),
+    ('\u{1e2ec}', '\u{1e2ef}'),
+    ('\u{1e4ec}', '\u{1e4ef}'),
+    ('\u{1e5ee}', '\u{1e5ef}'),
+    ('\u{1e8d0}', '\u{1e8d6}'),
+    ('\u{1e944}', '\u{1e94a}'),
+    ('\u{1f3fb}', '\u{1f3ff}'),
+    ('\u{e0020}', '\u{e007f}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];
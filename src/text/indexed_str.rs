@@ -0,0 +1,262 @@
+#![allow(dead_code)]
+use super::grapheme;
+use std::fmt;
+use std::ops::Range;
+
+// chap_18_string: `&hello[0..1]` panics on "Здравствуйте" and there's no O(1) way to get the nth char.
+// IndexedStr pays the O(n) walk once, storing the byte offset of every char, and after that char_at/slice_chars
+// are just two table lookups.
+pub struct IndexedStr<'a> {
+    source: &'a str,
+    offsets: Vec<usize>, // offsets[i] = byte offset of char i, plus source.len() at the end as a sentinel
+    clusters: Vec<usize>, // same thing for grapheme clusters, see text::grapheme
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SliceError {
+    OutOfBounds { index: usize, len: usize },
+    NotCharBoundary { index: usize },
+    Reversed { start: usize, end: usize },
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::OutOfBounds { index, len } => {
+                write!(f, "index {index} is out of bounds (length is {len})")
+            }
+            SliceError::NotCharBoundary { index } => {
+                write!(f, "byte index {index} is not a char boundary")
+            }
+            SliceError::Reversed { start, end } => {
+                write!(f, "range start {start} is greater than its end {end}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SliceError {}
+
+impl<'a> IndexedStr<'a> {
+    pub fn new(source: &'a str) -> Self {
+        let mut offsets: Vec<usize> = source.char_indices().map(|(idx, _)| idx).collect();
+        offsets.push(source.len());
+        let clusters = grapheme::boundaries(source);
+        Self {
+            source,
+            offsets,
+            clusters,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.source
+    }
+
+    // number of chars (Unicode scalars), NOT bytes: "Здравствуйте" -> 12
+    pub fn char_len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    // number of user-perceived characters: "नमस्ते" -> 3 (see text::grapheme for why not 4)
+    pub fn grapheme_len(&self) -> usize {
+        self.clusters.len() - 1
+    }
+
+    pub fn byte_len(&self) -> usize {
+        self.source.len()
+    }
+
+    pub fn char_at(&self, i: usize) -> Option<char> {
+        self.char_str(i)?.chars().next()
+    }
+
+    // The ith char as a &str, handy when you want to keep borrowing from the source
+    pub fn char_str(&self, i: usize) -> Option<&'a str> {
+        if i >= self.char_len() {
+            return None;
+        }
+        Some(&self.source[self.offsets[i]..self.offsets[i + 1]])
+    }
+
+    // Byte offset of the ith char; i == char_len() gives the end of the string
+    pub fn byte_offset(&self, i: usize) -> Option<usize> {
+        self.offsets.get(i).copied()
+    }
+
+    // Maps a byte offset back to the char index, only if it lies on a boundary
+    pub fn char_index(&self, byte: usize) -> Option<usize> {
+        self.offsets.binary_search(&byte).ok()
+    }
+
+    // Slice by char indices. Panics like `&s[a..b]` does; use try_slice_chars for the Result version.
+    pub fn slice_chars(&self, range: Range<usize>) -> &'a str {
+        match self.try_slice_chars(range) {
+            Ok(s) => s,
+            Err(e) => panic!("slice_chars: {e}"),
+        }
+    }
+
+    pub fn try_slice_chars(&self, range: Range<usize>) -> Result<&'a str, SliceError> {
+        let len = self.char_len();
+        if range.start > range.end {
+            return Err(SliceError::Reversed {
+                start: range.start,
+                end: range.end,
+            });
+        }
+        if range.end > len {
+            return Err(SliceError::OutOfBounds {
+                index: range.end,
+                len,
+            });
+        }
+        Ok(&self.source[self.offsets[range.start]..self.offsets[range.end]])
+    }
+
+    pub fn grapheme_at(&self, i: usize) -> Option<&'a str> {
+        if i >= self.grapheme_len() {
+            return None;
+        }
+        Some(&self.source[self.clusters[i]..self.clusters[i + 1]])
+    }
+
+    pub fn slice_graphemes(&self, range: Range<usize>) -> &'a str {
+        match self.try_slice_graphemes(range) {
+            Ok(s) => s,
+            Err(e) => panic!("slice_graphemes: {e}"),
+        }
+    }
+
+    pub fn try_slice_graphemes(&self, range: Range<usize>) -> Result<&'a str, SliceError> {
+        let len = self.grapheme_len();
+        if range.start > range.end {
+            return Err(SliceError::Reversed {
+                start: range.start,
+                end: range.end,
+            });
+        }
+        if range.end > len {
+            return Err(SliceError::OutOfBounds {
+                index: range.end,
+                len,
+            });
+        }
+        Ok(&self.source[self.clusters[range.start]..self.clusters[range.end]])
+    }
+
+    // Slice by byte indices, same as `&s[a..b]` but returns an error instead of panicking
+    pub fn try_slice(&self, range: Range<usize>) -> Result<&'a str, SliceError> {
+        try_slice(self.source, range)
+    }
+}
+
+pub fn try_slice(s: &str, range: Range<usize>) -> Result<&str, SliceError> {
+    if range.start > range.end {
+        return Err(SliceError::Reversed {
+            start: range.start,
+            end: range.end,
+        });
+    }
+    if range.end > s.len() {
+        return Err(SliceError::OutOfBounds {
+            index: range.end,
+            len: s.len(),
+        });
+    }
+    for index in [range.start, range.end] {
+        if !s.is_char_boundary(index) {
+            return Err(SliceError::NotCharBoundary { index });
+        }
+    }
+    Ok(&s[range])
+}
+
+pub fn start() {
+    let hello = "Здравствуйте";
+    // let s = &hello[0..1]; // panics at runtime, see chap_18_string
+    println!("{:?}", try_slice(hello, 0..1)); // Err(NotCharBoundary { index: 1 })
+    println!("{:?}", try_slice(hello, 0..4)); // Ok("Зд")
+
+    let indexed = IndexedStr::new(hello);
+    println!(
+        "chars: {}, bytes: {}",
+        indexed.char_len(),
+        indexed.byte_len()
+    ); // chars: 12, bytes: 24
+    println!("char_at(3): {:?}", indexed.char_at(3)); // Some('а')
+    println!("slice_chars(0..5): {}", indexed.slice_chars(0..5)); // Здрав
+    println!("{:?}", indexed.try_slice_chars(5..20)); // Err(OutOfBounds { index: 20, len: 12 })
+
+    let namaste = IndexedStr::new("नमस्ते");
+    println!(
+        "{} chars, {} graphemes: {:?}",
+        namaste.char_len(),
+        namaste.grapheme_len(),
+        namaste.grapheme_at(2)
+    ); // 6 chars, 3 graphemes: Some("स्ते")
+
+    let flags = IndexedStr::new("🇮🇳🇷🇺 hi");
+    println!(
+        "{} chars, {} graphemes, last two: {}",
+        flags.char_len(),
+        flags.grapheme_len(),
+        flags.slice_graphemes(3..5)
+    ); // 7 chars, 5 graphemes, last two: hi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_slices() {
+        let hello = "Здравствуйте";
+        assert_eq!(
+            try_slice(hello, 0..1),
+            Err(SliceError::NotCharBoundary { index: 1 })
+        );
+        assert_eq!(try_slice(hello, 0..4), Ok("Зд"));
+        assert_eq!(try_slice(hello, 4..4), Ok(""));
+        assert_eq!(
+            try_slice(hello, 4..2),
+            Err(SliceError::Reversed { start: 4, end: 2 })
+        );
+        assert_eq!(
+            try_slice(hello, 0..25),
+            Err(SliceError::OutOfBounds { index: 25, len: 24 })
+        );
+    }
+
+    #[test]
+    fn char_indexing() {
+        let indexed = IndexedStr::new("Здравствуйте");
+        assert_eq!((indexed.char_len(), indexed.byte_len()), (12, 24));
+        assert_eq!(indexed.char_at(3), Some('а'));
+        assert_eq!(indexed.char_at(12), None);
+        assert_eq!(indexed.slice_chars(0..5), "Здрав");
+        assert_eq!(
+            indexed.try_slice_chars(5..20),
+            Err(SliceError::OutOfBounds { index: 20, len: 12 })
+        );
+
+        let namaste = IndexedStr::new("नमस्ते");
+        assert_eq!(namaste.char_len(), 6);
+        assert_eq!(namaste.char_at(3), Some('्'));
+        assert_eq!(namaste.char_index(9), Some(3));
+        assert_eq!(namaste.char_index(10), None);
+    }
+
+    #[test]
+    fn graphemes() {
+        let namaste = IndexedStr::new("नमस्ते");
+        assert_eq!(namaste.grapheme_len(), 3);
+        assert_eq!(namaste.grapheme_at(2), Some("स्ते"));
+        assert_eq!(namaste.slice_graphemes(0..2), "नम");
+
+        let flags = IndexedStr::new("🇮🇳🇷🇺 hi");
+        assert_eq!((flags.char_len(), flags.grapheme_len()), (7, 5));
+        assert_eq!(flags.grapheme_at(0), Some("🇮🇳"));
+        assert_eq!(flags.slice_graphemes(3..5), "hi");
+    }
+}
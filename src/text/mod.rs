@@ -0,0 +1,14 @@
+// Text utilities that grew out of the string/slice chapters (chap_09 and chap_18).
+pub mod case;
+mod char_names;
+pub mod diagnostics;
+pub mod grapheme;
+mod grapheme_tables;
+pub mod indexed_str;
+pub mod rope;
+pub mod search;
+pub mod translit;
+pub mod utf8;
+mod width_tables;
+pub mod words;
+pub mod wrap;
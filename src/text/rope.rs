@@ -0,0 +1,520 @@
+#![allow(dead_code)]
+use std::fmt;
+use std::ops::Range;
+
+// chap_09_slices: `string.insert_str(0, "I am ")` reallocates and shifts every byte after the insert point, so
+// editing near the start of a big String is O(n). A rope keeps the text in small chunks at the leaves of a
+// balanced (AVL) tree; every node knows how many chars are below it, so finding a position, inserting, deleting
+// and slicing are all O(log n) plus the size of one chunk.
+
+const MAX_CHUNK: usize = 1024; // bytes per leaf
+
+enum Node {
+    Leaf(String),
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        chars: usize,
+        bytes: usize,
+        height: usize,
+    },
+}
+
+impl Node {
+    fn empty() -> Self {
+        Node::Leaf(String::new())
+    }
+
+    fn chars(&self) -> usize {
+        match self {
+            Node::Leaf(s) => s.chars().count(),
+            Node::Branch { chars, .. } => *chars,
+        }
+    }
+
+    fn bytes(&self) -> usize {
+        match self {
+            Node::Leaf(s) => s.len(),
+            Node::Branch { bytes, .. } => *bytes,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Node::Leaf(_) => 0,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes() == 0
+    }
+
+    // Builds a balanced tree out of consecutive leaves
+    fn from_leaves(leaves: &mut Vec<Node>, range: Range<usize>) -> Node {
+        match range.len() {
+            0 => Node::empty(),
+            1 => std::mem::replace(&mut leaves[range.start], Node::empty()),
+            len => {
+                let mid = range.start + len / 2;
+                let left = Node::from_leaves(leaves, range.start..mid);
+                let right = Node::from_leaves(leaves, mid..range.end);
+                branch(left, right)
+            }
+        }
+    }
+}
+
+fn branch(left: Node, right: Node) -> Node {
+    Node::Branch {
+        chars: left.chars() + right.chars(),
+        bytes: left.bytes() + right.bytes(),
+        height: left.height().max(right.height()) + 1,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+// Puts two subtrees whose heights differ by at most 2 back under one node, rotating if needed
+fn balance(left: Node, right: Node) -> Node {
+    if left.height() > right.height() + 1 {
+        let Node::Branch {
+            left: ll,
+            right: lr,
+            ..
+        } = left
+        else {
+            unreachable!("a taller subtree is always a branch")
+        };
+        if ll.height() >= lr.height() {
+            branch(*ll, branch(*lr, right))
+        } else {
+            let Node::Branch {
+                left: lrl,
+                right: lrr,
+                ..
+            } = *lr
+            else {
+                unreachable!()
+            };
+            branch(branch(*ll, *lrl), branch(*lrr, right))
+        }
+    } else if right.height() > left.height() + 1 {
+        let Node::Branch {
+            left: rl,
+            right: rr,
+            ..
+        } = right
+        else {
+            unreachable!("a taller subtree is always a branch")
+        };
+        if rr.height() >= rl.height() {
+            branch(branch(left, *rl), *rr)
+        } else {
+            let Node::Branch {
+                left: rll,
+                right: rlr,
+                ..
+            } = *rl
+            else {
+                unreachable!()
+            };
+            branch(branch(left, *rll), branch(*rlr, *rr))
+        }
+    } else {
+        branch(left, right)
+    }
+}
+
+// Concatenation: walks down the taller tree's spine until the heights match, O(|height difference|)
+fn join(left: Node, right: Node) -> Node {
+    if left.is_empty() {
+        return right;
+    }
+    if right.is_empty() {
+        return left;
+    }
+    match (left, right) {
+        (Node::Leaf(mut a), Node::Leaf(b)) if a.len() + b.len() <= MAX_CHUNK => {
+            a.push_str(&b);
+            Node::Leaf(a)
+        }
+        (left, right) if left.height() > right.height() + 1 => {
+            let Node::Branch {
+                left: ll,
+                right: lr,
+                ..
+            } = left
+            else {
+                unreachable!()
+            };
+            balance(*ll, join(*lr, right))
+        }
+        (left, right) if right.height() > left.height() + 1 => {
+            let Node::Branch {
+                left: rl,
+                right: rr,
+                ..
+            } = right
+            else {
+                unreachable!()
+            };
+            balance(join(left, *rl), *rr)
+        }
+        (left, right) => branch(left, right),
+    }
+}
+
+// Splits into [0, at) and [at, len) by char index
+fn split(node: Node, at: usize) -> (Node, Node) {
+    match node {
+        Node::Leaf(mut s) => {
+            let byte = s.char_indices().nth(at).map_or(s.len(), |(idx, _)| idx);
+            let tail = s.split_off(byte);
+            (Node::Leaf(s), Node::Leaf(tail))
+        }
+        Node::Branch { left, right, .. } => {
+            let left_chars = left.chars();
+            if at < left_chars {
+                let (a, b) = split(*left, at);
+                (a, join(b, *right))
+            } else {
+                let (a, b) = split(*right, at - left_chars);
+                (join(*left, a), b)
+            }
+        }
+    }
+}
+
+// Fast path for small inserts: if the leaf that holds `at` has room, edit it in place. Returns false if it didn't fit.
+fn insert_in_leaf(node: &mut Node, at: usize, text: &str, text_chars: usize) -> bool {
+    match node {
+        Node::Leaf(s) => {
+            if s.len() + text.len() > MAX_CHUNK {
+                return false;
+            }
+            let byte = s.char_indices().nth(at).map_or(s.len(), |(idx, _)| idx);
+            s.insert_str(byte, text);
+            true
+        }
+        Node::Branch {
+            left,
+            right,
+            chars,
+            bytes,
+            ..
+        } => {
+            let left_chars = left.chars();
+            let done = if at <= left_chars {
+                insert_in_leaf(left, at, text, text_chars)
+            } else {
+                insert_in_leaf(right, at - left_chars, text, text_chars)
+            };
+            if done {
+                *chars += text_chars;
+                *bytes += text.len();
+            }
+            done
+        }
+    }
+}
+
+pub struct Rope {
+    root: Node,
+}
+
+impl Rope {
+    pub fn new() -> Self {
+        Self {
+            root: Node::empty(),
+        }
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.root.chars()
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.root.bytes()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_empty()
+    }
+
+    pub fn height(&self) -> usize {
+        self.root.height()
+    }
+
+    // Insert `text` so that its first char ends up at char index `at`. Panics if at > len_chars(), like String::insert_str.
+    pub fn insert(&mut self, at: usize, text: &str) {
+        let len = self.len_chars();
+        assert!(
+            at <= len,
+            "insert index {at} is out of bounds (length is {len})"
+        );
+        if text.is_empty() {
+            return;
+        }
+        if insert_in_leaf(&mut self.root, at, text, text.chars().count()) {
+            return;
+        }
+        let root = std::mem::replace(&mut self.root, Node::empty());
+        let (before, after) = split(root, at);
+        self.root = join(join(before, Rope::from(text).root), after);
+    }
+
+    pub fn remove(&mut self, range: Range<usize>) {
+        let len = self.len_chars();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "remove range {range:?} is out of bounds (length is {len})"
+        );
+        let root = std::mem::replace(&mut self.root, Node::empty());
+        let (before, rest) = split(root, range.start);
+        let (_, after) = split(rest, range.end - range.start);
+        self.root = join(before, after);
+    }
+
+    pub fn char_at(&self, mut i: usize) -> Option<char> {
+        let mut node = &self.root;
+        loop {
+            match node {
+                Node::Leaf(s) => return s.chars().nth(i),
+                Node::Branch { left, right, .. } => {
+                    let left_chars = left.chars();
+                    if i < left_chars {
+                        node = left;
+                    } else {
+                        i -= left_chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // The chars in `range` as a String. Only the chunks that overlap the range are visited.
+    pub fn slice(&self, range: Range<usize>) -> String {
+        let len = self.len_chars();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "slice range {range:?} is out of bounds (length is {len})"
+        );
+        let mut out = String::new();
+        collect_range(&self.root, range, &mut out);
+        out
+    }
+
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            stack: vec![&self.root],
+        }
+    }
+}
+
+fn collect_range(node: &Node, range: Range<usize>, out: &mut String) {
+    if range.is_empty() {
+        return;
+    }
+    match node {
+        Node::Leaf(s) => out.extend(s.chars().skip(range.start).take(range.len())),
+        Node::Branch { left, right, .. } => {
+            let left_chars = left.chars();
+            if range.start < left_chars {
+                collect_range(left, range.start..range.end.min(left_chars), out);
+            }
+            if range.end > left_chars {
+                let start = range.start.saturating_sub(left_chars);
+                collect_range(right, start..range.end - left_chars, out);
+            }
+        }
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// In-order walk over the leaves
+pub struct Chunks<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match node {
+                Node::Leaf(s) if s.is_empty() => continue,
+                Node::Leaf(s) => return Some(s),
+                Node::Branch { left, right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Self {
+        let mut leaves = Vec::new();
+        let mut rest = text;
+        while !rest.is_empty() {
+            let mut end = rest.len().min(MAX_CHUNK);
+            while !rest.is_char_boundary(end) {
+                end -= 1;
+            }
+            leaves.push(Node::Leaf(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+        let len = leaves.len();
+        Self {
+            root: Node::from_leaves(&mut leaves, 0..len),
+        }
+    }
+}
+
+impl From<String> for Rope {
+    fn from(text: String) -> Self {
+        Rope::from(text.as_str())
+    }
+}
+
+impl From<&Rope> for String {
+    fn from(rope: &Rope) -> Self {
+        let mut s = String::with_capacity(rope.len_bytes());
+        for chunk in rope.chunks() {
+            s.push_str(chunk);
+        }
+        s
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.chunks() {
+            write!(f, "{chunk}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Rope {{ chars: {}, bytes: {}, height: {} }}",
+            self.len_chars(),
+            self.len_bytes(),
+            self.height()
+        )
+    }
+}
+
+pub fn start() {
+    // The chap_09_slices example, on a rope
+    let mut rope = Rope::from("Hello, World");
+    rope.insert(0, "I am ");
+    println!("{rope}"); // I am Hello, World
+    rope.remove(0..5);
+    println!("{rope}"); // Hello, World
+
+    let mut rope = Rope::from("Здравствуйте, नमस्ते!");
+    rope.insert(12, " мир");
+    println!("{rope} {:?}", rope.slice(13..16)); // Здравствуйте мир, नमस्ते! "мир"
+
+    // Inserting in the middle over and over is the worst case for a String, and the tree stays shallow
+    let mut rope = Rope::new();
+    for _ in 0..2_000 {
+        rope.insert(rope.len_chars() / 2, "ab Зд न ");
+    }
+    println!("after 2000 inserts in the middle: {rope:?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    // Checks the cached counts and heights against the leaves, and the AVL balance of every branch
+    fn check_node(node: &Node) -> (usize, usize, usize) {
+        match node {
+            Node::Leaf(s) => (s.chars().count(), s.len(), 0),
+            Node::Branch {
+                left,
+                right,
+                chars,
+                bytes,
+                height,
+            } => {
+                let (lc, lb, lh) = check_node(left);
+                let (rc, rb, rh) = check_node(right);
+                assert_eq!((*chars, *bytes), (lc + rc, lb + rb));
+                assert_eq!(*height, lh.max(rh) + 1);
+                assert!(lh.abs_diff(rh) <= 1, "unbalanced: {lh} vs {rh}");
+                (*chars, *bytes, *height)
+            }
+        }
+    }
+
+    #[test]
+    fn edits() {
+        let mut rope = Rope::from("Hello, World");
+        rope.insert(0, "I am ");
+        assert_eq!(rope.to_string(), "I am Hello, World");
+        rope.remove(0..5);
+        assert_eq!(rope.to_string(), "Hello, World");
+
+        let mut rope = Rope::from("Здравствуйте, नमस्ते!");
+        rope.insert(12, " мир");
+        assert_eq!(rope.to_string(), "Здравствуйте мир, नमस्ते!");
+        assert_eq!(rope.slice(13..16), "мир");
+        assert_eq!(rope.char_at(18), Some('न'));
+        assert_eq!(rope.char_at(100), None);
+        assert!(Rope::new().is_empty());
+    }
+
+    // Random edits against a Vec<char>, which is what we'd get with insert_str/replace_range on a String
+    #[test]
+    fn random_edits() {
+        let mut rng = thread_rng();
+        let mut rope = Rope::new();
+        let mut expected: Vec<char> = Vec::new();
+        for round in 0..2_000 {
+            if expected.is_empty() || rng.gen_bool(0.7) {
+                let at = rng.gen_range(0..=expected.len());
+                let text = "ab Зд न ".repeat(rng.gen_range(1..300));
+                rope.insert(at, &text);
+                expected.splice(at..at, text.chars());
+            } else {
+                let start = rng.gen_range(0..expected.len());
+                let end = rng.gen_range(start..=expected.len().min(start + 500));
+                rope.remove(start..end);
+                expected.drain(start..end);
+            }
+            assert_eq!(rope.len_chars(), expected.len());
+            if !expected.is_empty() {
+                let i = rng.gen_range(0..expected.len());
+                assert_eq!(rope.char_at(i), Some(expected[i]));
+                let end = rng.gen_range(i..=expected.len().min(i + 100));
+                assert_eq!(
+                    rope.slice(i..end),
+                    expected[i..end].iter().collect::<String>()
+                );
+            }
+            if round % 50 == 0 {
+                check_node(&rope.root);
+            }
+        }
+        let expected: String = expected.into_iter().collect();
+        assert_eq!(String::from(&rope), expected);
+        assert_eq!(rope.len_bytes(), expected.len());
+        check_node(&rope.root);
+    }
+}
@@ -0,0 +1,522 @@
+#![allow(dead_code)]
+use std::ops::Range;
+
+// chap_09_slices and chap_18_string find things by walking `as_bytes()` one byte at a time. For a longer needle
+// that's O(n * m) in the worst case; these are the classic algorithms that do better.
+//
+// All of them work on bytes. That's still safe for &str: UTF-8 is self-synchronising, so a valid UTF-8 needle can
+// only ever match a valid UTF-8 haystack at char boundaries and every span we return can be used to slice it.
+
+// Lets the same algorithm code scan a haystack forwards or backwards (for rfind).
+trait Bytes {
+    fn len(&self) -> usize;
+    fn at(&self, i: usize) -> u8;
+}
+
+impl Bytes for &[u8] {
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn at(&self, i: usize) -> u8 {
+        self[i]
+    }
+}
+
+struct Reversed<'a>(&'a [u8]);
+
+impl Bytes for Reversed<'_> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn at(&self, i: usize) -> u8 {
+        self.0[self.0.len() - 1 - i]
+    }
+}
+
+pub trait Searcher {
+    fn needle(&self) -> &[u8];
+
+    // Start of the first match at or after byte `from`
+    fn next_match(&self, haystack: &[u8], from: usize) -> Option<usize>;
+
+    // Start of the last match that ends at or before byte `end`
+    fn prev_match(&self, haystack: &[u8], end: usize) -> Option<usize>;
+}
+
+// Knuth-Morris-Pratt: never moves backwards in the haystack, O(n + m)
+pub struct Kmp {
+    needle: Vec<u8>,
+    forward: Vec<usize>,  // failure table of the needle
+    backward: Vec<usize>, // failure table of the reversed needle
+}
+
+// failure[i] = length of the longest proper prefix of needle[..=i] that's also a suffix of it
+fn failure_table(needle: &impl Bytes) -> Vec<usize> {
+    let mut failure = vec![0; needle.len()];
+    let mut k = 0;
+    for i in 1..needle.len() {
+        while k > 0 && needle.at(i) != needle.at(k) {
+            k = failure[k - 1];
+        }
+        if needle.at(i) == needle.at(k) {
+            k += 1;
+        }
+        failure[i] = k;
+    }
+    failure
+}
+
+fn kmp_search(
+    needle: &impl Bytes,
+    failure: &[usize],
+    haystack: &impl Bytes,
+    from: usize,
+) -> Option<usize> {
+    let m = needle.len();
+    let mut k = 0;
+    for i in from..haystack.len() {
+        while k > 0 && haystack.at(i) != needle.at(k) {
+            k = failure[k - 1];
+        }
+        if haystack.at(i) == needle.at(k) {
+            k += 1;
+        }
+        if k == m {
+            return Some(i + 1 - m);
+        }
+    }
+    None
+}
+
+impl Kmp {
+    pub fn new(needle: &str) -> Self {
+        let bytes = needle.as_bytes();
+        Self {
+            needle: bytes.to_vec(),
+            forward: failure_table(&bytes),
+            backward: failure_table(&Reversed(bytes)),
+        }
+    }
+}
+
+impl Searcher for Kmp {
+    fn needle(&self) -> &[u8] {
+        &self.needle
+    }
+
+    fn next_match(&self, haystack: &[u8], from: usize) -> Option<usize> {
+        if self.needle.is_empty() {
+            return (from <= haystack.len()).then_some(from);
+        }
+        kmp_search(&self.needle.as_slice(), &self.forward, &haystack, from)
+    }
+
+    fn prev_match(&self, haystack: &[u8], end: usize) -> Option<usize> {
+        let m = self.needle.len();
+        if m == 0 {
+            return Some(end);
+        }
+        let found = kmp_search(
+            &Reversed(&self.needle),
+            &self.backward,
+            &Reversed(&haystack[..end]),
+            0,
+        )?;
+        Some(end - found - m)
+    }
+}
+
+// Boyer-Moore-Horspool: compares the last byte of the window first and on a mismatch jumps ahead by up to m bytes,
+// so on typical text it looks at far fewer than n bytes
+pub struct Horspool {
+    needle: Vec<u8>,
+    forward: [usize; 256], // how far the window can move when byte b is under its last position
+    backward: [usize; 256], // same for the reversed needle
+}
+
+fn shift_table(needle: &impl Bytes) -> [usize; 256] {
+    let m = needle.len();
+    let mut shift = [m.max(1); 256];
+    for i in 0..m.saturating_sub(1) {
+        shift[needle.at(i) as usize] = m - 1 - i;
+    }
+    shift
+}
+
+fn horspool_search(
+    needle: &impl Bytes,
+    shift: &[usize; 256],
+    haystack: &impl Bytes,
+    from: usize,
+) -> Option<usize> {
+    let m = needle.len();
+    let mut pos = from;
+    while pos + m <= haystack.len() {
+        let last = haystack.at(pos + m - 1);
+        if last == needle.at(m - 1) && (0..m - 1).all(|i| haystack.at(pos + i) == needle.at(i)) {
+            return Some(pos);
+        }
+        pos += shift[last as usize];
+    }
+    None
+}
+
+impl Horspool {
+    pub fn new(needle: &str) -> Self {
+        let bytes = needle.as_bytes();
+        Self {
+            needle: bytes.to_vec(),
+            forward: shift_table(&bytes),
+            backward: shift_table(&Reversed(bytes)),
+        }
+    }
+}
+
+impl Searcher for Horspool {
+    fn needle(&self) -> &[u8] {
+        &self.needle
+    }
+
+    fn next_match(&self, haystack: &[u8], from: usize) -> Option<usize> {
+        if self.needle.is_empty() {
+            return (from <= haystack.len()).then_some(from);
+        }
+        horspool_search(&self.needle.as_slice(), &self.forward, &haystack, from)
+    }
+
+    fn prev_match(&self, haystack: &[u8], end: usize) -> Option<usize> {
+        let m = self.needle.len();
+        if m == 0 {
+            return Some(end);
+        }
+        let found = horspool_search(
+            &Reversed(&self.needle),
+            &self.backward,
+            &Reversed(&haystack[..end]),
+            0,
+        )?;
+        Some(end - found - m)
+    }
+}
+
+// Two-way (Crochemore-Perrin), the algorithm behind std's str::find: O(n + m) time with O(1) extra space.
+// The needle is cut at a "critical factorization" u|v; we match v left to right, then u right to left, and the
+// period of the needle tells us how far we can safely shift.
+pub struct TwoWay {
+    needle: Vec<u8>,
+    forward: Factorization,
+    backward: Factorization,
+}
+
+struct Factorization {
+    ell: isize, // the critical position is ell + 1, so u = needle[..=ell] (ell can be -1)
+    period: usize,
+    periodic: bool, // u is a suffix of v's first period, so we can remember what we matched
+}
+
+// Maximal suffix of the needle under the byte order (or its reverse), returns (start - 1, period)
+fn maximal_suffix(needle: &impl Bytes, reversed_order: bool) -> (isize, usize) {
+    let m = needle.len();
+    let mut ms: isize = -1;
+    let mut j: usize = 0;
+    let mut k: usize = 1;
+    let mut p: usize = 1;
+    while j + k < m {
+        let a = needle.at(j + k);
+        let b = needle.at((ms + k as isize) as usize);
+        let less = if reversed_order { a > b } else { a < b };
+        if less {
+            j += k;
+            k = 1;
+            p = (j as isize - ms) as usize;
+        } else if a == b {
+            if k != p {
+                k += 1;
+            } else {
+                j += p;
+                k = 1;
+            }
+        } else {
+            ms = j as isize;
+            j = ms as usize + 1;
+            k = 1;
+            p = 1;
+        }
+    }
+    (ms, p)
+}
+
+fn factorize(needle: &impl Bytes) -> Factorization {
+    let m = needle.len();
+    let (i, p) = maximal_suffix(needle, false);
+    let (j, q) = maximal_suffix(needle, true);
+    let (ell, period) = if i > j { (i, p) } else { (j, q) };
+    let u_len = (ell + 1) as usize;
+    let periodic = period + u_len <= m && (0..u_len).all(|k| needle.at(k) == needle.at(k + period));
+    let period = if periodic {
+        period
+    } else {
+        u_len.max(m - u_len) + 1
+    };
+    Factorization {
+        ell,
+        period,
+        periodic,
+    }
+}
+
+fn two_way_search(
+    needle: &impl Bytes,
+    f: &Factorization,
+    haystack: &impl Bytes,
+    from: usize,
+) -> Option<usize> {
+    let m = needle.len();
+    let n = haystack.len();
+    let ell = f.ell;
+    let mut pos = from;
+    let mut memory: isize = -1; // how much of the needle's prefix is known to match already
+
+    while pos + m <= n {
+        // match the right half v, skipping what we remember
+        let resume = if f.periodic { ell.max(memory) } else { ell };
+        let mut i = (resume + 1) as usize;
+        while i < m && needle.at(i) == haystack.at(pos + i) {
+            i += 1;
+        }
+        if i < m {
+            pos += (i as isize - ell) as usize;
+            memory = -1;
+            continue;
+        }
+
+        // then the left half u, right to left
+        let stop = if f.periodic { memory } else { -1 };
+        let mut i = ell;
+        while i > stop && needle.at(i as usize) == haystack.at(pos + i as usize) {
+            i -= 1;
+        }
+        if i <= stop {
+            return Some(pos);
+        }
+        pos += f.period;
+        if f.periodic {
+            memory = (m - f.period) as isize - 1;
+        }
+    }
+    None
+}
+
+impl TwoWay {
+    pub fn new(needle: &str) -> Self {
+        let bytes = needle.as_bytes();
+        Self {
+            needle: bytes.to_vec(),
+            forward: factorize(&bytes),
+            backward: factorize(&Reversed(bytes)),
+        }
+    }
+}
+
+impl Searcher for TwoWay {
+    fn needle(&self) -> &[u8] {
+        &self.needle
+    }
+
+    fn next_match(&self, haystack: &[u8], from: usize) -> Option<usize> {
+        if self.needle.is_empty() {
+            return (from <= haystack.len()).then_some(from);
+        }
+        two_way_search(&self.needle.as_slice(), &self.forward, &haystack, from)
+    }
+
+    fn prev_match(&self, haystack: &[u8], end: usize) -> Option<usize> {
+        let m = self.needle.len();
+        if m == 0 {
+            return Some(end);
+        }
+        let found = two_way_search(
+            &Reversed(&self.needle),
+            &self.backward,
+            &Reversed(&haystack[..end]),
+            0,
+        )?;
+        Some(end - found - m)
+    }
+}
+
+// The empty needle matches at every char boundary, like str::find("") does
+fn next_boundary(haystack: &str, pos: usize) -> usize {
+    let mut next = pos + 1;
+    while next < haystack.len() && !haystack.is_char_boundary(next) {
+        next += 1;
+    }
+    next
+}
+
+pub fn find(searcher: &impl Searcher, haystack: &str) -> Option<Range<usize>> {
+    let start = searcher.next_match(haystack.as_bytes(), 0)?;
+    Some(start..start + searcher.needle().len())
+}
+
+pub fn rfind(searcher: &impl Searcher, haystack: &str) -> Option<Range<usize>> {
+    let start = searcher.prev_match(haystack.as_bytes(), haystack.len())?;
+    Some(start..start + searcher.needle().len())
+}
+
+// Non-overlapping matches from left to right, same as str::match_indices: "aaaa" / "aa" -> 0..2, 2..4
+pub fn find_all(searcher: &impl Searcher, haystack: &str) -> Vec<Range<usize>> {
+    let m = searcher.needle().len();
+    let mut result = Vec::new();
+    let mut from = 0;
+    while let Some(start) = searcher.next_match(haystack.as_bytes(), from) {
+        result.push(start..start + m);
+        from = if m == 0 {
+            next_boundary(haystack, start)
+        } else {
+            start + m
+        };
+        if from > haystack.len() {
+            break;
+        }
+    }
+    result
+}
+
+// Every match, including ones that overlap: "aaaa" / "aa" -> 0..2, 1..3, 2..4
+pub struct Overlapping<'a, S: Searcher> {
+    searcher: &'a S,
+    haystack: &'a str,
+    from: usize,
+}
+
+impl<S: Searcher> Iterator for Overlapping<'_, S> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        if self.from > self.haystack.len() {
+            return None;
+        }
+        let start = self
+            .searcher
+            .next_match(self.haystack.as_bytes(), self.from)?;
+        // the next match can start at the next char at the earliest
+        self.from = next_boundary(self.haystack, start);
+        Some(start..start + self.searcher.needle().len())
+    }
+}
+
+pub fn overlapping<'a, S: Searcher>(searcher: &'a S, haystack: &'a str) -> Overlapping<'a, S> {
+    Overlapping {
+        searcher,
+        haystack,
+        from: 0,
+    }
+}
+
+pub fn start() {
+    let haystack = "I am hello world, hello Здравствуйте नमस्ते hello";
+    let searcher = TwoWay::new("hello");
+    println!("find: {:?}", find(&searcher, haystack)); // Some(5..10)
+    println!("rfind: {:?}", rfind(&searcher, haystack)); // Some(68..73)
+    println!("find_all: {:?}", find_all(&searcher, haystack));
+
+    let searcher = Kmp::new("aa");
+    println!(
+        "overlapping: {:?}",
+        overlapping(&searcher, "aaaa").collect::<Vec<_>>()
+    ); // [0..2, 1..3, 2..4]
+
+    let searcher = Horspool::new("ст");
+    let span = find(&searcher, haystack).unwrap();
+    println!("{span:?} -> {}", &haystack[span.clone()]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, seq::SliceRandom, thread_rng};
+
+    fn check_against_std(searcher: &impl Searcher, haystack: &str, needle: &str) {
+        let expected = haystack
+            .find(needle)
+            .map(|start| start..start + needle.len());
+        assert_eq!(
+            find(searcher, haystack),
+            expected,
+            "find {needle:?} in {haystack:?}"
+        );
+
+        let expected = haystack
+            .rfind(needle)
+            .map(|start| start..start + needle.len());
+        assert_eq!(
+            rfind(searcher, haystack),
+            expected,
+            "rfind {needle:?} in {haystack:?}"
+        );
+
+        let expected: Vec<Range<usize>> = haystack
+            .match_indices(needle)
+            .map(|(start, m)| start..start + m.len())
+            .collect();
+        assert_eq!(
+            find_all(searcher, haystack),
+            expected,
+            "find_all {needle:?} in {haystack:?}"
+        );
+
+        // overlapping matches: every char boundary where the rest of the haystack starts with the needle
+        let expected: Vec<Range<usize>> = (0..=haystack.len())
+            .filter(|&i| haystack.is_char_boundary(i) && haystack[i..].starts_with(needle))
+            .map(|start| start..start + needle.len())
+            .collect();
+        let found: Vec<Range<usize>> = overlapping(searcher, haystack).collect();
+        assert_eq!(found, expected, "overlapping {needle:?} in {haystack:?}");
+
+        for span in found {
+            assert!(haystack.is_char_boundary(span.start) && haystack.is_char_boundary(span.end));
+        }
+    }
+
+    #[test]
+    fn examples() {
+        let haystack = "I am hello world, hello Здравствуйте नमस्ते hello";
+        let searcher = TwoWay::new("hello");
+        assert_eq!(find(&searcher, haystack), Some(5..10));
+        assert_eq!(rfind(&searcher, haystack), Some(68..73));
+        assert_eq!(find_all(&searcher, haystack), [5..10, 18..23, 68..73]);
+        assert_eq!(
+            overlapping(&Kmp::new("aa"), "aaaa").collect::<Vec<_>>(),
+            [0..2, 1..3, 2..4]
+        );
+        let span = find(&Horspool::new("ст"), haystack).unwrap();
+        assert_eq!(&haystack[span], "ст");
+        for needle in ["", "hello", "ते", "्", "zzz"] {
+            check_against_std(&Kmp::new(needle), haystack, needle);
+            check_against_std(&Horspool::new(needle), haystack, needle);
+            check_against_std(&TwoWay::new(needle), haystack, needle);
+        }
+    }
+
+    // Random haystacks and needles over a tiny alphabet (lots of partial matches), with str::find as the oracle
+    #[test]
+    fn random_against_std() {
+        let alphabet = ["a", "b", "з", "न", "्"];
+        let mut rng = thread_rng();
+        let mut random_text = |len: usize| -> String {
+            (0..len)
+                .map(|_| *alphabet.choose(&mut rng).unwrap())
+                .collect()
+        };
+        for _ in 0..3_000 {
+            let haystack = random_text(thread_rng().gen_range(0..40));
+            let needle = random_text(thread_rng().gen_range(0..6));
+            check_against_std(&Kmp::new(&needle), &haystack, &needle);
+            check_against_std(&Horspool::new(&needle), &haystack, &needle);
+            check_against_std(&TwoWay::new(&needle), &haystack, &needle);
+        }
+    }
+}
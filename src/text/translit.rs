@@ -0,0 +1,632 @@
+#![allow(dead_code)]
+use super::words::is_separator;
+use std::fmt;
+
+// Transliteration for the two non-Latin strings chap_18_string uses: "नमस्ते" and "Здравствуйте".
+// Everything is driven by the tables below, one row per letter; characters that aren't in a table are copied as is.
+//
+// Devanagari is an abugida: a consonant carries an inherent "a" unless it's followed by a vowel sign (ि, े, ...)
+// or the virama (्), which is why "नमस्ते" = न म स ् त े comes out as "namaste" and not "nmaste".
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    // Devanagari, the classic scholarly scheme (ऋ = ṛ, ए = e), only works in one direction: without ISO 15919's
+    // ':' "kai" could be कै or कइ and "akhara" अखर or अक्हर
+    Iast,
+    // Devanagari, ISO 15919 (ऋ = r̥, ए = ē), with ':' to mark the few ambiguous spots
+    Iso15919,
+    // Cyrillic, ISO 9:1995, one Latin letter per Cyrillic letter so it round-trips. The one catch is Ъ and Ь,
+    // written as the caseless ʺ and ʹ: on the way back they're capitals only in a word written in capitals, so
+    // a mixed-case word like "бЪ" or a two-letter "КЪ" comes back different.
+    Iso9,
+    // Cyrillic, English-friendly (Ж = zh, Щ = shch), only works in one direction
+    Practical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Script {
+    Devanagari,
+    Cyrillic,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TranslitError {
+    NotReversible(Scheme),
+}
+
+impl fmt::Display for TranslitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TranslitError::NotReversible(scheme) => {
+                write!(f, "{scheme:?} can't be converted back, it's ambiguous")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TranslitError {}
+
+impl Scheme {
+    pub fn script(&self) -> Script {
+        match self {
+            Scheme::Iast | Scheme::Iso15919 => Script::Devanagari,
+            Scheme::Iso9 | Scheme::Practical => Script::Cyrillic,
+        }
+    }
+
+    pub fn is_reversible(&self) -> bool {
+        matches!(self, Scheme::Iso15919 | Scheme::Iso9)
+    }
+}
+
+// (Devanagari, IAST, ISO 15919)
+const CONSONANTS: &[(&str, &str, &str)] = &[
+    ("क", "k", "k"),
+    ("ख", "kh", "kh"),
+    ("ग", "g", "g"),
+    ("घ", "gh", "gh"),
+    ("ङ", "ṅ", "ṅ"),
+    ("च", "c", "c"),
+    ("छ", "ch", "ch"),
+    ("ज", "j", "j"),
+    ("झ", "jh", "jh"),
+    ("ञ", "ñ", "ñ"),
+    ("ट", "ṭ", "ṭ"),
+    ("ठ", "ṭh", "ṭh"),
+    ("ड", "ḍ", "ḍ"),
+    ("ढ", "ḍh", "ḍh"),
+    ("ण", "ṇ", "ṇ"),
+    ("त", "t", "t"),
+    ("थ", "th", "th"),
+    ("द", "d", "d"),
+    ("ध", "dh", "dh"),
+    ("न", "n", "n"),
+    ("प", "p", "p"),
+    ("फ", "ph", "ph"),
+    ("ब", "b", "b"),
+    ("भ", "bh", "bh"),
+    ("म", "m", "m"),
+    ("य", "y", "y"),
+    ("र", "r", "r"),
+    ("ल", "l", "l"),
+    ("ळ", "ḻ", "ḷ"), // IAST uses ḷ for ऌ, so ळ gets the Vedic ḻ
+    ("व", "v", "v"),
+    ("श", "ś", "ś"),
+    ("ष", "ṣ", "ṣ"),
+    ("स", "s", "s"),
+    ("ह", "h", "h"),
+    // nukta forms, written as consonant + U+093C; only ISO 15919 has letters for them
+    ("क\u{93c}", "", "q"),
+    ("ख\u{93c}", "", "ḵh"),
+    ("ग\u{93c}", "", "ġ"),
+    ("ज\u{93c}", "", "z"),
+    ("ड\u{93c}", "", "ṛ"),
+    ("ढ\u{93c}", "", "ṛh"),
+    ("फ\u{93c}", "", "f"),
+    ("य\u{93c}", "", "ẏ"),
+];
+
+// The precomposed nukta letters U+0958..U+095F are the same as consonant + nukta
+const NUKTA_PRECOMPOSED: &[(char, &str)] = &[
+    ('\u{958}', "क\u{93c}"),
+    ('\u{959}', "ख\u{93c}"),
+    ('\u{95a}', "ग\u{93c}"),
+    ('\u{95b}', "ज\u{93c}"),
+    ('\u{95c}', "ड\u{93c}"),
+    ('\u{95d}', "ढ\u{93c}"),
+    ('\u{95e}', "फ\u{93c}"),
+    ('\u{95f}', "य\u{93c}"),
+];
+
+// (independent vowel, vowel sign, IAST, ISO 15919); अ has no sign, it's the inherent vowel
+const VOWELS: &[(&str, &str, &str, &str)] = &[
+    ("अ", "", "a", "a"),
+    ("आ", "ा", "ā", "ā"),
+    ("इ", "ि", "i", "i"),
+    ("ई", "ी", "ī", "ī"),
+    ("उ", "ु", "u", "u"),
+    ("ऊ", "ू", "ū", "ū"),
+    ("ऋ", "ृ", "ṛ", "r̥"),
+    ("ॠ", "ॄ", "ṝ", "r̥̄"),
+    ("ऌ", "ॢ", "ḷ", "l̥"),
+    ("ए", "े", "e", "ē"),
+    ("ऐ", "ै", "ai", "ai"),
+    ("ओ", "ो", "o", "ō"),
+    ("औ", "ौ", "au", "au"),
+];
+
+// (mark, IAST, ISO 15919)
+const MARKS: &[(&str, &str, &str)] = &[
+    // anusvara, visarga, chandrabindu, avagraha and the digits
+    ("ं", "ṃ", "ṁ"),
+    ("ः", "ḥ", "ḥ"),
+    ("ँ", "m̐", "m̐"),
+    ("ऽ", "'", "'"),
+    ("०", "0", "0"),
+    ("१", "1", "1"),
+    ("२", "2", "2"),
+    ("३", "3", "3"),
+    ("४", "4", "4"),
+    ("५", "5", "5"),
+    ("६", "6", "6"),
+    ("७", "7", "7"),
+    ("८", "8", "8"),
+    ("९", "9", "9"),
+];
+
+const VIRAMA: &str = "्";
+
+// Consonants that have an aspirated partner, so "k" + "h" would read as ख. ISO 15919 writes क्ह as "k:h".
+const ASPIRABLE: &[&str] = &["k", "g", "c", "j", "ṭ", "ḍ", "t", "d", "p", "b", "ṛ"];
+
+// (Cyrillic, ISO 9, practical)
+const CYRILLIC: &[(&str, &str, &str)] = &[
+    ("А", "A", "A"),
+    ("Б", "B", "B"),
+    ("В", "V", "V"),
+    ("Г", "G", "G"),
+    ("Д", "D", "D"),
+    ("Е", "E", "E"),
+    ("Ё", "Ë", "Yo"),
+    ("Ж", "Ž", "Zh"),
+    ("З", "Z", "Z"),
+    ("И", "I", "I"),
+    ("Й", "J", "Y"),
+    ("К", "K", "K"),
+    ("Л", "L", "L"),
+    ("М", "M", "M"),
+    ("Н", "N", "N"),
+    ("О", "O", "O"),
+    ("П", "P", "P"),
+    ("Р", "R", "R"),
+    ("С", "S", "S"),
+    ("Т", "T", "T"),
+    ("У", "U", "U"),
+    ("Ф", "F", "F"),
+    ("Х", "H", "Kh"),
+    ("Ц", "C", "Ts"),
+    ("Ч", "Č", "Ch"),
+    ("Ш", "Š", "Sh"),
+    ("Щ", "Ŝ", "Shch"),
+    ("Ъ", "ʺ", "\""),
+    ("Ы", "Y", "Y"),
+    ("Ь", "ʹ", "'"),
+    ("Э", "È", "E"),
+    ("Ю", "Û", "Yu"),
+    ("Я", "Â", "Ya"),
+    // Ukrainian, Belarusian, Serbian and Macedonian letters
+    ("Ґ", "G̀", "G"),
+    ("Є", "Ê", "Ye"),
+    ("І", "Ì", "I"),
+    ("Ї", "Ï", "Yi"),
+    ("Ў", "Ǔ", "W"),
+    ("Ђ", "Đ", "Dj"),
+    ("Ѓ", "Ǵ", "Gj"),
+    ("Ѕ", "Ẑ", "Dz"),
+    ("Ј", "J̌", "J"),
+    ("Љ", "L̂", "Lj"),
+    ("Њ", "N̂", "Nj"),
+    ("Ћ", "Ć", "C"),
+    ("Ќ", "Ḱ", "Kj"),
+    ("Џ", "D̂", "Dzh"),
+    ("а", "a", "a"),
+    ("б", "b", "b"),
+    ("в", "v", "v"),
+    ("г", "g", "g"),
+    ("д", "d", "d"),
+    ("е", "e", "e"),
+    ("ё", "ë", "yo"),
+    ("ж", "ž", "zh"),
+    ("з", "z", "z"),
+    ("и", "i", "i"),
+    ("й", "j", "y"),
+    ("к", "k", "k"),
+    ("л", "l", "l"),
+    ("м", "m", "m"),
+    ("н", "n", "n"),
+    ("о", "o", "o"),
+    ("п", "p", "p"),
+    ("р", "r", "r"),
+    ("с", "s", "s"),
+    ("т", "t", "t"),
+    ("у", "u", "u"),
+    ("ф", "f", "f"),
+    ("х", "h", "kh"),
+    ("ц", "c", "ts"),
+    ("ч", "č", "ch"),
+    ("ш", "š", "sh"),
+    ("щ", "ŝ", "shch"),
+    ("ъ", "ʺ", "\""),
+    ("ы", "y", "y"),
+    ("ь", "ʹ", "'"),
+    ("э", "è", "e"),
+    ("ю", "û", "yu"),
+    ("я", "â", "ya"),
+    ("ґ", "g̀", "g"),
+    ("є", "ê", "ye"),
+    ("і", "ì", "i"),
+    ("ї", "ï", "yi"),
+    ("ў", "ǔ", "w"),
+    ("ђ", "đ", "dj"),
+    ("ѓ", "ǵ", "gj"),
+    ("ѕ", "ẑ", "dz"),
+    ("ј", "ǰ", "j"),
+    ("љ", "l̂", "lj"),
+    ("њ", "n̂", "nj"),
+    ("ћ", "ć", "c"),
+    ("ќ", "ḱ", "kj"),
+    ("џ", "d̂", "dzh"),
+];
+
+pub fn to_latin(s: &str, scheme: Scheme) -> String {
+    match scheme.script() {
+        Script::Devanagari => devanagari_to_latin(s, scheme == Scheme::Iso15919),
+        Script::Cyrillic => cyrillic_to_latin(s, scheme == Scheme::Iso9),
+    }
+}
+
+pub fn from_latin(s: &str, scheme: Scheme) -> Result<String, TranslitError> {
+    if !scheme.is_reversible() {
+        return Err(TranslitError::NotReversible(scheme));
+    }
+    Ok(match scheme.script() {
+        Script::Devanagari => latin_to_devanagari(s, scheme == Scheme::Iso15919),
+        Script::Cyrillic => latin_to_cyrillic(s),
+    })
+}
+
+// Finds the longest table entry that `s` starts with. `column` picks which &str of the row to match against.
+fn longest_match<'t, T>(s: &str, table: &'t [T], column: impl Fn(&T) -> &str) -> Option<&'t T> {
+    table
+        .iter()
+        .filter(|row| {
+            let key = column(row);
+            !key.is_empty() && s.starts_with(key)
+        })
+        .max_by_key(|row| column(row).len())
+}
+
+fn devanagari_to_latin(s: &str, iso: bool) -> String {
+    let pick = |iast: &'static str, iso_form: &'static str| if iso { iso_form } else { iast };
+
+    // Nukta letters can be precomposed or not, so bring them to one form first
+    let mut text = String::with_capacity(s.len());
+    for c in s.chars() {
+        match NUKTA_PRECOMPOSED.iter().find(|(pre, _)| *pre == c) {
+            Some((_, decomposed)) => text.push_str(decomposed),
+            None => text.push(c),
+        }
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+    // true right after a bare "a" (inherent or अ), where a following इ/उ would read as ऐ/औ
+    let mut after_a = false;
+    // the Latin of the previous consonant if it was killed by a virama, for the "k:h" case
+    let mut dead_consonant: Option<&str> = None;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(&(deva, iast, iso_form)) = longest_match(rest, CONSONANTS, |row| row.0) {
+            let latin = pick(iast, iso_form);
+            if latin.is_empty() {
+                // IAST has no letter for this nukta form, drop the nukta and keep the base consonant
+                rest = &rest[deva.len()..];
+                continue;
+            }
+            if iso
+                && latin.starts_with('h')
+                && dead_consonant.is_some_and(|d| ASPIRABLE.contains(&d))
+            {
+                out.push(':');
+            }
+            out.push_str(latin);
+            rest = &rest[deva.len()..];
+
+            // What follows the consonant decides its vowel
+            after_a = false;
+            dead_consonant = None;
+            if let Some(tail) = rest.strip_prefix(VIRAMA) {
+                rest = tail;
+                dead_consonant = Some(latin);
+            } else if let Some(&(_, sign, iast, iso_form)) =
+                longest_match(rest, VOWELS, |row| row.1)
+            {
+                out.push_str(pick(iast, iso_form));
+                rest = &rest[sign.len()..];
+            } else {
+                out.push('a');
+                after_a = true;
+            }
+            continue;
+        }
+
+        if let Some(&(independent, _, iast, iso_form)) = longest_match(rest, VOWELS, |row| row.0) {
+            let latin = pick(iast, iso_form);
+            if iso && after_a && (latin.starts_with('i') || latin.starts_with('u')) {
+                out.push(':');
+            }
+            out.push_str(latin);
+            rest = &rest[independent.len()..];
+            after_a = latin == "a";
+            dead_consonant = None;
+            continue;
+        }
+
+        after_a = false;
+        dead_consonant = None;
+        if let Some(&(mark, iast, iso_form)) = longest_match(rest, MARKS, |row| row.0) {
+            out.push_str(pick(iast, iso_form));
+            rest = &rest[mark.len()..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
+
+fn latin_to_devanagari(s: &str, iso: bool) -> String {
+    let pick = |row_iast: &'static str, row_iso: &'static str| if iso { row_iso } else { row_iast };
+    let text = s.to_lowercase();
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut rest = text.as_str();
+    let mut pending_consonant = false; // a consonant was written and we don't know its vowel yet
+
+    while let Some(c) = rest.chars().next() {
+        if iso && c == ':' {
+            // separator between letters that would otherwise merge ("k:h", "a:i")
+            rest = &rest[1..];
+            continue;
+        }
+
+        // The same Latin letter can start a consonant, a vowel or a mark ("r" vs "r̥", "m" vs "m̐"),
+        // so look in all three tables and take the longest match.
+        let consonant = longest_match(rest, CONSONANTS, |row| pick(row.1, row.2));
+        let vowel = longest_match(rest, VOWELS, |row| pick(row.2, row.3));
+        let mark = longest_match(rest, MARKS, |row| pick(row.1, row.2));
+        let consonant_len = consonant.map_or(0, |row| pick(row.1, row.2).len());
+        let vowel_len = vowel.map_or(0, |row| pick(row.2, row.3).len());
+        let mark_len = mark.map_or(0, |row| pick(row.1, row.2).len());
+
+        if let Some(&(deva, _, _)) = consonant.filter(|_| consonant_len >= vowel_len.max(mark_len))
+        {
+            if pending_consonant {
+                out.push_str(VIRAMA);
+            }
+            out.push_str(deva);
+            rest = &rest[consonant_len..];
+            pending_consonant = true;
+            continue;
+        }
+
+        if let Some(&(independent, sign, _, _)) = vowel.filter(|_| vowel_len >= mark_len) {
+            if pending_consonant {
+                out.push_str(sign); // "" for the inherent a
+            } else {
+                out.push_str(independent);
+            }
+            rest = &rest[vowel_len..];
+            pending_consonant = false;
+            continue;
+        }
+
+        // a consonant with no vowel after it (end of word, before a mark or punctuation) gets a virama
+        if pending_consonant {
+            out.push_str(VIRAMA);
+            pending_consonant = false;
+        }
+        if let Some(&(deva, _, _)) = mark {
+            out.push_str(deva);
+            rest = &rest[mark_len..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if pending_consonant {
+        out.push_str(VIRAMA);
+    }
+    out
+}
+
+fn cyrillic_to_latin(s: &str, iso: bool) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        match longest_match(rest, CYRILLIC, |row| row.0) {
+            Some(&(cyr, iso9, practical)) => {
+                out.push_str(if iso { iso9 } else { practical });
+                rest = &rest[cyr.len()..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+// Whether the word around byte `at` has at least two capital letters and no small ones. With only one it could
+// be "Къ" or "КЪ", and the first is much more likely.
+fn in_capitals(s: &str, at: usize) -> bool {
+    let start = s[..at]
+        .rfind(is_separator)
+        .map_or(0, |i| i + s[i..].chars().next().unwrap().len_utf8());
+    let end = s[at..].find(is_separator).map_or(s.len(), |i| at + i);
+    let word = &s[start..end];
+    !word.chars().any(char::is_lowercase) && word.chars().filter(|c| c.is_uppercase()).count() >= 2
+}
+
+fn latin_to_cyrillic(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        match longest_match(rest, CYRILLIC, |row| row.1) {
+            // ʺ and ʹ have no case, they're Ъ/Ь in a word written in capitals
+            Some(&(cyr, iso9, _)) if iso9.to_uppercase() == iso9.to_lowercase() => {
+                let upper = in_capitals(s, s.len() - rest.len());
+                rest = &rest[iso9.len()..];
+                out.push_str(&if upper {
+                    cyr.to_uppercase()
+                } else {
+                    cyr.to_lowercase()
+                });
+            }
+            Some(&(cyr, iso9, _)) => {
+                out.push_str(cyr);
+                rest = &rest[iso9.len()..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+pub fn start() {
+    let namaste = "नमस्ते";
+    println!(
+        "{namaste} -> IAST: {}, ISO 15919: {}",
+        to_latin(namaste, Scheme::Iast),
+        to_latin(namaste, Scheme::Iso15919)
+    ); // namaste, namastē
+
+    let hello = "Здравствуйте";
+    println!(
+        "{hello} -> ISO 9: {}, practical: {}",
+        to_latin(hello, Scheme::Iso9),
+        to_latin(hello, Scheme::Practical)
+    ); // Zdravstvujte, Zdravstvuyte
+
+    // ISO 15919 marks the spots IAST leaves ambiguous with ':', so only it can be converted back
+    for (word, scheme) in [
+        ("कइ", Scheme::Iso15919),
+        ("अक्हर", Scheme::Iso15919),
+        ("ज़िंदगी", Scheme::Iso15919),
+        ("ОБЪЯВЛЕНИЕ", Scheme::Iso9),
+        ("Щука", Scheme::Iso9),
+    ] {
+        let latin = to_latin(word, scheme);
+        println!(
+            "{word} -> {latin} -> {}",
+            from_latin(&latin, scheme).unwrap()
+        );
+    }
+    println!("{}", from_latin("kai", Scheme::Iast).unwrap_err());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    #[test]
+    fn iso15919_round_trips() {
+        for word in [
+            "नमस्ते",
+            "संस्कृतम्",
+            "क्षत्रिय",
+            "हिन्दी",
+            "कइ",
+            "कउ",
+            "अक्हर",
+            "ज़िंदगी",
+            "दुःख",
+        ] {
+            let latin = to_latin(word, Scheme::Iso15919);
+            assert_eq!(
+                from_latin(&latin, Scheme::Iso15919).unwrap(),
+                word,
+                "{latin}"
+            );
+        }
+        assert_eq!(to_latin("कइ", Scheme::Iso15919), "ka:i");
+        assert_eq!(to_latin("अक्हर", Scheme::Iso15919), "ak:hara");
+    }
+
+    #[test]
+    fn one_way_schemes_refuse_to_go_back() {
+        // IAST writes कइ and कै both as "kai"
+        assert_eq!(to_latin("कइ", Scheme::Iast), to_latin("कै", Scheme::Iast));
+        assert_eq!(
+            to_latin("अक्हर", Scheme::Iast),
+            to_latin("अखर", Scheme::Iast)
+        );
+        for scheme in [Scheme::Iast, Scheme::Practical] {
+            assert!(!scheme.is_reversible());
+            assert_eq!(
+                from_latin("kai", scheme),
+                Err(TranslitError::NotReversible(scheme))
+            );
+        }
+        assert_eq!(to_latin("Здравствуйте", Scheme::Practical), "Zdravstvuyte");
+    }
+
+    #[test]
+    fn iso9_round_trips_in_every_case() {
+        for word in [
+            "Здравствуйте",
+            "Щука",
+            "Ёлка",
+            "Їжак",
+            "Љубав",
+            "объявление",
+            "ОБЪЯВЛЕНИЕ",
+            "Объявление",
+            "ВЕСЬ",
+            "весь",
+            "ВЕСЬ МИР",
+            "ПОДЪЁМ, СЕМЬЯ",
+            "Къ",
+            "ДЬЯВОЛ-ОБЪЁМ",
+        ] {
+            let latin = to_latin(word, Scheme::Iso9);
+            assert_eq!(from_latin(&latin, Scheme::Iso9).unwrap(), word, "{latin}");
+        }
+    }
+
+    // Random words from every letter in the table, all lowercase, all uppercase or capitalised. Ъ and Ь never start
+    // a word and a word in capitals has at least two other letters, the cases in_capitals can't tell apart.
+    #[test]
+    fn iso9_round_trips_random_words() {
+        let letters: Vec<&str> = CYRILLIC
+            .iter()
+            .map(|row| row.0)
+            .filter(|l| l.chars().all(char::is_lowercase))
+            .collect();
+        let mut rng = thread_rng();
+        for _ in 0..5_000 {
+            let mut text = String::new();
+            for _ in 0..rng.gen_range(1..5) {
+                let mut word = String::new();
+                let mut others = 0;
+                while others < 2 || rng.gen_bool(0.7) {
+                    let letter = letters[rng.gen_range(0..letters.len())];
+                    if matches!(letter, "ъ" | "ь") {
+                        if word.is_empty() {
+                            continue;
+                        }
+                    } else {
+                        others += 1;
+                    }
+                    word.push_str(letter);
+                }
+                let word = match rng.gen_range(0..3) {
+                    0 => word,
+                    1 => word.to_uppercase(),
+                    _ => {
+                        let first = word.chars().next().unwrap();
+                        first.to_uppercase().collect::<String>() + &word[first.len_utf8()..]
+                    }
+                };
+                text.push_str(&word);
+                text.push_str([" ", ", ", "-", "\n"][rng.gen_range(0..4)]);
+            }
+            let latin = to_latin(&text, Scheme::Iso9);
+            assert_eq!(from_latin(&latin, Scheme::Iso9).unwrap(), text, "{latin}");
+        }
+    }
+}
@@ -0,0 +1,484 @@
+#![allow(dead_code)]
+use super::char_names::CHAR_NAMES;
+use std::fmt;
+use std::fs;
+use std::io;
+
+// chap_18_string prints `"Зд".bytes()` and `"Зд".chars()` to show that З is two bytes. This module does that
+// decoding by hand, so we can see exactly *why* a byte sequence isn't valid UTF-8 and where.
+//
+// Bytes per scalar value:
+// U+0000..U+007F     0xxxxxxx
+// U+0080..U+07FF     110xxxxx 10xxxxxx
+// U+0800..U+FFFF     1110xxxx 10xxxxxx 10xxxxxx            (minus the surrogates U+D800..U+DFFF)
+// U+10000..U+10FFFF  11110xxx 10xxxxxx 10xxxxxx 10xxxxxx
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Utf8ErrorKind {
+    InvalidLeadByte,        // 0xF8..=0xFF can't start anything
+    UnexpectedContinuation, // a 10xxxxxx byte where a new scalar should start
+    InvalidContinuation,    // a lead byte that isn't followed by enough 10xxxxxx bytes
+    Truncated,              // the input ends in the middle of a sequence
+    Overlong,               // e.g. C0 AF for '/', which must be encoded as 2F
+    Surrogate,              // U+D800..U+DFFF only exist in UTF-16
+    OutOfRange,             // above U+10FFFF
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    pub position: usize, // byte offset of the first byte of the bad sequence
+    pub len: usize, // how many bytes make up the bad sequence (what lossy mode replaces with U+FFFD)
+    pub kind: Utf8ErrorKind,
+}
+
+impl fmt::Display for Utf8ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Utf8ErrorKind::InvalidLeadByte => "invalid lead byte",
+            Utf8ErrorKind::UnexpectedContinuation => "unexpected continuation byte",
+            Utf8ErrorKind::InvalidContinuation => "expected a continuation byte",
+            Utf8ErrorKind::Truncated => "truncated sequence at end of input",
+            Utf8ErrorKind::Overlong => "overlong encoding",
+            Utf8ErrorKind::Surrogate => "UTF-16 surrogate",
+            Utf8ErrorKind::OutOfRange => "value above U+10FFFF",
+        };
+        write!(f, "{msg}")
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {} (length {})",
+            self.kind, self.position, self.len
+        )
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+fn is_continuation(b: u8) -> bool {
+    b & 0b1100_0000 == 0b1000_0000
+}
+
+// Decodes the scalar starting at bytes[position]. Ok gives the char and how many bytes it used.
+pub fn decode_at(bytes: &[u8], position: usize) -> Result<(char, usize), DecodeError> {
+    let error = |len: usize, kind: Utf8ErrorKind| DecodeError {
+        position,
+        len,
+        kind,
+    };
+
+    let lead = bytes[position];
+    let len = match lead {
+        0x00..=0x7F => return Ok((lead as char, 1)),
+        0x80..=0xBF => return Err(error(1, Utf8ErrorKind::UnexpectedContinuation)),
+        0xC0 | 0xC1 => return Err(error(1, Utf8ErrorKind::Overlong)), // would encode U+0000..U+007F
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        0xF5..=0xF7 => return Err(error(1, Utf8ErrorKind::OutOfRange)), // would be above U+10FFFF
+        0xF8..=0xFF => return Err(error(1, Utf8ErrorKind::InvalidLeadByte)),
+    };
+
+    // For some lead bytes only part of the 80..BF range is allowed as the second byte. Checking that up front
+    // (instead of after decoding the whole value) means the bad sequence is as short as possible, which is what
+    // the Unicode standard recommends for U+FFFD replacement and what String::from_utf8_lossy does.
+    let (second_min, second_max, kind) = match lead {
+        0xE0 => (0xA0, 0xBF, Utf8ErrorKind::Overlong),
+        0xED => (0x80, 0x9F, Utf8ErrorKind::Surrogate),
+        0xF0 => (0x90, 0xBF, Utf8ErrorKind::Overlong),
+        0xF4 => (0x80, 0x8F, Utf8ErrorKind::OutOfRange),
+        _ => (0x80, 0xBF, Utf8ErrorKind::InvalidContinuation),
+    };
+
+    let mut value = (lead & (0x7F >> len)) as u32;
+    for i in 1..len {
+        let b = match bytes.get(position + i) {
+            None => return Err(error(i, Utf8ErrorKind::Truncated)),
+            Some(&b) => b,
+        };
+        if !is_continuation(b) {
+            return Err(error(i, Utf8ErrorKind::InvalidContinuation));
+        }
+        if i == 1 && !(second_min..=second_max).contains(&b) {
+            return Err(error(1, kind));
+        }
+        value = (value << 6) | (b & 0b0011_1111) as u32;
+    }
+
+    // the checks above rule out every invalid value, so this can't fail
+    let value = char::from_u32(value).expect("validated UTF-8 sequence");
+    Ok((value, len))
+}
+
+// One decoded unit: either a scalar with its byte range, or an error covering the bytes it skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoded {
+    Char {
+        position: usize,
+        len: usize,
+        value: char,
+    },
+    Invalid(DecodeError),
+}
+
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Iterator for Decoder<'a> {
+    type Item = Decoded;
+
+    fn next(&mut self) -> Option<Decoded> {
+        if self.pos >= self.bytes.len() {
+            return None;
+        }
+        let position = self.pos;
+        match decode_at(self.bytes, position) {
+            Ok((value, len)) => {
+                self.pos += len;
+                Some(Decoded::Char {
+                    position,
+                    len,
+                    value,
+                })
+            }
+            Err(e) => {
+                self.pos += e.len;
+                Some(Decoded::Invalid(e))
+            }
+        }
+    }
+}
+
+pub fn decoder(bytes: &[u8]) -> Decoder<'_> {
+    Decoder { bytes, pos: 0 }
+}
+
+// Strict mode: stop at the first problem, like String::from_utf8
+pub fn decode_strict(bytes: &[u8]) -> Result<String, DecodeError> {
+    let mut s = String::with_capacity(bytes.len());
+    for unit in decoder(bytes) {
+        match unit {
+            Decoded::Char { value, .. } => s.push(value),
+            Decoded::Invalid(e) => return Err(e),
+        }
+    }
+    Ok(s)
+}
+
+// Lossy mode: every bad sequence becomes U+FFFD, like String::from_utf8_lossy, but we also get the errors back
+pub fn decode_lossy(bytes: &[u8]) -> (String, Vec<DecodeError>) {
+    let mut s = String::with_capacity(bytes.len());
+    let mut errors = Vec::new();
+    for unit in decoder(bytes) {
+        match unit {
+            Decoded::Char { value, .. } => s.push(value),
+            Decoded::Invalid(e) => {
+                s.push(char::REPLACEMENT_CHARACTER);
+                errors.push(e);
+            }
+        }
+    }
+    (s, errors)
+}
+
+pub fn encode_char_utf8(c: char, out: &mut Vec<u8>) {
+    let v = c as u32;
+    match v {
+        0..=0x7F => out.push(v as u8),
+        0x80..=0x7FF => {
+            out.push(0b1100_0000 | (v >> 6) as u8);
+            out.push(0b1000_0000 | (v & 0x3F) as u8);
+        }
+        0x800..=0xFFFF => {
+            out.push(0b1110_0000 | (v >> 12) as u8);
+            out.push(0b1000_0000 | ((v >> 6) & 0x3F) as u8);
+            out.push(0b1000_0000 | (v & 0x3F) as u8);
+        }
+        _ => {
+            out.push(0b1111_0000 | (v >> 18) as u8);
+            out.push(0b1000_0000 | ((v >> 12) & 0x3F) as u8);
+            out.push(0b1000_0000 | ((v >> 6) & 0x3F) as u8);
+            out.push(0b1000_0000 | (v & 0x3F) as u8);
+        }
+    }
+}
+
+pub fn encode_utf8(s: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        encode_char_utf8(c, &mut out);
+    }
+    out
+}
+
+// UTF-16 code units: one for the BMP, a surrogate pair for everything above U+FFFF
+pub fn encode_utf16_units(s: &str) -> Vec<u16> {
+    let mut out = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        let v = c as u32;
+        if v < 0x10000 {
+            out.push(v as u16);
+        } else {
+            let v = v - 0x10000;
+            out.push(0xD800 | (v >> 10) as u16);
+            out.push(0xDC00 | (v & 0x3FF) as u16);
+        }
+    }
+    out
+}
+
+pub fn encode_utf16le(s: &str) -> Vec<u8> {
+    encode_utf16_units(s)
+        .into_iter()
+        .flat_map(|unit| unit.to_le_bytes())
+        .collect()
+}
+
+pub fn encode_utf16be(s: &str) -> Vec<u8> {
+    encode_utf16_units(s)
+        .into_iter()
+        .flat_map(|unit| unit.to_be_bytes())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeError {
+    pub position: usize, // byte offset in the source &str
+    pub value: char,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} (U+{:04X}) at byte {} can't be encoded in Latin-1",
+            self.value, self.value as u32, self.position
+        )
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+// Latin-1 (ISO 8859-1) is one byte per char, so it only covers U+0000..U+00FF. "Зд" can't be encoded at all.
+pub fn encode_latin1(s: &str) -> Result<Vec<u8>, EncodeError> {
+    let mut out = Vec::with_capacity(s.len());
+    for (position, value) in s.char_indices() {
+        match u8::try_from(value as u32) {
+            Ok(b) => out.push(b),
+            Err(_) => return Err(EncodeError { position, value }),
+        }
+    }
+    Ok(out)
+}
+
+pub fn encode_latin1_lossy(s: &str) -> Vec<u8> {
+    s.chars()
+        .map(|c| u8::try_from(c as u32).unwrap_or(b'?'))
+        .collect()
+}
+
+pub fn char_name(c: char) -> String {
+    match CHAR_NAMES.binary_search_by_key(&c, |&(ch, _)| ch) {
+        Ok(idx) => CHAR_NAMES[idx].1.to_string(),
+        Err(_) => format!("U+{:04X}", c as u32),
+    }
+}
+
+// One line per decoded scalar (or bad sequence):
+// 00000000  d0 97        U+0417  'З'  CYRILLIC CAPITAL LETTER ZE
+pub fn hexdump(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for unit in decoder(bytes) {
+        let (position, len) = match unit {
+            Decoded::Char { position, len, .. } => (position, len),
+            Decoded::Invalid(e) => (e.position, e.len),
+        };
+        let hex: Vec<String> = bytes[position..position + len]
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        let hex = hex.join(" ");
+
+        let annotation = match unit {
+            Decoded::Char { value, .. } => {
+                let shown = if value.is_control() {
+                    value.escape_default().to_string()
+                } else {
+                    value.to_string()
+                };
+                format!("U+{:04X}  '{}'  {}", value as u32, shown, char_name(value))
+            }
+            Decoded::Invalid(e) => format!("error: {}", e.kind),
+        };
+        out.push_str(&format!("{position:08x}  {hex:<12} {annotation}\n"));
+    }
+    out
+}
+
+// The hexdump command: dumps the file given as the first command line argument, or else a line read from stdin.
+pub fn hexdump_command() {
+    let bytes = match std::env::args().nth(1) {
+        Some(path) => match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                println!("Couldn't read {path}: {e}");
+                return;
+            }
+        },
+        None => {
+            println!("Type some text to dump:");
+            let mut line = String::new();
+            io::stdin()
+                .read_line(&mut line)
+                .expect("Failed to read the line");
+            line.trim_end_matches(['\r', '\n']).as_bytes().to_vec()
+        }
+    };
+    print!("{}", hexdump(&bytes));
+}
+
+pub fn start() {
+    // Same output as `for b in "Зд".bytes()` in chap_18_string, but with the decoding spelled out
+    print!("{}", hexdump("Зд".as_bytes()));
+    print!("{}", hexdump("नमस्ते".as_bytes()));
+
+    println!("{}", encode_latin1("Зд").unwrap_err());
+
+    // Every kind of broken input, with the position of the problem
+    let broken: [&[u8]; 7] = [
+        &[0x41, 0x97],             // unexpected continuation
+        &[0xD0, 0x41],             // lead byte without its continuation
+        &[0x41, 0xE0, 0xA4],       // truncated
+        &[0xC0, 0xAF],             // overlong '/'
+        &[0xED, 0xA0, 0x80],       // surrogate U+D800
+        &[0xF4, 0x90, 0x80, 0x80], // U+110000
+        &[0xFF],                   // not a lead byte at all
+    ];
+    for bytes in broken {
+        match decode_strict(bytes) {
+            Ok(s) => println!("{bytes:02x?} -> {s:?}"),
+            Err(e) => println!("{bytes:02x?} -> {e}"),
+        }
+    }
+
+    let (text, errors) = decode_lossy(b"caf\xC3\xA9 \xFF ok");
+    println!("{text:?}, {} error(s)", errors.len()); // "café \u{fffd} ok", 1 error(s)
+    print!("{}", hexdump(b"caf\xC3\xA9 \xFF"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    const BROKEN: [&[u8]; 7] = [
+        &[0x41, 0x97],
+        &[0xD0, 0x41],
+        &[0x41, 0xE0, 0xA4],
+        &[0xC0, 0xAF],
+        &[0xED, 0xA0, 0x80],
+        &[0xF4, 0x90, 0x80, 0x80],
+        &[0xFF],
+    ];
+
+    #[test]
+    fn encoding() {
+        assert_eq!(
+            encode_utf8("Здравствуйте नमस्ते 🇮🇳"),
+            "Здравствуйте नमस्ते 🇮🇳".as_bytes()
+        );
+        assert_eq!(encode_utf16le("З"), [0x17, 0x04]);
+        assert_eq!(encode_utf16be("😀"), [0xD8, 0x3D, 0xDE, 0x00]);
+        assert_eq!(
+            encode_utf16_units("a😀"),
+            "a😀".encode_utf16().collect::<Vec<_>>()
+        );
+        assert_eq!(encode_latin1("café"), Ok(vec![b'c', b'a', b'f', 0xE9]));
+        assert!(encode_latin1("Зд").is_err());
+        assert_eq!(encode_latin1_lossy("Зд!"), b"??!");
+    }
+
+    #[test]
+    fn error_positions() {
+        let errors: Vec<(usize, usize, Utf8ErrorKind)> = BROKEN
+            .iter()
+            .map(|bytes| {
+                let e = decode_strict(bytes).unwrap_err();
+                (e.position, e.len, e.kind)
+            })
+            .collect();
+        use Utf8ErrorKind::*;
+        assert_eq!(
+            errors,
+            [
+                (1, 1, UnexpectedContinuation),
+                (0, 1, InvalidContinuation),
+                (1, 2, Truncated),
+                (0, 1, Overlong),
+                (0, 1, Surrogate),
+                (0, 1, OutOfRange),
+                (0, 1, InvalidLeadByte),
+            ]
+        );
+        assert_eq!(
+            decode_strict(&[0xC0, 0xAF]).unwrap_err().to_string(),
+            "overlong encoding at byte 0 (length 1)"
+        );
+        assert_eq!(decode_strict("Зд".as_bytes()), Ok("Зд".to_string()));
+    }
+
+    #[test]
+    fn lossy_matches_std() {
+        for bytes in BROKEN {
+            assert_eq!(decode_lossy(bytes).0, String::from_utf8_lossy(bytes));
+        }
+        let (text, errors) = decode_lossy(b"caf\xC3\xA9 \xFF ok");
+        assert_eq!(text, "café \u{fffd} ok");
+        assert_eq!(errors.len(), 1);
+
+        // Random bytes, weighted towards the ones that start or continue sequences
+        let mut rng = thread_rng();
+        const BYTES: [u8; 12] = [
+            0x41, 0x80, 0xA4, 0xBF, 0xC0, 0xC3, 0xE0, 0xED, 0xF0, 0xF4, 0xF5, 0xFF,
+        ];
+        for _ in 0..5000 {
+            let bytes: Vec<u8> = (0..rng.gen_range(0..12))
+                .map(|_| {
+                    if rng.gen_bool(0.5) {
+                        BYTES[rng.gen_range(0..BYTES.len())]
+                    } else {
+                        rng.r#gen()
+                    }
+                })
+                .collect();
+            assert_eq!(
+                decode_lossy(&bytes).0,
+                String::from_utf8_lossy(&bytes),
+                "{bytes:02x?}"
+            );
+            assert_eq!(
+                decode_strict(&bytes).ok(),
+                String::from_utf8(bytes.clone()).ok()
+            );
+        }
+    }
+
+    #[test]
+    fn names_and_hexdump() {
+        assert_eq!(char_name('З'), "CYRILLIC CAPITAL LETTER ZE");
+        assert_eq!(
+            char_name('І'),
+            "CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I"
+        );
+        assert_eq!(char_name('\u{7}'), "ALERT");
+        assert_eq!(char_name('\u{10FFFF}'), "U+10FFFF");
+        // char_name binary searches the table
+        assert!(CHAR_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(hexdump("Зд".as_bytes()).starts_with("00000000  d0 97"));
+    }
+}
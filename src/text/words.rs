@@ -0,0 +1,205 @@
+#![allow(dead_code)]
+use std::ops::Range;
+
+// A generalised version of chap_09's first_word/second_word.
+// Those only split on b' ', so "hello,  world" or "नमस्ते दुनिया।" come out wrong. Here a word is a maximal
+// run of chars that are neither whitespace nor punctuation, and every span we hand out starts and ends on a
+// char boundary because we only ever cut at `char_indices()` positions.
+
+pub fn is_separator(c: char) -> bool {
+    c.is_whitespace() || is_punctuation(c)
+}
+
+// std only knows ASCII punctuation, so the common Unicode punctuation blocks are listed by hand.
+pub fn is_punctuation(c: char) -> bool {
+    if c.is_ascii() {
+        // '_' is treated as part of a word, just like in identifiers (chap_15_if_let_and_let_else_syntax)
+        return c.is_ascii_punctuation() && c != '_';
+    }
+    matches!(c,
+        '\u{00A1}' | '\u{00A7}' | '\u{00AB}' | '\u{00B6}' | '\u{00B7}' | '\u{00BB}' | '\u{00BF}'
+        | '\u{037E}' | '\u{0387}'
+        | '\u{055A}'..='\u{055F}'
+        | '\u{0589}' | '\u{05BE}' | '\u{05C0}' | '\u{05C3}' | '\u{05F3}' | '\u{05F4}'
+        | '\u{060C}' | '\u{061B}' | '\u{061F}' | '\u{06D4}'
+        | '\u{0964}' | '\u{0965}' | '\u{0970}' // Devanagari danda, double danda, abbreviation sign
+        | '\u{2010}'..='\u{2027}'
+        | '\u{2030}'..='\u{205E}'
+        | '\u{2E00}'..='\u{2E4F}'
+        | '\u{3001}'..='\u{3003}'
+        | '\u{3008}'..='\u{3011}'
+        | '\u{3014}'..='\u{301F}'
+        | '\u{FE10}'..='\u{FE19}'
+        | '\u{FE30}'..='\u{FE4F}'
+        | '\u{FF01}'..='\u{FF0F}'
+        | '\u{FF1A}'..='\u{FF20}'
+        | '\u{FF3B}'..='\u{FF3D}'
+        | '\u{FF5B}'..='\u{FF65}'
+    )
+}
+
+// Yields the byte range of every word, e.g. "I am  hello" -> 0..1, 2..4, 6..11
+pub struct WordSpans<'a> {
+    source: &'a str,
+    pos: usize, // byte offset we continue scanning from, always on a char boundary
+}
+
+impl<'a> Iterator for WordSpans<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let rest = &self.source[self.pos..];
+        let (start, _) = rest.char_indices().find(|&(_, c)| !is_separator(c))?;
+        let start = self.pos + start;
+
+        let tail = &self.source[start..];
+        let end = match tail.char_indices().find(|&(_, c)| is_separator(c)) {
+            Some((idx, _)) => start + idx,
+            None => self.source.len(),
+        };
+
+        self.pos = end;
+        Some(start..end)
+    }
+}
+
+// Same as WordSpans, but hands out the borrowed slices themselves.
+pub struct Words<'a> {
+    spans: WordSpans<'a>,
+}
+
+impl<'a> Words<'a> {
+    // Pairs every word with its byte range in the original string.
+    pub fn with_spans(self) -> impl Iterator<Item = (Range<usize>, &'a str)> {
+        let source = self.spans.source;
+        self.spans.map(move |span| (span.clone(), &source[span]))
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let span = self.spans.next()?;
+        Some(&self.spans.source[span])
+    }
+}
+
+pub fn word_spans(s: &str) -> WordSpans<'_> {
+    WordSpans { source: s, pos: 0 }
+}
+
+pub fn words(s: &str) -> Words<'_> {
+    Words {
+        spans: word_spans(s),
+    }
+}
+
+// nth_word(s, 0) is what first_word_improved should have been, nth_word(s, 1) is second_word.
+pub fn nth_word(s: &str, n: usize) -> Option<&str> {
+    words(s).nth(n)
+}
+
+pub fn start() {
+    let s = "I am  hello world";
+    let all: Vec<&str> = words(s).collect();
+    println!("words: {all:?}"); // ["I", "am", "hello", "world"]
+    println!("second word: {:?}", nth_word(s, 1)); // Some("am")
+
+    let s = "Hello, World! How's—it going?";
+    for (span, word) in words(s).with_spans() {
+        println!("{span:?} -> {word}");
+    }
+
+    // Multi-byte text: every span lands on a char boundary, so slicing with it never panics
+    let s = "नमस्ते\u{3000}दुनिया। Здравствуйте,мир";
+    for span in word_spans(s) {
+        assert!(s.is_char_boundary(span.start) && s.is_char_boundary(span.end));
+        println!("{span:?} -> {}", &s[span.clone()]);
+    }
+    assert_eq!(nth_word(s, 1), Some("दुनिया"));
+    assert_eq!(nth_word(s, 3), Some("мир"));
+    assert_eq!(nth_word("   ", 0), None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, thread_rng};
+
+    // Random strings mixing ASCII, whitespace and punctuation from several scripts, combining marks, emoji and
+    // completely random chars, so words start and end next to chars of every UTF-8 length
+    fn random_text(rng: &mut impl Rng) -> String {
+        const POOL: &[char] = &[
+            'a', 'Z', '_', '7', ' ', '\t', '\n', ',', '-', '\'', 'न', 'म', '्', 'त', '\u{947}', '।',
+            'З', 'д', 'é', '\u{301}', '\u{3000}', '、', '「', '—', '…', '😀', '🇮', '\u{200D}',
+            '\u{FE0F}', '！',
+        ];
+        (0..rng.gen_range(0..40))
+            .map(|_| {
+                if rng.gen_bool(0.15) {
+                    rng.r#gen::<char>()
+                } else {
+                    POOL[rng.gen_range(0..POOL.len())]
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn spans_lie_on_char_boundaries() {
+        let mut rng = thread_rng();
+        for _ in 0..5_000 {
+            let s = random_text(&mut rng);
+            let mut previous_end = 0;
+            for span in word_spans(&s) {
+                assert!(s.is_char_boundary(span.start), "{s:?} {span:?}");
+                assert!(s.is_char_boundary(span.end), "{s:?} {span:?}");
+                assert!(
+                    previous_end <= span.start && span.start < span.end,
+                    "{s:?} {span:?}"
+                );
+                previous_end = span.end;
+            }
+        }
+    }
+
+    #[test]
+    fn words_are_maximal_runs_of_non_separators() {
+        let mut rng = thread_rng();
+        for _ in 0..5_000 {
+            let s = random_text(&mut rng);
+            for (span, word) in words(&s).with_spans() {
+                assert_eq!(&s[span.clone()], word);
+                assert!(!word.chars().any(is_separator), "{s:?} {word:?}");
+                // the chars on either side, if any, are separators
+                assert!(
+                    s[..span.start].chars().next_back().is_none_or(is_separator),
+                    "{s:?} {span:?}"
+                );
+                assert!(
+                    s[span.end..].chars().next().is_none_or(is_separator),
+                    "{s:?} {span:?}"
+                );
+            }
+            let expected: Vec<&str> = s.split(is_separator).filter(|w| !w.is_empty()).collect();
+            let found: Vec<&str> = words(&s).collect();
+            assert_eq!(found, expected, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn words_borrow_from_the_input() {
+        let mut rng = thread_rng();
+        for _ in 0..2_000 {
+            let s = random_text(&mut rng);
+            let range = s.as_bytes().as_ptr_range();
+            for (n, word) in words(&s).enumerate() {
+                let bytes = word.as_bytes().as_ptr_range();
+                assert!(range.start <= bytes.start && bytes.end <= range.end);
+                assert_eq!(nth_word(&s, n), Some(word));
+            }
+            assert_eq!(nth_word(&s, words(&s).count()), None);
+        }
+    }
+}
@@ -0,0 +1,390 @@
+#![allow(dead_code)]
+use super::grapheme::graphemes;
+use super::width_tables::{WIDE, ZERO_WIDTH};
+use std::cmp::Ordering;
+
+// Wrapping long explanations (like the ownership notes at the top of chap_19_exercises) to the terminal width.
+// Widths are measured in terminal columns, not bytes or chars: "Зд" is 2 columns but 4 bytes, "你好" is 4
+// columns but 2 chars, and the combining marks in "नमस्ते" take no column at all.
+
+fn in_table(c: char, table: &[(char, char)]) -> bool {
+    table
+        .binary_search_by(|&(lo, hi)| {
+            if hi < c {
+                Ordering::Less
+            } else if lo > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+pub fn char_width(c: char) -> usize {
+    if c.is_control() || in_table(c, ZERO_WIDTH) {
+        0
+    } else if in_table(c, WIDE) {
+        2
+    } else {
+        1
+    }
+}
+
+// A grapheme cluster is drawn as one unit. Emoji sequences (👨‍👩‍👧, ❤️ with its variation selector) take 2 columns
+// no matter how many chars they're made of; anything else is the sum of its chars.
+fn cluster_width(cluster: &str) -> usize {
+    let mut chars = cluster.chars();
+    let first = chars.next().map_or(0, char_width);
+    if first == 2 || cluster.contains('\u{fe0f}') {
+        return 2;
+    }
+    first + chars.map(char_width).sum::<usize>()
+}
+
+pub fn display_width(s: &str) -> usize {
+    graphemes(s).map(cluster_width).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    // fill each line as much as possible, fast but can leave one very short line
+    Greedy,
+    // minimise the sum of squared leftover space over all lines but the last (Knuth-Plass without hyphenation)
+    MinRaggedness,
+}
+
+#[derive(Debug, Clone)]
+pub struct WrapOptions {
+    pub width: usize,
+    pub algorithm: Algorithm,
+    // printed before the first line, e.g. "- " for a bullet
+    pub initial_indent: String,
+    // printed before every other line, e.g. "  " to hang under the bullet text
+    pub subsequent_indent: String,
+    // stretch the spaces so every line but the last is exactly `width` wide
+    pub justify: bool,
+}
+
+impl WrapOptions {
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            algorithm: Algorithm::MinRaggedness,
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+            justify: false,
+        }
+    }
+
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    // "- " on the first line and as many spaces as it is wide on the others
+    pub fn hanging(mut self, bullet: &str) -> Self {
+        self.initial_indent = bullet.to_string();
+        self.subsequent_indent = " ".repeat(display_width(bullet));
+        self
+    }
+
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+}
+
+struct Word<'a> {
+    text: &'a str,
+    width: usize,
+}
+
+// Words wider than a line get cut at grapheme boundaries so we never split a char (or a conjunct) in half
+fn split_long_word(word: &str, max: usize) -> Vec<Word<'_>> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut width = 0;
+    let mut pos = 0;
+    for cluster in graphemes(word) {
+        let w = cluster_width(cluster);
+        if width + w > max && width > 0 {
+            pieces.push(Word {
+                text: &word[start..pos],
+                width,
+            });
+            start = pos;
+            width = 0;
+        }
+        width += w;
+        pos += cluster.len();
+    }
+    pieces.push(Word {
+        text: &word[start..],
+        width,
+    });
+    pieces
+}
+
+// Each returned range is one line's worth of words
+fn break_greedy(words: &[Word], first: usize, rest: usize) -> Vec<(usize, usize)> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut width = 0;
+    for (i, word) in words.iter().enumerate() {
+        let max = if lines.is_empty() { first } else { rest };
+        let needed = if i == start {
+            word.width
+        } else {
+            width + 1 + word.width
+        };
+        if needed > max && i > start {
+            lines.push((start, i));
+            start = i;
+            width = word.width;
+        } else {
+            width = needed;
+        }
+    }
+    if start < words.len() {
+        lines.push((start, words.len()));
+    }
+    lines
+}
+
+// best[i] = the cheapest way to lay out words[i..]. The cost of a line is the square of its leftover space,
+// except for the last line which is free, so the slack gets spread evenly instead of piling up at the end.
+fn break_min_raggedness(words: &[Word], first: usize, rest: usize) -> Vec<(usize, usize)> {
+    let n = words.len();
+    let mut best = vec![(u64::MAX, n); n + 1];
+    best[n] = (0, n);
+    for i in (0..n).rev() {
+        // the first line can be narrower (a bullet), every other line has the same width
+        let max = if i == 0 { first } else { rest };
+        let mut width = 0;
+        for j in i + 1..=n {
+            width += words[j - 1].width + usize::from(j - 1 > i);
+            if width > max && j > i + 1 {
+                break;
+            }
+            let slack = max.saturating_sub(width) as u64;
+            let cost = if j == n { 0 } else { slack * slack };
+            let total = cost.saturating_add(best[j].0);
+            if total < best[i].0 {
+                best[i] = (total, j);
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let j = best[i].1;
+        lines.push((i, j));
+        i = j;
+    }
+    lines
+}
+
+// Spreads the leftover space over the gaps, the leftmost gaps get the extra ones
+fn justify_line(words: &[Word], max: usize) -> String {
+    if words.len() == 1 {
+        return words[0].text.to_string();
+    }
+    let gaps = words.len() - 1;
+    let spaces = max.saturating_sub(words.iter().map(|w| w.width).sum::<usize>());
+    let mut line = String::new();
+    for (i, word) in words.iter().enumerate() {
+        line.push_str(word.text);
+        if i < gaps {
+            let count = spaces / gaps + usize::from(i < spaces % gaps);
+            line.push_str(&" ".repeat(count.max(1)));
+        }
+    }
+    line
+}
+
+pub fn wrap(text: &str, options: &WrapOptions) -> Vec<String> {
+    let first = options
+        .width
+        .saturating_sub(display_width(&options.initial_indent))
+        .max(1);
+    let rest = options
+        .width
+        .saturating_sub(display_width(&options.subsequent_indent))
+        .max(1);
+
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        words.extend(split_long_word(word, first.min(rest)));
+    }
+    if words.is_empty() {
+        return vec![options.initial_indent.trim_end().to_string()];
+    }
+
+    let breaks = match options.algorithm {
+        Algorithm::Greedy => break_greedy(&words, first, rest),
+        Algorithm::MinRaggedness => break_min_raggedness(&words, first, rest),
+    };
+
+    let last = breaks.len() - 1;
+    breaks
+        .iter()
+        .enumerate()
+        .map(|(i, &(start, end))| {
+            let (indent, max) = if i == 0 {
+                (&options.initial_indent, first)
+            } else {
+                (&options.subsequent_indent, rest)
+            };
+            let line_words = &words[start..end];
+            let body = if options.justify && i != last {
+                justify_line(line_words, max)
+            } else {
+                let texts: Vec<&str> = line_words.iter().map(|w| w.text).collect();
+                texts.join(" ")
+            };
+            format!("{indent}{body}")
+        })
+        .collect()
+}
+
+pub fn fill(text: &str, options: &WrapOptions) -> String {
+    wrap(text, options).join("\n")
+}
+
+// Every item becomes a bullet whose continuation lines hang under the text, not under the bullet
+pub fn fill_bullets(items: &[&str], bullet: &str, options: &WrapOptions) -> String {
+    let options = options.clone().hanging(bullet);
+    let lines: Vec<String> = items.iter().map(|item| fill(item, &options)).collect();
+    lines.join("\n")
+}
+
+pub fn start() {
+    // From the notes at the top of chap_19_exercises
+    let note = "Move simply means that the \"ownership\" has been transferred to a different variable and the previous binding is dead to the compiler unless we decide to re-initialize it with the same name and in that case too the binding is \"shadowed\" and will be treated as new.";
+
+    let ruler = "-".repeat(40);
+    println!(
+        "{ruler}\nGreedy:\n{}",
+        fill(note, &WrapOptions::new(40).algorithm(Algorithm::Greedy))
+    );
+    println!(
+        "{ruler}\nMinimum raggedness:\n{}",
+        fill(note, &WrapOptions::new(40))
+    );
+    println!(
+        "{ruler}\nJustified:\n{}",
+        fill(note, &WrapOptions::new(40).justify(true))
+    );
+
+    let rules = [
+        "Only happens with a type if it doesn't implement the Copy trait; otherwise it will be copied.",
+        "Heap-owning types always move (by default); duplicating their heap data requires an explicit `clone()`.",
+        "Здравствуйте नमस्ते 你好世界 👨‍👩‍👧 are all measured in terminal columns, not bytes.",
+    ];
+    println!(
+        "{ruler}\n{}\n{ruler}",
+        fill_bullets(&rules, "- ", &WrapOptions::new(40))
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE: &str = "Move simply means that the \"ownership\" has been transferred to a different variable and the previous binding is dead to the compiler unless we decide to re-initialize it with the same name and in that case too the binding is \"shadowed\" and will be treated as new.";
+
+    #[test]
+    fn widths() {
+        assert_eq!(display_width("Здравствуйте"), 12);
+        assert_eq!(display_width("नमस्ते"), 4);
+        assert_eq!(display_width("你好"), 4);
+        assert_eq!(display_width("👨‍👩‍👧"), 2);
+        assert_eq!(display_width("❤️"), 2);
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("a\tb"), 2);
+        assert_eq!(char_width('\u{1160}'), 0); // Hangul medial vowel filler
+        assert_eq!(char_width('\u{ff21}'), 2); // FULLWIDTH LATIN CAPITAL LETTER A
+        assert_eq!(char_width('\u{1fadf}'), 2); // SPLATTER, new in Unicode 16.0
+        assert_eq!(char_width('\u{378}'), 1); // unassigned, so East_Asian_Width N
+        assert_eq!(char_width('\u{2a6e0}'), 2); // unassigned, but in the ideograph plane
+        // in_table binary searches both tables
+        for table in [ZERO_WIDTH, WIDE] {
+            assert!(table.iter().all(|&(lo, hi)| lo <= hi));
+            assert!(table.windows(2).all(|w| w[0].1 < w[1].0));
+        }
+    }
+
+    // The greedy layout leaves "in that case too the binding is" short, minimum raggedness evens it out
+    #[test]
+    fn algorithms() {
+        assert_eq!(
+            wrap(NOTE, &WrapOptions::new(40).algorithm(Algorithm::Greedy)),
+            [
+                "Move simply means that the \"ownership\"",
+                "has been transferred to a different",
+                "variable and the previous binding is",
+                "dead to the compiler unless we decide to",
+                "re-initialize it with the same name and",
+                "in that case too the binding is",
+                "\"shadowed\" and will be treated as new.",
+            ]
+        );
+        assert_eq!(
+            wrap(NOTE, &WrapOptions::new(40)),
+            [
+                "Move simply means that the \"ownership\"",
+                "has been transferred to a different",
+                "variable and the previous binding is",
+                "dead to the compiler unless we decide",
+                "to re-initialize it with the same name",
+                "and in that case too the binding is",
+                "\"shadowed\" and will be treated as new.",
+            ]
+        );
+        assert_eq!(wrap("", &WrapOptions::new(40)), [""]);
+    }
+
+    #[test]
+    fn justification() {
+        let lines = wrap(NOTE, &WrapOptions::new(40).justify(true));
+        let (last, full) = lines.split_last().unwrap();
+        assert!(full.iter().all(|line| display_width(line) == 40));
+        assert_eq!(last, "\"shadowed\" and will be treated as new.");
+        assert_eq!(lines[0], "Move  simply  means that the \"ownership\"");
+        // a line with one word has no gaps to stretch
+        assert_eq!(
+            wrap("Здравствуйте мир", &WrapOptions::new(12).justify(true)),
+            ["Здравствуйте", "мир"]
+        );
+    }
+
+    #[test]
+    fn hanging_indent() {
+        let rules = [
+            "Heap-owning types always move (by default); duplicating their heap data requires an explicit `clone()`.",
+            "Здравствуйте नमस्ते 你好世界 👨‍👩‍👧 are all measured in terminal columns, not bytes.",
+        ];
+        assert_eq!(
+            fill_bullets(&rules, "- ", &WrapOptions::new(40)),
+            "- Heap-owning types always move (by\n  default); duplicating their heap data\n  requires an explicit `clone()`.\n\
+             - Здравствуйте नमस्ते 你好世界 👨‍👩‍👧 are\n  all measured in terminal columns, not\n  bytes."
+        );
+        let options = WrapOptions::new(40).hanging("- ");
+        assert_eq!(options.subsequent_indent, "  ");
+        assert_eq!(wrap("", &options), ["-"]);
+    }
+
+    // Words longer than a line are cut between grapheme clusters, never inside one
+    #[test]
+    fn long_words() {
+        assert_eq!(wrap("abcdefgh", &WrapOptions::new(3)), ["abc", "def", "gh"]);
+        // "स्ते" is one cluster two columns wide
+        assert_eq!(wrap("नमस्तेनमस्ते", &WrapOptions::new(3)), ["नम", "स्तेन", "मस्ते"]);
+        assert_eq!(
+            wrap("你好世界", &WrapOptions::new(3)),
+            ["你", "好", "世", "界"]
+        );
+    }
+}